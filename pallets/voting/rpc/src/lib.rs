@@ -0,0 +1,224 @@
+//! JSON-RPC interface for the voting pallet.
+//!
+//! Exposes `voting_getResults`, `voting_getResultsJson` and `voting_getWinner`, which call into
+//! the [`VotingRuntimeApi`] at a given block, or the best block if none is given, and return the
+//! results as plain JSON so that web clients do not have to decode SCALE encoded storage.
+
+use std::sync::Arc;
+
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::{error::ErrorObject, ErrorObjectOwned},
+};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{traits::Block as BlockT, Permill};
+
+pub use pallet_voting_runtime_api::VotingApi as VotingRuntimeApi;
+
+/// The number of votes received by a candidate of an election.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CandidateResult {
+	/// The identifier of the candidate.
+	pub candidate_id: u32,
+	/// The name of the candidate, decoded as UTF-8.
+	pub name: String,
+	/// The number of votes received by the candidate.
+	pub votes: u32,
+}
+
+/// The result of a candidate of an election, or of the abstentions, as returned by
+/// `voting_getResultsJson`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CandidateShare {
+	/// The identifier of the candidate.
+	pub id: u32,
+	/// The name of the candidate, decoded as UTF-8.
+	pub name: String,
+	/// The number of votes received by the candidate.
+	pub votes: u32,
+	/// The share of the total votes received by the candidate, in percent.
+	pub percentage: f64,
+}
+
+/// Whether the election queried by `voting_getResultsJson` exists.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ResultsStatus {
+	/// The election exists and its results are listed.
+	Ok,
+	/// No election exists with the given identifier, so no results are listed.
+	NoElection,
+}
+
+/// The results of an election as returned by `voting_getResultsJson`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ElectionResults {
+	/// Whether the election exists.
+	pub status: ResultsStatus,
+	/// The result of every candidate, followed by the abstentions. Empty if the election does
+	/// not exist or its results are hidden until it is finalized.
+	pub results: Vec<CandidateShare>,
+}
+
+/// The single leading candidate of an election.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ElectionWinner {
+	/// The identifier of the candidate.
+	pub candidate_id: u32,
+	/// The number of votes received by the candidate.
+	pub votes: u32,
+}
+
+#[rpc(client, server)]
+pub trait VotingApi<BlockHash> {
+	/// Returns the number of votes received by every candidate of an election, followed by the
+	/// abstentions.
+	#[method(name = "voting_getResults")]
+	fn get_results(
+		&self,
+		election_id: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<CandidateResult>>;
+
+	/// Returns the results of an election with the names of the candidates and their share of the
+	/// total votes, so that they can be shown without any SCALE tooling. An unknown election is
+	/// reported through the status rather than as an error.
+	#[method(name = "voting_getResultsJson")]
+	fn get_results_json(
+		&self,
+		election_id: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<ElectionResults>;
+
+	/// Returns the single leading candidate of an election, or `null` if no votes have been cast
+	/// or the election is tied.
+	#[method(name = "voting_getWinner")]
+	fn get_winner(
+		&self,
+		election_id: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<ElectionWinner>>;
+}
+
+/// Provides RPC methods to query the results of elections.
+pub struct Voting<C, B> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Voting<C, B> {
+	/// Creates a new instance of the Voting Rpc helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// No election exists with the given identifier.
+	NoElectionExists,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::NoElectionExists => 2,
+		}
+	}
+}
+
+fn runtime_error(error: impl ToString) -> ErrorObjectOwned {
+	ErrorObject::owned(
+		Error::RuntimeError.into(),
+		"Unable to query election results.",
+		Some(error.to_string()),
+	)
+}
+
+/// Convert a share of the votes to a percentage.
+fn percentage(share: Permill) -> f64 {
+	f64::from(share.deconstruct()) / 10_000.0
+}
+
+impl<C, Block> VotingApiServer<<Block as BlockT>::Hash> for Voting<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: VotingRuntimeApi<Block>,
+{
+	fn get_results(
+		&self,
+		election_id: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<CandidateResult>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let results =
+			api.get_results(at_hash, election_id).map_err(runtime_error)?.ok_or_else(|| {
+				ErrorObject::owned(
+					Error::NoElectionExists.into(),
+					"No election exists with the given identifier.",
+					Some(election_id),
+				)
+			})?;
+
+		Ok(results
+			.into_iter()
+			.map(|(candidate_id, name, votes)| CandidateResult {
+				candidate_id,
+				name: String::from_utf8_lossy(&name).into_owned(),
+				votes,
+			})
+			.collect())
+	}
+
+	fn get_results_json(
+		&self,
+		election_id: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<ElectionResults> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let Some(results) =
+			api.results_with_percentage(at_hash, election_id).map_err(runtime_error)?
+		else {
+			return Ok(ElectionResults { status: ResultsStatus::NoElection, results: Vec::new() })
+		};
+
+		Ok(ElectionResults {
+			status: ResultsStatus::Ok,
+			results: results
+				.into_iter()
+				.map(|(id, name, votes, share)| CandidateShare {
+					id,
+					name: String::from_utf8_lossy(&name).into_owned(),
+					votes,
+					percentage: percentage(share),
+				})
+				.collect(),
+		})
+	}
+
+	fn get_winner(
+		&self,
+		election_id: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<ElectionWinner>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let winner = api.get_winner(at_hash, election_id).map_err(runtime_error)?;
+		Ok(winner.map(|(candidate_id, votes)| ElectionWinner { candidate_id, votes }))
+	}
+}
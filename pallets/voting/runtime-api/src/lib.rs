@@ -0,0 +1,176 @@
+//! Runtime API definition for the voting pallet.
+//!
+//! Exposes the candidates and results of elections, as well as the archive of finalized
+//! elections, to the client, so that RPCs and front-ends can query them without decoding the
+//! pallet's storage. The accounts that voted are exposed separately by [`VotingAuditApi`] for
+//! recount audits, and whether an account can vote by [`VoterEligibilityApi`].
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{DispatchError, Permill, RuntimeDebug};
+
+/// The result of a candidate, or of the abstentions, as returned by
+/// [`VotingApi::results_with_percentage`]: `(candidate_id, name, votes, share)`.
+pub type ResultWithPercentage = (u32, Vec<u8>, u32, Permill);
+
+/// A finalized election as returned by [`VotingApi::past_elections`]:
+/// `(archive_id, election_id, title, winners, total_votes, vote_counts)`, where `vote_counts`
+/// lists `(candidate_id, votes)` for every candidate.
+pub type PastElection = (u32, u32, Vec<u8>, Vec<u32>, u32, Vec<(u32, u32)>);
+
+/// The stage of its lifecycle an election is in, as part of an [`ElectionExport`].
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum ElectionStatus {
+	/// The election has been created but not started yet.
+	Pending,
+	/// The election has been started and votes are accepted in its voting window.
+	Active,
+	/// The election has been ended but not finalized yet.
+	Ended,
+	/// The results of the election have been frozen.
+	Finalized,
+	/// The election was finalized without reaching its quorum.
+	Failed,
+	/// The election was cancelled.
+	Cancelled,
+}
+
+/// The order in which [`VotingApi::get_results_ordered`] lists the candidates of an election.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum ResultOrder {
+	/// From most to fewest votes; candidates with equal votes keep their insertion order.
+	ByVotesDesc,
+	/// By ascending candidate identifier, which lists the abstentions first.
+	ById,
+	/// In the order the candidates were added, followed by the abstentions.
+	Insertion,
+}
+
+/// The results of an election as returned by [`VotingApi::export_results`].
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ElectionExport {
+	/// Human readable title of the election.
+	pub title: Vec<u8>,
+	/// Every candidate of the election as `(candidate_id, name, votes)`.
+	pub candidates: Vec<(u32, Vec<u8>, u32)>,
+	/// The total number of votes cast in the election, including abstentions.
+	pub total_votes: u32,
+	/// The number of abstentions.
+	pub abstentions: u32,
+	/// The stage of its lifecycle the election is in. The results are only final once it is
+	/// finalized or failed.
+	pub status: ElectionStatus,
+}
+
+/// The state of an election at a glance, as returned by [`VotingApi::election_snapshot`].
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ElectionSnapshot {
+	/// Human readable title of the election.
+	pub title: Vec<u8>,
+	/// The stage of its lifecycle the election is in.
+	pub status: ElectionStatus,
+	/// The first block of the voting window, or `None` if the window is measured in time.
+	pub start_block: Option<u32>,
+	/// The block from which votes are no longer accepted, or `None` if the window is measured in
+	/// time.
+	pub end_block: Option<u32>,
+	/// The number of candidates standing in the election.
+	pub candidate_count: u32,
+	/// The total number of votes cast in the election, including abstentions.
+	pub total_votes: u32,
+	/// Whether the current block or time is inside the voting window.
+	pub within_window: bool,
+}
+
+sp_api::decl_runtime_apis! {
+	/// Query the results of elections run by the voting pallet.
+	///
+	/// Elections are identified by their `ElectionId`, which is a `u32`.
+	pub trait VotingApi {
+		/// Returns `(candidate_id, name, votes)` for every candidate of an election, followed by
+		/// the abstentions, or `None` if no election exists with the given identifier. The list is
+		/// empty while the results of an unfinalized election are hidden.
+		fn get_results(election_id: u32) -> Option<Vec<(u32, Vec<u8>, u32)>>;
+
+		/// Returns the results like `get_results`, with each entry's share of the total votes
+		/// appended. Shares are rounded down and all zero if no votes have been cast.
+		fn results_with_percentage(election_id: u32) -> Option<Vec<ResultWithPercentage>>;
+
+		/// Returns `(candidate_id, votes)` of the single leading candidate of an election, or
+		/// `None` if there is no such candidate because no votes have been cast, the election is
+		/// tied or its results are hidden until it is finalized.
+		fn get_winner(election_id: u32) -> Option<(u32, u32)>;
+
+		/// Returns the total number of votes cast in an election, including abstentions, or `0`
+		/// while the results of the election are hidden.
+		fn total_votes(election_id: u32) -> u32;
+
+		/// Returns `(candidate_id, name, description)` for up to `limit` candidates of an election,
+		/// starting at position `offset`. Offsets past the last candidate yield an empty list.
+		fn candidates_paged(election_id: u32, offset: u32, limit: u32) -> Vec<(u32, Vec<u8>, Vec<u8>)>;
+
+		/// Returns the number of candidates standing in an election.
+		fn candidate_count(election_id: u32) -> u32;
+
+		/// Returns `(candidate_id, name, description)` of a single candidate of an election, or
+		/// `None` if no such candidate stands.
+		fn get_candidate(election_id: u32, candidate_id: u32) -> Option<(u32, Vec<u8>, Vec<u8>)>;
+
+		/// Returns up to `limit` finalized elections, starting at archive identifier `offset`.
+		fn past_elections(offset: u32, limit: u32) -> Vec<PastElection>;
+
+		/// Returns the title, candidates with their votes, total votes, abstentions and status of
+		/// an election at once, or `None` if no election exists with the given identifier. The
+		/// counts are `0` while the results of the election are hidden.
+		fn export_results(election_id: u32) -> Option<ElectionExport>;
+
+		/// Returns the share of registered voters that cast a ballot in an election, including
+		/// abstentions, counting every ballot once whatever its weight. Zero if no voters are
+		/// registered or the results of the election are hidden, and capped at 100% if accounts
+		/// that are not registered voted too.
+		fn turnout(election_id: u32) -> Permill;
+
+		/// Returns the results like `get_results`, listed in the given `order`.
+		fn get_results_ordered(election_id: u32, order: ResultOrder) -> Option<Vec<(u32, Vec<u8>, u32)>>;
+
+		/// Returns the share of a candidate's votes among the votes of an election that were not
+		/// abstentions. Zero if only abstentions have been cast or the results are hidden until
+		/// the election is finalized.
+		fn candidate_share_of_decided(election_id: u32, candidate_id: u32) -> Permill;
+
+		/// Returns whether an election accepts votes right now: it has been started, is not
+		/// paused and the current block or time is inside its voting window.
+		fn is_voting_open(election_id: u32) -> bool;
+
+		/// Returns the title, status, voting window, number of candidates and total votes of an
+		/// election at once, or `None` if no election exists with the given identifier. The total
+		/// votes are `0` while the results of the election are hidden.
+		fn election_snapshot(election_id: u32) -> Option<ElectionSnapshot>;
+	}
+
+	/// List the voters of elections run by the voting pallet, for recount audits.
+	///
+	/// Every call iterates over all votes of an election, so these queries are only offered
+	/// off-chain. No voters are listed while the results of an election are hidden.
+	pub trait VotingAuditApi<AccountId> where AccountId: Codec {
+		/// Returns the accounts whose vote in an election went to `candidate_id`, or to the first
+		/// preference in ranked-choice elections.
+		fn voters_for_candidate(election_id: u32, candidate_id: u32) -> Vec<AccountId>;
+
+		/// Returns every account that voted in an election.
+		fn all_voters(election_id: u32) -> Vec<AccountId>;
+	}
+
+	/// Check whether accounts can vote in elections run by the voting pallet, so that front-ends
+	/// can tell before asking for a signature.
+	pub trait VoterEligibilityApi<AccountId> where AccountId: Codec {
+		/// Returns `Ok` if `who` could vote for `candidate_id` in an election right now, or the
+		/// error casting the vote would fail with.
+		fn can_vote(who: AccountId, election_id: u32, candidate_id: u32) -> Result<(), DispatchError>;
+	}
+}
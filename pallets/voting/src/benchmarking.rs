@@ -0,0 +1,1049 @@
+//! Benchmarking setup for pallet-voting
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as Voting;
+use alloc::{vec, vec::Vec};
+use frame_benchmarking::v2::*;
+use frame_support::{
+	sp_runtime::{
+		traits::{Bounded, Hash, Saturating},
+		Permill,
+	},
+	traits::{Currency, EnsureOrigin, Get, ReservableCurrency},
+	BoundedVec,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+
+const SEED: u32 = 0;
+const START_BLOCK: u32 = 10;
+const END_BLOCK: u32 = 100;
+const REVEAL_END_BLOCK: u32 = 200;
+
+/// An account holding enough funds for an election deposit and a candidate bond.
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let who = account(name, index, SEED);
+	let amount = T::Currency::minimum_balance()
+		.saturating_add(T::ElectionDeposit::get())
+		.saturating_add(T::CandidateBond::get())
+		.saturating_mul(10u32.into());
+	T::Currency::make_free_balance_be(&who, amount);
+	who
+}
+
+/// A funded account listed in [`Admins`].
+fn admin<T: Config>() -> T::AccountId {
+	let who = funded_account::<T>("admin", 0);
+	Admins::<T>::mutate(|admins| admins.try_push(who.clone()).expect("Admins is not full"));
+	who
+}
+
+/// A registered voter.
+fn voter<T: Config>(index: u32) -> T::AccountId {
+	let who = account("voter", index, SEED);
+	RegisteredVoters::<T>::insert(&who, ());
+	RegisteredVoterCount::<T>::mutate(|count| *count = count.saturating_add(1));
+	who
+}
+
+/// A candidate name of the maximum length.
+fn max_name<T: Config>() -> Vec<u8> {
+	vec![b'x'; T::MaxNameLength::get() as usize]
+}
+
+/// A candidate description of the maximum length.
+fn max_description<T: Config>() -> Vec<u8> {
+	vec![b'x'; T::MaxDescriptionLength::get() as usize]
+}
+
+/// An election title of the maximum length.
+fn max_title<T: Config>() -> Vec<u8> {
+	vec![b'x'; T::MaxTitleLength::get() as usize]
+}
+
+/// A metadata URI of the maximum length.
+fn max_metadata_uri() -> Vec<u8> {
+	vec![b'x'; MAX_METADATA_URI_LENGTH as usize]
+}
+
+fn block<T: Config>(number: u32) -> BlockNumberFor<T> {
+	number.into()
+}
+
+/// The block-based voting window of benchmarked elections.
+fn window<T: Config>() -> VotingWindowOf<T> {
+	VotingWindow::Blocks { start: block::<T>(START_BLOCK), end: block::<T>(END_BLOCK) }
+}
+
+/// Move to the earliest block in which an election with the benchmarked voting window can be
+/// created, which is [`Config::MinCreationLead`] blocks before the window starts.
+fn before_window<T: Config>() {
+	frame_system::Pallet::<T>::set_block_number(
+		block::<T>(START_BLOCK).saturating_sub(T::MinCreationLead::get()),
+	);
+}
+
+/// Create an election with a deposit reserved from `admin`.
+fn create<T: Config>(
+	admin: &T::AccountId,
+	kind: ElectionKind,
+	weighted: bool,
+	reveal_end_block: Option<u32>,
+) -> Result<ElectionId, BenchmarkError> {
+	let election_id = NextElectionId::<T>::get();
+	before_window::<T>();
+	Voting::<T>::create_election(
+		RawOrigin::Signed(admin.clone()).into(),
+		max_title::<T>(),
+		window::<T>(),
+		kind,
+		weighted,
+		reveal_end_block.map(block::<T>),
+		1,
+		false,
+		false,
+		None,
+		None,
+	)?;
+	Ok(election_id)
+}
+
+/// Add `count` candidates to an election, returning their identifiers.
+fn add_candidates<T: Config>(
+	admin: &T::AccountId,
+	election_id: ElectionId,
+	count: u32,
+) -> Result<Vec<u32>, BenchmarkError> {
+	(0..count)
+		.map(|_| {
+			let candidate_id = NextCandidateId::<T>::get(election_id);
+			Voting::<T>::add_candidate(
+				RawOrigin::Signed(admin.clone()).into(),
+				election_id,
+				max_name::<T>(),
+				max_description::<T>(),
+			)?;
+			Ok(candidate_id)
+		})
+		.collect()
+}
+
+/// Start an election, adding candidates first if it has too few to be started and locking them,
+/// and move to the first block of its voting window.
+fn start<T: Config>(admin: &T::AccountId, election_id: ElectionId) -> Result<(), BenchmarkError> {
+	let missing =
+		T::MinCandidates::get().max(1).saturating_sub(Voting::<T>::candidate_count(election_id));
+	add_candidates::<T>(admin, election_id, missing)?;
+	Voting::<T>::lock_candidates(RawOrigin::Signed(admin.clone()).into(), election_id)?;
+	frame_system::Pallet::<T>::set_block_number(block::<T>(START_BLOCK));
+	Voting::<T>::start_election(RawOrigin::Signed(admin.clone()).into(), election_id)?;
+	Ok(())
+}
+
+/// Start a ranked-choice election with the maximum number of self-registered candidates, whose
+/// bonds have to be settled when it is reset, and cast `voters` ranked ballots in it.
+fn election_to_reset<T: Config>(
+	admin: &T::AccountId,
+	voters: u32,
+) -> Result<ElectionId, BenchmarkError> {
+	let election_id = create::<T>(admin, ElectionKind::RankedChoice, false, None)?;
+	let candidates = (0..T::MaxCandidates::get())
+		.map(|index| {
+			let candidate_id = NextCandidateId::<T>::get(election_id);
+			Voting::<T>::register_as_candidate(
+				RawOrigin::Signed(funded_account::<T>("candidate", index)).into(),
+				election_id,
+				max_name::<T>(),
+				max_description::<T>(),
+			)?;
+			Ok(candidate_id)
+		})
+		.collect::<Result<Vec<_>, BenchmarkError>>()?;
+	start::<T>(admin, election_id)?;
+	cast_ranked_ballots::<T>(election_id, &candidates, voters)?;
+	Ok(election_id)
+}
+
+/// Cast `count` ranked ballots over all `candidates`, rotating the first preference so that the
+/// instant-runoff tally needs several rounds.
+fn cast_ranked_ballots<T: Config>(
+	election_id: ElectionId,
+	candidates: &[u32],
+	count: u32,
+) -> Result<(), BenchmarkError> {
+	for index in 0..count {
+		let mut rankings = candidates.to_vec();
+		rankings.rotate_left(index as usize % candidates.len());
+		Voting::<T>::cast_ranked_vote(
+			RawOrigin::Signed(voter::<T>(index)).into(),
+			election_id,
+			rankings,
+		)?;
+	}
+	Ok(())
+}
+
+/// Delegate the votes of `count` registered accounts to `delegate`.
+fn delegate_to<T: Config>(delegate: &T::AccountId, count: u32) -> Result<(), BenchmarkError> {
+	for index in 0..count {
+		let delegator: T::AccountId = account("delegator", index, SEED);
+		RegisteredVoters::<T>::insert(&delegator, ());
+		RegisteredVoterCount::<T>::mutate(|count| *count = count.saturating_add(1));
+		Voting::<T>::delegate_vote(RawOrigin::Signed(delegator).into(), delegate.clone())?;
+	}
+	Ok(())
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn create_election() {
+		let admin = admin::<T>();
+		before_window::<T>();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(admin.clone()),
+			max_title::<T>(),
+			window::<T>(),
+			ElectionKind::Plurality,
+			true,
+			Some(block::<T>(REVEAL_END_BLOCK)),
+			1,
+			false,
+			false,
+			Some(max_metadata_uri()),
+			Some(T::MaxCandidates::get()),
+		);
+
+		assert!(Election::<T>::contains_key(0));
+		assert_eq!(T::Currency::reserved_balance(&admin), T::ElectionDeposit::get());
+	}
+
+	#[benchmark]
+	fn add_candidate(c: Linear<0, { T::MaxCandidates::get() - 1 }>) -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id = create::<T>(&admin, ElectionKind::Plurality, false, None)?;
+		add_candidates::<T>(&admin, election_id, c)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(admin), election_id, max_name::<T>(), max_description::<T>());
+
+		assert_eq!(Candidates::<T>::get(election_id).len() as u32, c + 1);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn start_election() -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id = create::<T>(&admin, ElectionKind::Plurality, false, None)?;
+		add_candidates::<T>(&admin, election_id, T::MinCandidates::get().max(1))?;
+		Voting::<T>::lock_candidates(RawOrigin::Signed(admin.clone()).into(), election_id)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(admin), election_id);
+
+		assert!(Election::<T>::get(election_id).is_some_and(|e| e.status == ElectionStatus::Active));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cast_vote(d: Linear<0, { T::MaxDelegators::get() }>) -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id = create::<T>(&admin, ElectionKind::Plurality, true, None)?;
+		let candidates = add_candidates::<T>(&admin, election_id, T::MaxCandidates::get())?;
+		start::<T>(&admin, election_id)?;
+		let voter = voter::<T>(0);
+		T::Currency::make_free_balance_be(&voter, BalanceOf::<T>::max_value() / 2u32.into());
+		delegate_to::<T>(&voter, d)?;
+		let candidate_id = *candidates.last().ok_or(BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter.clone()), election_id, candidate_id);
+
+		assert_eq!(Voting::<T>::get_vote_for_account(election_id, &voter), Some(candidate_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn end_election() -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id = create::<T>(&admin, ElectionKind::Plurality, false, None)?;
+		start::<T>(&admin, election_id)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(admin), election_id);
+
+		assert!(Election::<T>::get(election_id).is_some_and(|e| e.status == ElectionStatus::Ended));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn finalize_election(v: Linear<0, 1_000>) -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id = create::<T>(&admin, ElectionKind::RankedChoice, false, None)?;
+		let candidates = add_candidates::<T>(&admin, election_id, T::MaxCandidates::get())?;
+		start::<T>(&admin, election_id)?;
+		cast_ranked_ballots::<T>(election_id, &candidates, v)?;
+		Voting::<T>::end_election(RawOrigin::Signed(admin).into(), election_id)?;
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, election_id, v);
+
+		assert!(
+			Election::<T>::get(election_id).is_some_and(|e| e.status == ElectionStatus::Finalized)
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn reset_election(v: Linear<0, 1_000>) -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id = election_to_reset::<T>(&admin, v)?;
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		Voting::<T>::end_election(RawOrigin::Signed(admin).into(), election_id)?;
+		Voting::<T>::finalize_election(origin.clone(), election_id, v)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, election_id);
+
+		assert!(!Election::<T>::contains_key(election_id));
+		assert_eq!(CandidateOwners::<T>::iter_prefix(election_id).count(), 0);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_candidate() -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id = create::<T>(&admin, ElectionKind::Plurality, false, None)?;
+		for index in 0..T::MaxCandidates::get() {
+			Voting::<T>::register_as_candidate(
+				RawOrigin::Signed(funded_account::<T>("candidate", index)).into(),
+				election_id,
+				max_name::<T>(),
+				max_description::<T>(),
+			)?;
+		}
+		let candidate_id = NextCandidateId::<T>::get(election_id) - 1;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(admin), election_id, candidate_id);
+
+		assert_eq!(
+			CandidateOwners::<T>::iter_prefix(election_id).count() as u32,
+			T::MaxCandidates::get() - 1
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn update_candidate() -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id = create::<T>(&admin, ElectionKind::Plurality, false, None)?;
+		let candidates = add_candidates::<T>(&admin, election_id, T::MaxCandidates::get())?;
+		let candidate_id = *candidates.last().ok_or(BenchmarkError::Weightless)?;
+		let name = vec![b'y'; T::MaxNameLength::get() as usize];
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(admin),
+			election_id,
+			candidate_id,
+			name.clone(),
+			max_description::<T>(),
+		);
+
+		assert!(Candidates::<T>::get(election_id).iter().any(|c| c.name.to_vec() == name));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn change_vote() -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id = create::<T>(&admin, ElectionKind::Plurality, false, None)?;
+		let candidates = add_candidates::<T>(&admin, election_id, T::MaxCandidates::get())?;
+		start::<T>(&admin, election_id)?;
+		let voter = voter::<T>(0);
+		Voting::<T>::cast_vote(RawOrigin::Signed(voter.clone()).into(), election_id, ABSTAIN)?;
+		let candidate_id = *candidates.last().ok_or(BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter.clone()), election_id, candidate_id);
+
+		assert_eq!(Voting::<T>::get_vote_for_account(election_id, &voter), Some(candidate_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn retract_vote(d: Linear<0, { T::MaxDelegators::get() }>) -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id = create::<T>(&admin, ElectionKind::Approval, false, None)?;
+		let candidates = add_candidates::<T>(&admin, election_id, T::MaxCandidates::get())?;
+		start::<T>(&admin, election_id)?;
+		let voter = voter::<T>(0);
+		delegate_to::<T>(&voter, d)?;
+		Voting::<T>::cast_approval_vote(
+			RawOrigin::Signed(voter.clone()).into(),
+			election_id,
+			candidates,
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter.clone()), election_id);
+
+		assert!(!Voting::<T>::has_account_voted(election_id, &voter));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cast_ranked_vote(d: Linear<0, { T::MaxDelegators::get() }>) -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id = create::<T>(&admin, ElectionKind::RankedChoice, true, None)?;
+		let candidates = add_candidates::<T>(&admin, election_id, T::MaxCandidates::get())?;
+		start::<T>(&admin, election_id)?;
+		let voter = voter::<T>(0);
+		T::Currency::make_free_balance_be(&voter, BalanceOf::<T>::max_value() / 2u32.into());
+		delegate_to::<T>(&voter, d)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter.clone()), election_id, candidates);
+
+		assert!(Rankings::<T>::contains_key(election_id, &voter));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn commit_vote() -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id =
+			create::<T>(&admin, ElectionKind::Plurality, false, Some(REVEAL_END_BLOCK))?;
+		start::<T>(&admin, election_id)?;
+		let voter = voter::<T>(0);
+		let commitment = T::Hashing::hash_of(&(ABSTAIN, [0u8; 32]));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter.clone()), election_id, commitment);
+
+		assert_eq!(Commitments::<T>::get(election_id, &voter), Some(commitment));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn reveal_vote() -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id =
+			create::<T>(&admin, ElectionKind::Plurality, true, Some(REVEAL_END_BLOCK))?;
+		let candidates = add_candidates::<T>(&admin, election_id, T::MaxCandidates::get())?;
+		start::<T>(&admin, election_id)?;
+		let voter = voter::<T>(0);
+		T::Currency::make_free_balance_be(&voter, BalanceOf::<T>::max_value() / 2u32.into());
+		let candidate_id = *candidates.last().ok_or(BenchmarkError::Weightless)?;
+		let salt = [7u8; 32];
+		Voting::<T>::commit_vote(
+			RawOrigin::Signed(voter.clone()).into(),
+			election_id,
+			T::Hashing::hash_of(&(candidate_id, salt)),
+		)?;
+		Voting::<T>::end_election(RawOrigin::Signed(admin).into(), election_id)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter.clone()), election_id, candidate_id, salt);
+
+		assert_eq!(Voting::<T>::get_vote_for_account(election_id, &voter), Some(candidate_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn register_voter() -> Result<(), BenchmarkError> {
+		let who: T::AccountId = account("voter", 0, SEED);
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, who.clone());
+
+		assert!(RegisteredVoters::<T>::contains_key(&who));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn deregister_voter() -> Result<(), BenchmarkError> {
+		let who = voter::<T>(0);
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, who.clone());
+
+		assert!(!RegisteredVoters::<T>::contains_key(&who));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn add_admin() -> Result<(), BenchmarkError> {
+		let admins: Vec<T::AccountId> =
+			(1..16).map(|index| account("admin", index, SEED)).collect();
+		Admins::<T>::put(BoundedVec::truncate_from(admins));
+		let who: T::AccountId = account("admin", 0, SEED);
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, who.clone());
+
+		assert!(Admins::<T>::get().contains(&who));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_admin() -> Result<(), BenchmarkError> {
+		let admins: Vec<T::AccountId> =
+			(0..16).map(|index| account("admin", index, SEED)).collect();
+		Admins::<T>::put(BoundedVec::truncate_from(admins));
+		let who: T::AccountId = account("admin", 15, SEED);
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, who.clone());
+
+		assert!(!Admins::<T>::get().contains(&who));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn register_as_candidate() -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id = create::<T>(&admin, ElectionKind::Plurality, false, None)?;
+		add_candidates::<T>(&admin, election_id, T::MaxCandidates::get() - 1)?;
+		let who = funded_account::<T>("candidate", 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who.clone()), election_id, max_name::<T>(), max_description::<T>());
+
+		assert!(CandidateOwners::<T>::contains_key(election_id, &who));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn withdraw_candidacy() -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id = create::<T>(&admin, ElectionKind::Plurality, false, None)?;
+		add_candidates::<T>(&admin, election_id, T::MaxCandidates::get() - 1)?;
+		let who = funded_account::<T>("candidate", 0);
+		Voting::<T>::register_as_candidate(
+			RawOrigin::Signed(who.clone()).into(),
+			election_id,
+			max_name::<T>(),
+			max_description::<T>(),
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who.clone()), election_id);
+
+		assert!(!CandidateOwners::<T>::contains_key(election_id, &who));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn delegate_vote() -> Result<(), BenchmarkError> {
+		let delegator: T::AccountId = account("delegator", T::MaxDelegators::get(), SEED);
+		let previous = voter::<T>(0);
+		let delegate = voter::<T>(1);
+		Voting::<T>::delegate_vote(RawOrigin::Signed(delegator.clone()).into(), previous)?;
+		delegate_to::<T>(&delegate, T::MaxDelegators::get() - 1)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(delegator.clone()), delegate.clone());
+
+		assert_eq!(Delegations::<T>::get(&delegator), Some(delegate));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn undelegate() -> Result<(), BenchmarkError> {
+		let delegate = voter::<T>(0);
+		delegate_to::<T>(&delegate, T::MaxDelegators::get())?;
+		let delegator: T::AccountId = account("delegator", 0, SEED);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(delegator.clone()));
+
+		assert!(!Delegations::<T>::contains_key(&delegator));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn extend_election() -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id =
+			create::<T>(&admin, ElectionKind::Plurality, false, Some(REVEAL_END_BLOCK))?;
+		start::<T>(&admin, election_id)?;
+		let new_end_block = block::<T>(REVEAL_END_BLOCK - 1);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(admin), election_id, new_end_block);
+
+		assert!(Election::<T>::get(election_id).is_some_and(
+			|e| matches!(e.window, VotingWindow::Blocks { end, .. } if end == new_end_block)
+		));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn pause_election() -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id = create::<T>(&admin, ElectionKind::Plurality, false, None)?;
+		start::<T>(&admin, election_id)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(admin), election_id);
+
+		assert!(Election::<T>::get(election_id).is_some_and(|e| e.status == ElectionStatus::Paused));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn resume_election() -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id = create::<T>(&admin, ElectionKind::Plurality, false, None)?;
+		start::<T>(&admin, election_id)?;
+		Voting::<T>::pause_election(RawOrigin::Signed(admin.clone()).into(), election_id)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(admin), election_id);
+
+		assert!(Election::<T>::get(election_id).is_some_and(|e| e.status == ElectionStatus::Active));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn batch_add_candidates(
+		n: Linear<1, { T::MaxCandidates::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id = create::<T>(&admin, ElectionKind::Plurality, false, None)?;
+		add_candidates::<T>(&admin, election_id, T::MaxCandidates::get() - n)?;
+		let candidates =
+			(0..n).map(|_| (max_name::<T>(), max_description::<T>())).collect::<Vec<_>>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(admin), election_id, candidates);
+
+		assert_eq!(Candidates::<T>::get(election_id).len() as u32, T::MaxCandidates::get());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn force_finalize(v: Linear<0, 1_000>) -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id = create::<T>(&admin, ElectionKind::RankedChoice, false, None)?;
+		let candidates = add_candidates::<T>(&admin, election_id, T::MaxCandidates::get())?;
+		start::<T>(&admin, election_id)?;
+		cast_ranked_ballots::<T>(election_id, &candidates, v)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, election_id, v);
+
+		assert!(
+			Election::<T>::get(election_id).is_some_and(|e| e.status == ElectionStatus::Finalized)
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_election() -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id = create::<T>(&admin, ElectionKind::Plurality, false, None)?;
+		for index in 0..T::MaxCandidates::get() {
+			Voting::<T>::register_as_candidate(
+				RawOrigin::Signed(funded_account::<T>("candidate", index)).into(),
+				election_id,
+				max_name::<T>(),
+				max_description::<T>(),
+			)?;
+		}
+		start::<T>(&admin, election_id)?;
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let reason = vec![b'x'; T::MaxReasonLength::get() as usize];
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, election_id, reason);
+
+		assert!(
+			Election::<T>::get(election_id).is_some_and(|e| e.status == ElectionStatus::Cancelled)
+		);
+		assert_eq!(CandidateOwners::<T>::iter_prefix(election_id).count(), 0);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cast_unlisted_vote() -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id = NextElectionId::<T>::get();
+		before_window::<T>();
+		Voting::<T>::create_election(
+			RawOrigin::Signed(admin.clone()).into(),
+			max_title::<T>(),
+			window::<T>(),
+			ElectionKind::Plurality,
+			true,
+			None,
+			1,
+			true,
+			false,
+			None,
+			None,
+		)?;
+		let candidates = add_candidates::<T>(&admin, election_id, T::MaxCandidates::get())?;
+		start::<T>(&admin, election_id)?;
+		let voter = voter::<T>(0);
+		T::Currency::make_free_balance_be(&voter, BalanceOf::<T>::max_value() / 2u32.into());
+		let candidate_id = *candidates.last().ok_or(BenchmarkError::Weightless)?;
+		let nullifier = Pallet::<T>::nullifier(election_id, &voter);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter), election_id, candidate_id);
+
+		assert!(Nullifiers::<T>::contains_key(election_id, nullifier));
+		assert_eq!(
+			VoteCount::<T>::get(election_id, candidate_id),
+			TotalVotes::<T>::get(election_id)
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cast_write_in_vote(d: Linear<0, { T::MaxDelegators::get() }>) -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id = NextElectionId::<T>::get();
+		before_window::<T>();
+		Voting::<T>::create_election(
+			RawOrigin::Signed(admin.clone()).into(),
+			max_title::<T>(),
+			window::<T>(),
+			ElectionKind::Plurality,
+			true,
+			None,
+			1,
+			false,
+			true,
+			None,
+			None,
+		)?;
+		add_candidates::<T>(&admin, election_id, T::MaxCandidates::get() - 1)?;
+		start::<T>(&admin, election_id)?;
+		let voter = voter::<T>(0);
+		T::Currency::make_free_balance_be(&voter, BalanceOf::<T>::max_value() / 2u32.into());
+		delegate_to::<T>(&voter, d)?;
+		// A name that differs from those of the standing candidates only in its last byte.
+		let mut name = max_name::<T>();
+		if let Some(last) = name.last_mut() {
+			*last = b'y';
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter.clone()), election_id, name);
+
+		assert_eq!(Voting::<T>::candidate_count(election_id), T::MaxCandidates::get());
+		assert!(Voting::<T>::get_vote_for_account(election_id, &voter).is_some());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn lock_candidates() -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id = create::<T>(&admin, ElectionKind::Plurality, false, None)?;
+		add_candidates::<T>(&admin, election_id, T::MaxCandidates::get())?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(admin), election_id);
+
+		assert!(CandidatesLocked::<T>::get(election_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn reopen_election() -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id = create::<T>(&admin, ElectionKind::Plurality, false, None)?;
+		add_candidates::<T>(&admin, election_id, 1)?;
+		start::<T>(&admin, election_id)?;
+		Voting::<T>::end_election(RawOrigin::Signed(admin).into(), election_id)?;
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		Voting::<T>::finalize_election(origin, election_id, 0)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, election_id);
+
+		assert!(Election::<T>::get(election_id)
+			.is_some_and(|e| e.status == ElectionStatus::Ended && e.reopen_count == 1));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn request_reset() -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id = create::<T>(&admin, ElectionKind::Plurality, false, None)?;
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		if !T::AllowResetDuringVoting::get() {
+			Voting::<T>::cancel_election(origin.clone(), election_id, Vec::new())?;
+		}
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, election_id);
+
+		assert!(PendingResets::<T>::contains_key(election_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn confirm_reset(v: Linear<0, 1_000>) -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id = election_to_reset::<T>(&admin, v)?;
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		// Unfinalized elections, whose deposit is slashed, are the heavier ones to reset.
+		if !T::AllowResetDuringVoting::get() {
+			Voting::<T>::end_election(RawOrigin::Signed(admin).into(), election_id)?;
+			Voting::<T>::finalize_election(origin.clone(), election_id, v)?;
+		}
+		let nonce = NextResetNonce::<T>::get();
+		Voting::<T>::request_reset(origin.clone(), election_id)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, election_id, nonce);
+
+		assert!(!Election::<T>::contains_key(election_id));
+		assert!(!PendingResets::<T>::contains_key(election_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn adjust_vote_count() -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id = create::<T>(&admin, ElectionKind::Plurality, false, None)?;
+		let candidate_id = add_candidates::<T>(&admin, election_id, 1)?[0];
+		start::<T>(&admin, election_id)?;
+		Voting::<T>::end_election(RawOrigin::Signed(admin).into(), election_id)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, election_id, candidate_id, 1);
+
+		assert_eq!(VoteCount::<T>::get(election_id, candidate_id), 1);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_results_hidden() -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id = create::<T>(&admin, ElectionKind::Plurality, false, None)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(admin), election_id, true);
+
+		assert!(Voting::<T>::results_hidden(election_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_election_metadata() -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id = create::<T>(&admin, ElectionKind::Plurality, false, None)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(admin), election_id, Some(max_metadata_uri()));
+
+		assert!(Election::<T>::get(election_id).is_some_and(|e| e.metadata_uri.is_some()));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn reset_votes(v: Linear<0, 1_000>) -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id = election_to_reset::<T>(&admin, v)?;
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		Voting::<T>::end_election(RawOrigin::Signed(admin).into(), election_id)?;
+		Voting::<T>::finalize_election(origin.clone(), election_id, v)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, election_id);
+
+		// The election is only back to `Created` once the last batch of votes has been removed.
+		assert!(Election::<T>::get(election_id)
+			.is_some_and(|e| matches!(e.status, ElectionStatus::Ended | ElectionStatus::Created)));
+		assert_eq!(TotalVotes::<T>::get(election_id), 0);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_win_threshold() -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id = create::<T>(&admin, ElectionKind::Plurality, false, None)?;
+		let threshold = Some(Permill::from_percent(50));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(admin), election_id, threshold);
+
+		assert!(Election::<T>::get(election_id).is_some_and(|e| e.win_threshold == threshold));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cast_approval_vote(d: Linear<0, { T::MaxDelegators::get() }>) -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id = create::<T>(&admin, ElectionKind::Approval, true, None)?;
+		let candidates = add_candidates::<T>(&admin, election_id, T::MaxCandidates::get())?;
+		start::<T>(&admin, election_id)?;
+		let voter = voter::<T>(0);
+		T::Currency::make_free_balance_be(&voter, BalanceOf::<T>::max_value() / 2u32.into());
+		delegate_to::<T>(&voter, d)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter.clone()), election_id, candidates);
+
+		assert!(Approvals::<T>::contains_key(election_id, &voter));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn transfer_admin() -> Result<(), BenchmarkError> {
+		let admins: Vec<T::AccountId> =
+			(0..16).map(|index| account("admin", index, SEED)).collect();
+		Admins::<T>::put(BoundedVec::truncate_from(admins));
+		let who: T::AccountId = account("admin", 15, SEED);
+		let new_admin: T::AccountId = account("admin", 16, SEED);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who.clone()), new_admin.clone());
+
+		let admins = Admins::<T>::get();
+		assert!(admins.contains(&new_admin) && !admins.contains(&who));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_dispute_window() -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id = create::<T>(&admin, ElectionKind::Plurality, false, None)?;
+		let dispute_window = block::<T>(END_BLOCK);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(admin), election_id, dispute_window);
+
+		assert!(Election::<T>::get(election_id).is_some_and(|e| e.dispute_window == dispute_window));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn flag_dispute() -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id = create::<T>(&admin, ElectionKind::Plurality, false, None)?;
+		Voting::<T>::set_dispute_window(
+			RawOrigin::Signed(admin.clone()).into(),
+			election_id,
+			block::<T>(END_BLOCK),
+		)?;
+		start::<T>(&admin, election_id)?;
+		Voting::<T>::end_election(RawOrigin::Signed(admin.clone()).into(), election_id)?;
+		let reason = vec![b'x'; T::MaxReasonLength::get() as usize];
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(admin), election_id, reason);
+
+		assert!(Disputes::<T>::contains_key(election_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn resolve_dispute() -> Result<(), BenchmarkError> {
+		let election_id = 0;
+		Disputes::<T>::insert(election_id, BoundedVec::truncate_from(vec![b'x'; 1]));
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, election_id);
+
+		assert!(!Disputes::<T>::contains_key(election_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn endorse_candidate() -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id = create::<T>(&admin, ElectionKind::Plurality, false, None)?;
+		let candidate_id = NextCandidateId::<T>::get(election_id);
+		Voting::<T>::register_as_candidate(
+			RawOrigin::Signed(funded_account::<T>("candidate", 0)).into(),
+			election_id,
+			max_name::<T>(),
+			max_description::<T>(),
+		)?;
+		let endorser = voter::<T>(0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(endorser.clone()), election_id, candidate_id);
+
+		assert!(Endorsements::<T>::contains_key(election_id, (candidate_id, endorser)));
+		assert_eq!(EndorsementCount::<T>::get(election_id, candidate_id), 1);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cast_quadratic_vote() -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let election_id = create::<T>(&admin, ElectionKind::Quadratic, false, None)?;
+		// A single vote for every candidate that the budget pays for.
+		let count = T::MaxCandidates::get().min(T::VoteCreditBudget::get()).max(1);
+		let candidates = add_candidates::<T>(&admin, election_id, count)?;
+		start::<T>(&admin, election_id)?;
+		let voter = voter::<T>(0);
+		let votes: Vec<(u32, u32)> = candidates.into_iter().map(|id| (id, 1)).collect();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter.clone()), election_id, votes);
+
+		assert!(SpentCredits::<T>::get(election_id, &voter) > 0);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn unlock_vote_balance(l: Linear<1, { T::MaxVoteLocks::get() }>) -> Result<(), BenchmarkError> {
+		let admin = admin::<T>();
+		let voter = voter::<T>(0);
+		T::Currency::make_free_balance_be(&voter, BalanceOf::<T>::max_value() / 2u32.into());
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		// The balance is locked for `l` elections, all of which have been closed since.
+		for _ in 0..l {
+			let election_id = create::<T>(&admin, ElectionKind::Plurality, true, None)?;
+			let candidate_id = NextCandidateId::<T>::get(election_id);
+			start::<T>(&admin, election_id)?;
+			Voting::<T>::cast_vote(
+				RawOrigin::Signed(voter.clone()).into(),
+				election_id,
+				candidate_id,
+			)?;
+			Voting::<T>::cancel_election(origin.clone(), election_id, vec![b'x'])?;
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(admin), voter.clone());
+
+		assert!(!VoteLocks::<T>::contains_key(&voter));
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(Voting, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,113 @@
+//! Transaction extensions of the voting pallet.
+
+use crate::{Call, Commitments, Config, HasVoted, Nullifiers, Pallet};
+use codec::{Decode, DecodeWithMemTracking, Encode};
+use core::marker::PhantomData;
+use frame_support::{
+	pallet_prelude::{TransactionSource, Weight},
+	sp_runtime::{
+		impl_tx_ext_default,
+		traits::{DispatchInfoOf, TransactionExtension, ValidateResult},
+		transaction_validity::InvalidTransaction,
+	},
+	traits::{Get, IsSubType, OriginTrait},
+	DefaultNoBound,
+};
+use scale_info::TypeInfo;
+
+/// The custom [`InvalidTransaction`] code of a vote from an account that already voted.
+pub const ALREADY_VOTED: u8 = 0;
+
+/// Reject the transactions casting, committing or revealing a vote from accounts that already
+/// voted in the election.
+///
+/// The same check is made when the vote is dispatched, so this only keeps votes that would fail
+/// out of the transaction pool and out of blocks.
+#[derive(Encode, Decode, DecodeWithMemTracking, DefaultNoBound, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckNotAlreadyVoted<T>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> core::fmt::Debug for CheckNotAlreadyVoted<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "CheckNotAlreadyVoted")
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut core::fmt::Formatter) -> core::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Config + Send + Sync> CheckNotAlreadyVoted<T> {
+	/// Create a new `CheckNotAlreadyVoted` extension.
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+
+	/// Whether `call` votes in an election that `who` already voted in.
+	fn already_voted(call: &Call<T>, who: &T::AccountId) -> bool {
+		match call {
+			Call::cast_vote { election_id, .. }
+			| Call::cast_ranked_vote { election_id, .. }
+			| Call::cast_approval_vote { election_id, .. }
+			| Call::cast_quadratic_vote { election_id, .. }
+			| Call::cast_write_in_vote { election_id, .. }
+			| Call::reveal_vote { election_id, .. } => HasVoted::<T>::contains_key(election_id, who),
+			Call::commit_vote { election_id, .. } =>
+				Commitments::<T>::contains_key(election_id, who),
+			Call::cast_unlisted_vote { election_id, .. } => Nullifiers::<T>::contains_key(
+				election_id,
+				Pallet::<T>::nullifier(*election_id, who),
+			),
+			_ => false,
+		}
+	}
+}
+
+impl<T: Config + Send + Sync> TransactionExtension<<T as frame_system::Config>::RuntimeCall>
+	for CheckNotAlreadyVoted<T>
+where
+	<T as frame_system::Config>::RuntimeCall: IsSubType<Call<T>>,
+{
+	const IDENTIFIER: &'static str = "CheckNotAlreadyVoted";
+	type Implicit = ();
+	type Val = ();
+	type Pre = ();
+
+	fn weight(&self, call: &<T as frame_system::Config>::RuntimeCall) -> Weight {
+		match call.is_sub_type() {
+			Some(
+				Call::cast_vote { .. }
+				| Call::cast_ranked_vote { .. }
+				| Call::cast_approval_vote { .. }
+				| Call::cast_quadratic_vote { .. }
+				| Call::cast_write_in_vote { .. }
+				| Call::reveal_vote { .. }
+				| Call::commit_vote { .. }
+				| Call::cast_unlisted_vote { .. },
+			) => T::DbWeight::get().reads(1),
+			_ => Weight::zero(),
+		}
+	}
+
+	fn validate(
+		&self,
+		origin: <T as frame_system::Config>::RuntimeOrigin,
+		call: &<T as frame_system::Config>::RuntimeCall,
+		_info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+		_len: usize,
+		_self_implicit: Self::Implicit,
+		_inherited_implication: &impl Encode,
+		_source: TransactionSource,
+	) -> ValidateResult<Self::Val, <T as frame_system::Config>::RuntimeCall> {
+		if let (Some(who), Some(call)) = (origin.as_signer(), call.is_sub_type()) {
+			if Self::already_voted(call, who) {
+				return Err(InvalidTransaction::Custom(ALREADY_VOTED).into())
+			}
+		}
+		Ok((Default::default(), (), origin))
+	}
+
+	impl_tx_ext_default!(<T as frame_system::Config>::RuntimeCall; prepare);
+}
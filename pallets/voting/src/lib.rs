@@ -0,0 +1,5036 @@
+//! # Voting Pallet
+//!
+//! A pallet for running on-chain elections, such as student council or club elections on a
+//! campus network.
+//!
+//! ## Overview
+//!
+//! Several elections can run side by side. Each one is identified by an [`ElectionId`] handed out
+//! by [`Pallet::create_election`] and goes through the following lifecycle:
+//!
+//! 1. An administrator creates the election with a title and a [`VotingWindow`] expressed in block
+//!    numbers or in timestamps.
+//! 2. Candidates are added to the election.
+//! 3. The election is started, after which any signed account may cast a single vote for one of the
+//!    candidates while the current block or time is inside the voting window.
+//! 4. The election is ended and then finalized, freezing the results.
+//!
+//! Plurality elections can also be run in commit-reveal mode, in which case voters only submit a
+//! hash of their choice with [`Pallet::commit_vote`] while voting is open, and disclose it with
+//! [`Pallet::reveal_vote`] after the election has ended and before its `reveal_end_block`. Votes
+//! are only counted once they are revealed, so no running tally is visible during the election.
+//!
+//! An election and all of its storage can be removed again with [`Pallet::reset_election`].
+//!
+//! Run `cargo doc --package pallet-voting --open` to view this pallet's documentation.
+
+// We make sure this pallet uses `no_std` for compiling to Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod extensions;
+pub mod migrations;
+pub mod weights;
+pub use extensions::CheckNotAlreadyVoted;
+pub use weights::*;
+
+// The calls that create elections take one argument per election setting.
+#[allow(clippy::too_many_arguments)]
+#[frame_support::pallet]
+pub mod pallet {
+	use alloc::{vec, vec::Vec};
+	#[cfg(feature = "scheduler")]
+	use frame_support::traits::{
+		schedule::{v3::Named as ScheduleNamed, v3::TaskName, DispatchTime, LOWEST_PRIORITY},
+		Bounded, BoundedInline,
+	};
+	use frame_support::{
+		pallet_prelude::*,
+		sp_runtime::{
+			traits::{BlakeTwo256, Convert, Hash, Saturating, TrailingZeroInput, Zero},
+			PerThing, Permill, Rounding,
+		},
+		traits::{
+			BalanceStatus, Currency, LockIdentifier, LockableCurrency, Randomness,
+			ReservableCurrency, Time, WithdrawReasons,
+		},
+	};
+	use frame_system::pallet_prelude::*;
+
+	use crate::weights::WeightInfo;
+
+	/// Identifier of an election.
+	pub type ElectionId = u32;
+
+	/// The reserved candidate identifier for explicitly abstaining in a plurality election.
+	///
+	/// Abstentions count towards [`TotalVotes`] but never win an election.
+	pub const ABSTAIN: u32 = 0;
+
+	/// The maximum number of votes recorded in the [`VoteLog`] of a candidate. Later votes are
+	/// counted but no longer logged.
+	pub const MAX_VOTE_LOG_LENGTH: u32 = 1_000;
+
+	/// The maximum length of the storage key kept in [`ResetCursor`] to resume clearing a map.
+	pub const MAX_RESET_CURSOR_LENGTH: u32 = 256;
+
+	/// The maximum length of the [`ElectionInfo::metadata_uri`] of an election.
+	pub const MAX_METADATA_URI_LENGTH: u32 = 256;
+
+	/// The number of maps with an entry per vote that are cleared when the votes of an election
+	/// are reset.
+	const VOTE_STAGES: u8 = 11;
+
+	/// The number of maps that are cleared when an election is reset: the maps with an entry per
+	/// vote, followed by [`Endorsements`] and [`EndorsementCount`].
+	const RESET_STAGES: u8 = VOTE_STAGES + 2;
+
+	/// The identifier of the lock on the balances that weighted votes were counted with.
+	pub const VOTE_LOCK_ID: LockIdentifier = *b"votelock";
+
+	/// The balance type of the currency used to weight votes.
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	/// The timestamp type of [`Config::Time`].
+	pub type MomentOf<T> = <<T as Config>::Time as Time>::Moment;
+
+	/// The voting window of an election of the runtime.
+	pub type VotingWindowOf<T> = VotingWindow<BlockNumberFor<T>, MomentOf<T>>;
+
+	/// The vote of an account of the runtime.
+	pub type VoteRecordOf<T> = VoteRecord<BlockNumberFor<T>>;
+
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(22);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	/// The pallet's configuration trait.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching runtime event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The maximum number of candidates a single election can hold.
+		#[pallet::constant]
+		type MaxCandidates: Get<u32>;
+		/// The maximum length in bytes of candidate names.
+		#[pallet::constant]
+		type MaxNameLength: Get<u32>;
+		/// The maximum length in bytes of election titles.
+		#[pallet::constant]
+		type MaxTitleLength: Get<u32>;
+		/// The currency whose free balance determines the weight of votes in weighted elections.
+		///
+		/// The balance a vote was weighted by is locked until the election is closed, see
+		/// [`VoteLocks`]. The currency is also used to reserve the deposit for creating an
+		/// election.
+		type Currency: ReservableCurrency<Self::AccountId>
+			+ LockableCurrency<Self::AccountId, Moment = BlockNumberFor<Self>>;
+		/// Converts the free balance of a voter into the weight of their vote in weighted
+		/// elections.
+		type BalanceToVoteWeight: Convert<BalanceOf<Self>, u32>;
+		/// Whether only accounts in [`RegisteredVoters`] may vote.
+		#[pallet::constant]
+		type RequireRegistration: Get<bool>;
+		/// The origin allowed to manage elections, candidates and registered voters.
+		///
+		/// Bind it to `EnsureRoot` to keep election administration with sudo, or to a collective
+		/// or a specific account to delegate it.
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The deposit reserved from an election admin for creating an election.
+		///
+		/// Elections created by [`Config::AdminOrigin`] do not require a deposit.
+		#[pallet::constant]
+		type ElectionDeposit: Get<BalanceOf<Self>>;
+		/// The bond reserved from an account registering itself as a candidate.
+		#[pallet::constant]
+		type CandidateBond: Get<BalanceOf<Self>>;
+		/// The minimum number of votes, including abstentions, for the results of an election to
+		/// be valid.
+		#[pallet::constant]
+		type MinQuorum: Get<u32>;
+		/// A type representing the weights required by the dispatchables of this pallet.
+		type WeightInfo: WeightInfo;
+		/// The source of randomness used to break ties at finalization.
+		///
+		/// Tie breaks are only as unpredictable as this provider. Weak sources such as
+		/// `pallet_insecure_randomness_collective_flip` can be influenced by block producers.
+		type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
+		/// Whether a tie is broken with [`Config::Randomness`] at finalization. Otherwise the
+		/// election is finalized without a winner and [`Event::ElectionTied`] is emitted.
+		#[pallet::constant]
+		type RandomTieBreaking: Get<bool>;
+		/// The maximum number of accounts that can delegate their vote to a single account.
+		#[pallet::constant]
+		type MaxDelegators: Get<u32>;
+		/// Whether elections that have not been finalized may be removed through
+		/// [`Pallet::request_reset`] and [`Pallet::confirm_reset`].
+		///
+		/// Convenient on development chains. Production chains should disable it, so that a
+		/// mistaken call cannot wipe a live election.
+		#[pallet::constant]
+		type AllowResetDuringVoting: Get<bool>;
+		/// The clock of elections whose voting window is given in [`VotingWindow::Time`].
+		///
+		/// Usually `pallet_timestamp`. Runtimes that only run block-based elections can use any
+		/// other source, since it is never read for them.
+		type Time: Time;
+		/// The minimum number of candidates an election needs to be started. Elections can never
+		/// be started without candidates, even if this is `0`.
+		#[pallet::constant]
+		type MinCandidates: Get<u32>;
+		/// Tells which accounts have a verified on-chain identity, such as a judgement of at
+		/// least `Reasonable` in `pallet_identity`.
+		///
+		/// Only consulted if [`Config::RequireVerifiedIdentity`] is set, so runtimes without an
+		/// identity pallet can use `()`.
+		type IdentityProvider: IdentityVerifier<Self::AccountId>;
+		/// Whether only accounts with a verified identity, according to
+		/// [`Config::IdentityProvider`], may vote.
+		#[pallet::constant]
+		type RequireVerifiedIdentity: Get<bool>;
+		/// The number of blocks between two [`Event::TurnoutSnapshot`]s of every active election.
+		/// `0` disables the snapshots.
+		#[pallet::constant]
+		type TurnoutReportInterval: Get<BlockNumberFor<Self>>;
+		/// The maximum length in bytes of the reason given for cancelling or disputing an election.
+		#[pallet::constant]
+		type MaxReasonLength: Get<u32>;
+		/// The number of votes, or their total weight in weighted elections, after which an
+		/// election accepts no more ballots through [`Pallet::cast_vote`] and
+		/// [`Pallet::cast_ranked_vote`].
+		#[pallet::constant]
+		type MaxVoters: Get<u32>;
+		/// The number of blocks by which the voting window of a block-based election must start
+		/// after the block it is created in.
+		#[pallet::constant]
+		type MinCreationLead: Get<BlockNumberFor<Self>>;
+		/// Where the bonds of self-registered candidates go when their election is reset.
+		///
+		/// `None` returns every bond to its candidate. `Some` slashes the bonds into the free
+		/// balance of the given account, such as a treasury.
+		type SlashedFundsDestination: Get<Option<Self::AccountId>>;
+		/// Whether an account may stand as a self-registered candidate in several elections at
+		/// once.
+		///
+		/// Allowing it suits chains whose elections are sub-races of one another, such as the
+		/// offices of a club. Disallowing it keeps every account to one race at a time, as in a
+		/// student council, but also stops it from registering for the next election until its
+		/// candidacy in the previous one is withdrawn, removed or reset.
+		#[pallet::constant]
+		type AllowMultipleCandidaciesPerAccount: Get<bool>;
+		/// The number of candidates an election is expected to have once it is set up.
+		///
+		/// When an admin adds the candidate that brings an election to this number,
+		/// [`Event::CandidateSetComplete`] is emitted. `0` disables the event.
+		#[pallet::constant]
+		type ExpectedCandidates: Get<u32>;
+		/// Whether the block of every vote is recorded in [`VoteLog`].
+		///
+		/// The log lets dashboards plot when votes arrived, but also reveals when every vote for
+		/// a candidate was cast, which privacy-focused runtimes may want to avoid.
+		#[pallet::constant]
+		type EnableVoteLog: Get<bool>;
+		/// The maximum length in bytes of candidate descriptions.
+		#[pallet::constant]
+		type MaxDescriptionLength: Get<u32>;
+		/// The number of blocks after [`Pallet::request_reset`] in which the reset can be
+		/// confirmed through [`Pallet::confirm_reset`].
+		#[pallet::constant]
+		type ResetConfirmationWindow: Get<BlockNumberFor<Self>>;
+		/// The maximum number of votes and vote-related entries removed by a single call that
+		/// resets an election. Larger elections are reset over several calls, which should be
+		/// made in separate blocks: entries removed earlier in the same block count against the
+		/// batch again.
+		#[pallet::constant]
+		type ResetBatchSize: Get<u32>;
+		/// The number of blocks an account registered through [`Pallet::register_voter`] has to
+		/// wait before it can vote, which keeps freshly created accounts from voting right away.
+		/// `0` lets registered accounts vote immediately.
+		#[pallet::constant]
+		type VotingEligibilityDelay: Get<BlockNumberFor<Self>>;
+		/// The minimum length in bytes of the descriptions of candidates added by an admin or
+		/// registered by themselves. Write-in candidates, which have no description, are exempt.
+		#[pallet::constant]
+		type MinDescriptionLength: Get<u32>;
+		/// The maximum number of blocks a block-based voting window can span, also when it is
+		/// extended. This keeps elections from being created with an end block so far in the
+		/// future that they would never end on their own.
+		#[pallet::constant]
+		type MaxElectionDuration: Get<BlockNumberFor<Self>>;
+		/// Whether an [`Event::VoteCast`] is deposited for every vote.
+		///
+		/// Busy elections can fill the events of a block with votes. Without per-vote events, a
+		/// single [`Event::VotesBatchRecorded`] summarizes the votes of each block instead.
+		#[pallet::constant]
+		type EmitPerVoteEvents: Get<bool>;
+		/// The number of registered voters that have to endorse every candidate of an election
+		/// through [`Pallet::endorse_candidate`] before it can be started. `0` disables the
+		/// requirement.
+		#[pallet::constant]
+		type MinEndorsements: Get<u32>;
+		/// The number of credits every voter can spend on its votes in a quadratic election.
+		#[pallet::constant]
+		type VoteCreditBudget: Get<u32>;
+		/// The number of elections that can be waiting for their voting window to open or to
+		/// close at the same time, which bounds the work of [`Hooks::on_initialize`].
+		#[pallet::constant]
+		type MaxScheduledElections: Get<u32>;
+		/// The maximum number of weighted elections that the balance of an account can be locked
+		/// for at the same time, see [`VoteLocks`].
+		#[pallet::constant]
+		type MaxVoteLocks: Get<u32>;
+		/// The overarching call type, of which the finalizations scheduled through
+		/// [`Config::Scheduler`] are made.
+		#[cfg(feature = "scheduler")]
+		type RuntimeCall: From<Call<Self>> + Encode;
+		/// The origin of the calls dispatched by [`Config::Scheduler`].
+		#[cfg(feature = "scheduler")]
+		type PalletsOrigin: From<frame_system::RawOrigin<Self::AccountId>>;
+		/// Finalizes block-based elections once their voting window has closed, so that the
+		/// finalization does not have to wait for an admin and does not weigh on
+		/// [`Hooks::on_initialize`].
+		///
+		/// The finalization is dispatched as root, which [`Config::AdminOrigin`] has to accept.
+		#[cfg(feature = "scheduler")]
+		type Scheduler: ScheduleNamed<
+			BlockNumberFor<Self>,
+			<Self as Config>::RuntimeCall,
+			Self::PalletsOrigin,
+		>;
+		/// The number of blocks after the end of its voting window, or of its reveal period, at
+		/// which an election is finalized by [`Config::Scheduler`], or `None` to not schedule
+		/// any finalizations.
+		#[cfg(feature = "scheduler")]
+		type FinalizationDelay: Get<Option<BlockNumberFor<Self>>>;
+	}
+
+	/// A source of verified on-chain identities.
+	///
+	/// A runtime with `pallet_identity` can accept accounts that a registrar judged to be at
+	/// least `Reasonable`:
+	///
+	/// ```ignore
+	/// pub struct ReasonableIdentity;
+	/// impl pallet_voting::IdentityVerifier<AccountId> for ReasonableIdentity {
+	///     fn has_verified_identity(who: &AccountId) -> bool {
+	///         pallet_identity::IdentityOf::<Runtime>::get(who).is_some_and(|registration| {
+	///             registration.judgements.iter().any(|(_, judgement)| {
+	///                 matches!(judgement, Judgement::Reasonable | Judgement::KnownGood)
+	///             })
+	///         })
+	///     }
+	/// }
+	/// ```
+	pub trait IdentityVerifier<AccountId> {
+		/// Whether `who` has a verified identity.
+		fn has_verified_identity(who: &AccountId) -> bool;
+	}
+
+	/// No identities are verified.
+	impl<AccountId> IdentityVerifier<AccountId> for () {
+		fn has_verified_identity(_: &AccountId) -> bool {
+			false
+		}
+	}
+
+	/// How the ballots of an election are cast and tallied.
+	#[derive(
+		Encode,
+		Decode,
+		DecodeWithMemTracking,
+		Clone,
+		Copy,
+		PartialEq,
+		Eq,
+		Default,
+		RuntimeDebug,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	pub enum ElectionKind {
+		/// Every voter picks a single candidate and the candidate with the most votes wins.
+		#[default]
+		Plurality,
+		/// Every voter ranks candidates in order of preference and the winner is determined by
+		/// instant-runoff rounds.
+		RankedChoice,
+		/// Every voter approves any number of candidates, each of which receives the vote, and
+		/// the candidate with the most approvals wins.
+		Approval,
+		/// Every voter spends up to [`Config::VoteCreditBudget`] credits on votes for any number
+		/// of candidates, where `n` votes for a candidate cost `n * n` credits, and the candidate
+		/// with the most votes wins.
+		Quadratic,
+	}
+
+	/// The period in which an election accepts votes, measured in blocks or in time.
+	///
+	/// Votes are accepted from `start` until before `end`.
+	#[derive(
+		Encode,
+		Decode,
+		DecodeWithMemTracking,
+		Clone,
+		Copy,
+		PartialEq,
+		Eq,
+		RuntimeDebug,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	pub enum VotingWindow<BlockNumber, Moment> {
+		/// A window measured in block numbers.
+		Blocks {
+			/// First block at which votes are accepted.
+			start: BlockNumber,
+			/// Block from which votes are no longer accepted.
+			end: BlockNumber,
+		},
+		/// A window measured in timestamps of [`Config::Time`].
+		Time {
+			/// First timestamp at which votes are accepted.
+			start: Moment,
+			/// Timestamp from which votes are no longer accepted.
+			end: Moment,
+		},
+	}
+
+	impl<BlockNumber: PartialOrd, Moment: PartialOrd> VotingWindow<BlockNumber, Moment> {
+		/// Whether the window ends after it starts.
+		pub fn is_valid(&self) -> bool {
+			match self {
+				Self::Blocks { start, end } => end > start,
+				Self::Time { start, end } => end > start,
+			}
+		}
+
+		/// Whether the window has opened at block `block` and time `moment`.
+		pub fn has_opened(&self, block: &BlockNumber, moment: &Moment) -> bool {
+			match self {
+				Self::Blocks { start, .. } => block >= start,
+				Self::Time { start, .. } => moment >= start,
+			}
+		}
+
+		/// Whether the window has closed at block `block` and time `moment`.
+		pub fn has_closed(&self, block: &BlockNumber, moment: &Moment) -> bool {
+			match self {
+				Self::Blocks { end, .. } => block >= end,
+				Self::Time { end, .. } => moment >= end,
+			}
+		}
+	}
+
+	/// The configuration and lifecycle of an election.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct ElectionInfo<T: Config> {
+		/// Human readable title of the election.
+		pub title: BoundedVec<u8, T::MaxTitleLength>,
+		/// The period in which votes are accepted.
+		pub window: VotingWindowOf<T>,
+		/// How ballots are cast and tallied.
+		pub kind: ElectionKind,
+		/// Whether votes are weighted by the free balance of the voter instead of counting once.
+		pub weighted: bool,
+		/// Block from which committed votes can no longer be revealed, for elections run in
+		/// commit-reveal mode. `None` for elections whose votes are cast openly.
+		pub reveal_end_block: Option<BlockNumberFor<T>>,
+		/// The number of candidates the election fills. Ranked-choice elections have a single
+		/// seat.
+		pub seats: u32,
+		/// The number of candidates that can stand in the election, at most
+		/// [`Config::MaxCandidates`].
+		pub max_candidates: u32,
+		/// The stage of its lifecycle the election is in.
+		pub status: ElectionStatus,
+		/// Whether the election was finalized without reaching [`Config::MinQuorum`], in which
+		/// case no winner is declared.
+		pub is_failed: bool,
+		/// Whether votes are cast through [`Pallet::cast_unlisted_vote`], which records a
+		/// nullifier in [`Nullifiers`] instead of the vote of the account in [`HasVoted`].
+		///
+		/// Unlisted votes are not anonymous: the signed extrinsic shows the voter and the
+		/// candidate, and the nullifier of any account can be computed and looked up.
+		pub unlisted: bool,
+		/// Whether voters may vote for candidates that are not standing through
+		/// [`Pallet::cast_write_in_vote`].
+		pub allow_write_ins: bool,
+		/// How many times the election was reopened for a recount through
+		/// [`Pallet::reopen_election`].
+		pub reopen_count: u32,
+		/// Whether the results are withheld from [`Pallet::get_results`] and the runtime API
+		/// until the election is finalized, as set through [`Pallet::set_results_hidden`].
+		pub results_hidden: bool,
+		/// A pointer to off-chain documentation of the election, such as its rules or the
+		/// manifestos of its candidates.
+		pub metadata_uri: Option<BoundedVec<u8, ConstU32<MAX_METADATA_URI_LENGTH>>>,
+		/// The share of [`TotalVotes`] the winner of a single-seat election must reach, as set
+		/// through [`Pallet::set_win_threshold`]. Without a threshold the candidate with the most
+		/// votes wins however small its share.
+		pub win_threshold: Option<Permill>,
+		/// The number of blocks after the end of the voting window during which the results can
+		/// be disputed with [`Pallet::flag_dispute`], as set through
+		/// [`Pallet::set_dispute_window`]. The election cannot be finalized before it is over.
+		pub dispute_window: BlockNumberFor<T>,
+		/// The account that reserved a deposit for creating the election, and its amount.
+		pub deposit: Option<(T::AccountId, BalanceOf<T>)>,
+	}
+
+	/// A candidate standing in an election.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Candidate<T: Config> {
+		/// Identifier of the candidate, unique within its election.
+		pub id: u32,
+		/// Name of the candidate.
+		pub name: BoundedVec<u8, T::MaxNameLength>,
+		/// Short description or manifesto of the candidate.
+		pub description: BoundedVec<u8, T::MaxDescriptionLength>,
+		/// The account that registered itself as the candidate through
+		/// [`Pallet::register_as_candidate`], or `None` for candidates added by an admin.
+		pub owner: Option<T::AccountId>,
+	}
+
+	/// The vote of an account in an election.
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct VoteRecord<BlockNumber> {
+		/// The candidate voted for; the first preference in ranked-choice elections.
+		pub candidate_id: u32,
+		/// The weight that was added to the tally for this vote.
+		pub weight: u32,
+		/// The block in which the vote was cast, or last changed.
+		pub block: BlockNumber,
+	}
+
+	/// The record of a finalized or cancelled election kept in [`PastElections`].
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct ElectionSummary<T: Config> {
+		/// The identifier the election had while it was live.
+		pub election_id: ElectionId,
+		/// Human readable title of the election.
+		pub title: BoundedVec<u8, T::MaxTitleLength>,
+		/// The candidates that won the election, from most to fewest votes. Empty if the election
+		/// failed its quorum, ended in an unbroken tie or received no votes.
+		pub winners: BoundedVec<u32, T::MaxCandidates>,
+		/// The total number of votes cast in the election, including abstentions.
+		pub total_votes: u32,
+		/// The number of votes received by each candidate, as `(candidate_id, votes)`.
+		pub vote_counts: BoundedVec<(u32, u32), T::MaxCandidates>,
+		/// The number of votes cast for [`ABSTAIN`].
+		pub abstentions: u32,
+		/// The reason the election was cancelled for, or `None` if it was finalized.
+		pub cancellation_reason: Option<BoundedVec<u8, T::MaxReasonLength>>,
+	}
+
+	/// The stage of its lifecycle an election is in.
+	///
+	/// An election is created, started, possibly paused and resumed, ended and finalized, and
+	/// can be cancelled at any stage before it is finalized. Only created elections can be
+	/// started, and only ended ones finalized, except through [`Pallet::force_finalize`].
+	/// Finalized elections reopened through [`Pallet::reopen_election`] are ended again and can
+	/// only be finalized once more. Resetting the votes through [`Pallet::reset_votes`] brings
+	/// an election back to created.
+	#[derive(
+		Encode,
+		Decode,
+		DecodeWithMemTracking,
+		Clone,
+		Copy,
+		PartialEq,
+		Eq,
+		RuntimeDebug,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	pub enum ElectionStatus {
+		/// The election has been created but never started.
+		Created,
+		/// The election has been started and votes are accepted in its voting window.
+		Active,
+		/// Voting in the started election has been halted by an election admin.
+		Paused,
+		/// The election has been ended but not finalized yet.
+		Ended,
+		/// The results of the election have been frozen.
+		Finalized,
+		/// The election was cancelled through [`Pallet::cancel_election`].
+		Cancelled,
+	}
+
+	impl ElectionStatus {
+		/// Whether the election has been started and not ended yet, including while it is
+		/// paused.
+		pub fn is_active(&self) -> bool {
+			matches!(self, Self::Active | Self::Paused)
+		}
+	}
+
+	/// The order in which [`Pallet::get_results_ordered`] lists the candidates of an election.
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub enum ResultOrder {
+		/// From most to fewest votes; candidates with equal votes keep their insertion order.
+		ByVotesDesc,
+		/// By ascending candidate identifier, which lists the abstentions first.
+		ById,
+		/// In the order the candidates were added, followed by the abstentions.
+		Insertion,
+	}
+
+	/// The results of an election bundled for export, as returned by
+	/// [`Pallet::export_results`].
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	#[scale_info(skip_type_params(T))]
+	pub struct ElectionExport<T: Config> {
+		/// Human readable title of the election.
+		pub title: BoundedVec<u8, T::MaxTitleLength>,
+		/// Every candidate of the election as `(candidate_id, name, votes)`, in the order they
+		/// were added.
+		pub candidates: Vec<(u32, BoundedVec<u8, T::MaxNameLength>, u32)>,
+		/// The total number of votes cast in the election, including abstentions.
+		pub total_votes: u32,
+		/// The number of votes cast for [`ABSTAIN`].
+		pub abstentions: u32,
+		/// The stage of its lifecycle the election is in. The results are only final once it is
+		/// [`ElectionStatus::Finalized`].
+		pub status: ElectionStatus,
+		/// Whether the election was finalized without reaching [`Config::MinQuorum`].
+		pub is_failed: bool,
+	}
+
+	/// The state of an election at a glance, as returned by [`Pallet::election_snapshot`].
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	#[scale_info(skip_type_params(T))]
+	pub struct ElectionSnapshot<T: Config> {
+		/// Human readable title of the election.
+		pub title: BoundedVec<u8, T::MaxTitleLength>,
+		/// The stage of its lifecycle the election is in.
+		pub status: ElectionStatus,
+		/// Whether the election was finalized without reaching [`Config::MinQuorum`].
+		pub is_failed: bool,
+		/// The first block of the voting window, or `None` if the window is measured in time.
+		pub start_block: Option<BlockNumberFor<T>>,
+		/// The block from which votes are no longer accepted, or `None` if the window is measured
+		/// in time.
+		pub end_block: Option<BlockNumberFor<T>>,
+		/// The number of candidates standing in the election.
+		pub candidate_count: u32,
+		/// The total number of votes cast in the election, including abstentions, or `0` while
+		/// its results are hidden, see [`Pallet::results_hidden`].
+		pub total_votes: u32,
+		/// Whether the current block or time is inside the voting window. Votes are only
+		/// accepted if the election is also [`ElectionStatus::Active`].
+		pub within_window: bool,
+	}
+
+	/// A reset of an election that waits for [`Pallet::confirm_reset`].
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct PendingReset<BlockNumber> {
+		/// The nonce the reset has to be confirmed with.
+		pub nonce: u32,
+		/// The block in which the reset was requested.
+		pub requested_at: BlockNumber,
+	}
+
+	/// How far the clearing of the votes of an election that is being reset has progressed.
+	#[derive(
+		Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+	)]
+	pub struct ResetProgress {
+		/// The index of the map that is being cleared.
+		pub stage: u8,
+		/// The key to resume clearing the map from, or `None` to start from its first key.
+		pub cursor: Option<BoundedVec<u8, ConstU32<MAX_RESET_CURSOR_LENGTH>>>,
+	}
+
+	/// The outcome of an election as computed by [`Pallet::get_winner`].
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub enum WinnerResult {
+		/// A single candidate received the most votes, given as `(candidate_id, vote_count)`.
+		Winner(u32, u32),
+		/// Several candidates share the highest vote count.
+		Tie(Vec<u32>),
+		/// No votes have been cast for any candidate.
+		NoVotes,
+	}
+
+	/// The identifier that will be assigned to the next created election.
+	#[pallet::storage]
+	pub type NextElectionId<T> = StorageValue<_, ElectionId, ValueQuery>;
+
+	/// All known elections.
+	#[pallet::storage]
+	pub type Election<T: Config> = StorageMap<_, Twox64Concat, ElectionId, ElectionInfo<T>>;
+
+	/// The candidates standing in each election.
+	#[pallet::storage]
+	pub type Candidates<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		ElectionId,
+		BoundedVec<Candidate<T>, T::MaxCandidates>,
+		ValueQuery,
+	>;
+
+	/// The identifiers of the candidates standing in each election, so that votes can check that
+	/// their candidate exists without reading all of [`Candidates`].
+	#[pallet::storage]
+	pub type CandidateExists<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, ElectionId, Twox64Concat, u32, ()>;
+
+	/// The candidate each account registered as through [`Pallet::register_as_candidate`], per
+	/// election, together with the bond reserved for it.
+	#[pallet::storage]
+	pub type CandidateOwners<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ElectionId,
+		Blake2_128Concat,
+		T::AccountId,
+		(u32, BalanceOf<T>),
+	>;
+
+	/// The number of elections in which each account stands as a self-registered candidate,
+	/// that is the number of its entries in [`CandidateOwners`].
+	#[pallet::storage]
+	pub type Candidacies<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// The identifier of the first candidate of an election, right after [`ABSTAIN`].
+	#[pallet::type_value]
+	pub fn FirstCandidateId() -> u32 {
+		ABSTAIN + 1
+	}
+
+	/// The identifier that will be assigned to the next candidate added to each election.
+	#[pallet::storage]
+	pub type NextCandidateId<T> =
+		StorageMap<_, Twox64Concat, ElectionId, u32, ValueQuery, FirstCandidateId>;
+
+	/// The election officers that may manage candidates and start and end elections besides
+	/// [`Config::AdminOrigin`].
+	#[pallet::storage]
+	pub type Admins<T: Config> =
+		StorageValue<_, BoundedVec<T::AccountId, ConstU32<16>>, ValueQuery>;
+
+	/// The accounts that are registered as eligible voters.
+	///
+	/// Only consulted if [`Config::RequireRegistration`] is set.
+	#[pallet::storage]
+	pub type RegisteredVoters<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+
+	/// The number of accounts in [`RegisteredVoters`].
+	#[pallet::storage]
+	pub type RegisteredVoterCount<T> = StorageValue<_, u32, ValueQuery>;
+
+	/// The vote of each account, per election.
+	#[pallet::storage]
+	pub type HasVoted<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ElectionId,
+		Blake2_128Concat,
+		T::AccountId,
+		VoteRecordOf<T>,
+	>;
+
+	/// The vote commitments of each voter in commit-reveal elections that have not been revealed
+	/// yet.
+	///
+	/// A commitment is the hash of `(candidate_id, salt)`.
+	#[pallet::storage]
+	pub type Commitments<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, ElectionId, Blake2_128Concat, T::AccountId, T::Hash>;
+
+	/// The ordered candidate preferences of each voter in ranked-choice elections.
+	#[pallet::storage]
+	pub type Rankings<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ElectionId,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<u32, T::MaxCandidates>,
+	>;
+
+	/// The number of ranked ballots in [`Rankings`] for each election, which bounds the
+	/// finalization of ranked-choice elections.
+	#[pallet::storage]
+	pub type RankedBallotCount<T> = StorageMap<_, Twox64Concat, ElectionId, u32, ValueQuery>;
+
+	/// The number of accounts that cast a ballot in each election, including abstentions and
+	/// unlisted votes, from which [`Pallet::turnout`] is computed.
+	#[pallet::storage]
+	pub type BallotCount<T> = StorageMap<_, Twox64Concat, ElectionId, u32, ValueQuery>;
+
+	/// The candidates approved by each voter in approval elections.
+	#[pallet::storage]
+	pub type Approvals<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ElectionId,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<u32, T::MaxCandidates>,
+	>;
+
+	/// The number of votes received by each candidate, per election.
+	///
+	/// In ranked-choice elections this holds the number of first preferences. In weighted
+	/// elections every vote counts with the weight recorded in [`HasVoted`].
+	#[pallet::storage]
+	pub type VoteCount<T> =
+		StorageDoubleMap<_, Twox64Concat, ElectionId, Twox64Concat, u32, u32, ValueQuery>;
+
+	/// The number of votes cast for [`ABSTAIN`], per election.
+	#[pallet::storage]
+	pub type AbstainCount<T> = StorageMap<_, Twox64Concat, ElectionId, u32, ValueQuery>;
+
+	/// The total number of votes cast in each election, including abstentions.
+	#[pallet::storage]
+	pub type TotalVotes<T> = StorageMap<_, Twox64Concat, ElectionId, u32, ValueQuery>;
+
+	/// The summaries of finalized elections, keyed by archive identifier.
+	///
+	/// Unlike the live storage of an election, summaries are kept by [`Pallet::reset_election`].
+	#[pallet::storage]
+	pub type PastElections<T: Config> = StorageMap<_, Twox64Concat, u32, ElectionSummary<T>>;
+
+	/// The archive identifier that will be assigned to the next finalized election.
+	#[pallet::storage]
+	pub type NextArchiveId<T> = StorageValue<_, u32, ValueQuery>;
+
+	/// The account each account has delegated its vote to.
+	#[pallet::storage]
+	pub type Delegations<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId>;
+
+	/// The accounts that have delegated their vote to each account, the inverse of
+	/// [`Delegations`].
+	#[pallet::storage]
+	pub type Delegators<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<T::AccountId, T::MaxDelegators>,
+		ValueQuery,
+	>;
+
+	/// The delegate whose vote counted the weight of each delegator, per election, together with
+	/// that weight.
+	///
+	/// Kept so the weight can be taken back out of the delegate's vote should the delegator
+	/// vote directly.
+	#[pallet::storage]
+	pub type DelegatedVotes<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ElectionId,
+		Blake2_128Concat,
+		T::AccountId,
+		(T::AccountId, u32),
+	>;
+
+	/// The delegators whose weight was counted in the vote of each delegate, per election, the
+	/// inverse of [`DelegatedVotes`].
+	#[pallet::storage]
+	pub type AppliedDelegations<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ElectionId,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<T::AccountId, T::MaxDelegators>,
+	>;
+
+	/// The nullifiers of the votes cast in unlisted elections, per election.
+	///
+	/// The nullifier of a vote is derived from the election and the voter by
+	/// [`Pallet::nullifier`], so every account can vote once per election without an entry in
+	/// [`HasVoted`]. Anyone can derive the nullifier of an account, so it does not hide whether
+	/// the account voted.
+	#[pallet::storage]
+	pub type Nullifiers<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, ElectionId, Identity, T::Hash, ()>;
+
+	/// Whether the candidates of each election have been locked with
+	/// [`Pallet::lock_candidates`], which is required before the election can be started.
+	#[pallet::storage]
+	pub type CandidatesLocked<T> = StorageMap<_, Twox64Concat, ElectionId, bool, ValueQuery>;
+
+	/// The blocks in which the votes for each candidate of an election were cast, in order, if
+	/// [`Config::EnableVoteLog`] is set.
+	///
+	/// Only the first [`MAX_VOTE_LOG_LENGTH`] votes for a candidate are logged. Abstentions are
+	/// not logged.
+	#[pallet::storage]
+	pub type VoteLog<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ElectionId,
+		Twox64Concat,
+		u32,
+		BoundedVec<BlockNumberFor<T>, ConstU32<MAX_VOTE_LOG_LENGTH>>,
+		ValueQuery,
+	>;
+
+	/// The resets requested through [`Pallet::request_reset`] that have not been confirmed yet.
+	#[pallet::storage]
+	pub type PendingResets<T: Config> =
+		StorageMap<_, Twox64Concat, ElectionId, PendingReset<BlockNumberFor<T>>>;
+
+	/// The nonce that will be assigned to the next requested reset.
+	#[pallet::storage]
+	pub type NextResetNonce<T> = StorageValue<_, u32, ValueQuery>;
+
+	/// The block in which each account in [`RegisteredVoters`] was registered.
+	///
+	/// Accounts registered before the block was recorded have no entry and are not subject to
+	/// [`Config::VotingEligibilityDelay`].
+	#[pallet::storage]
+	pub type VoterRegisteredAt<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>>;
+
+	/// The `(candidate_id, votes)` of every candidate of each finalized election, frozen when it
+	/// was finalized so that [`Pallet::get_results`] does not read every count again.
+	#[pallet::storage]
+	pub type FinalResults<T: Config> =
+		StorageMap<_, Twox64Concat, ElectionId, BoundedVec<(u32, u32), T::MaxCandidates>>;
+
+	/// The receipt of the latest vote of every account reported in [`Event::VoteCast`], per
+	/// election.
+	///
+	/// A receipt is the `blake2_256` hash of the SCALE encoded
+	/// `(election_id, voter, candidate_id, block)` tuple, which voters can check against
+	/// [`Pallet::verify_receipt`].
+	#[pallet::storage]
+	pub type Receipts<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, ElectionId, Blake2_128Concat, T::AccountId, T::Hash>;
+
+	/// The progress of the elections whose reset did not fit in a single call. Their
+	/// [`Election`] entry has already been removed.
+	#[pallet::storage]
+	pub type ResetCursor<T> = StorageMap<_, Twox64Concat, ElectionId, ResetProgress>;
+
+	/// The progress of the elections whose votes are being removed through
+	/// [`Pallet::reset_votes`] over several calls. They cannot be started until it completes.
+	#[pallet::storage]
+	pub type VotesResetCursor<T> = StorageMap<_, Twox64Concat, ElectionId, ResetProgress>;
+
+	/// The reason given for each disputed election, which cannot be finalized until the dispute
+	/// is resolved with [`Pallet::resolve_dispute`].
+	#[pallet::storage]
+	pub type Disputes<T: Config> =
+		StorageMap<_, Twox64Concat, ElectionId, BoundedVec<u8, T::MaxReasonLength>>;
+
+	/// The number of votes cast in the current block and their combined weight, which are
+	/// reported in [`Event::VotesBatchRecorded`] when [`Config::EmitPerVoteEvents`] is not set.
+	#[pallet::storage]
+	pub type VotesInBlock<T> = StorageValue<_, (u32, u32), ValueQuery>;
+
+	/// The accounts that endorsed each candidate, per election.
+	#[pallet::storage]
+	pub type Endorsements<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, ElectionId, Blake2_128Concat, (u32, T::AccountId), ()>;
+
+	/// The number of endorsements of each candidate, per election.
+	#[pallet::storage]
+	pub type EndorsementCount<T> =
+		StorageDoubleMap<_, Twox64Concat, ElectionId, Twox64Concat, u32, u32, ValueQuery>;
+
+	/// The credits each voter spent on its ballot in a quadratic election, per election.
+	#[pallet::storage]
+	pub type SpentCredits<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ElectionId,
+		Blake2_128Concat,
+		T::AccountId,
+		u32,
+		ValueQuery,
+	>;
+
+	/// The elections that have not been started yet or are active, which
+	/// [`Hooks::on_initialize`] starts and ends by their voting window.
+	#[pallet::storage]
+	pub type ScheduledElections<T: Config> =
+		StorageValue<_, BoundedVec<ElectionId, T::MaxScheduledElections>, ValueQuery>;
+
+	/// The weighted elections that the balance of each account is locked for, with the free
+	/// balance its vote was weighted by in each.
+	///
+	/// The balance is locked under [`VOTE_LOCK_ID`] for the largest of these amounts, so that
+	/// it cannot be moved to another account and counted again. [`Pallet::unlock_vote_balance`]
+	/// releases it once the elections are closed.
+	#[pallet::storage]
+	pub type VoteLocks<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<(ElectionId, BalanceOf<T>), T::MaxVoteLocks>,
+		ValueQuery,
+	>;
+
+	/// Seeds an election and its candidates at genesis, which is handy for test networks.
+	///
+	/// The election is created like with [`Pallet::create_election`] as a plurality election
+	/// without deposit, and its candidates are locked so that it starts with its voting window.
+	/// Building the genesis panics if the election or any of its candidates is invalid.
+	/// In a chain spec, byte strings are given as arrays of bytes:
+	///
+	/// ```json
+	/// "voting": {
+	///   "election": [[67, 111, 117, 110, 99, 105, 108], 1, 100],
+	///   "candidates": [
+	///     [1, [65, 108, 105, 99, 101], [70, 105, 114, 115, 116]],
+	///     [2, [66, 111, 98], [83, 101, 99, 111, 110, 100]]
+	///   ]
+	/// }
+	/// ```
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// The election to create, given as `(title, start_block, end_block)`.
+		pub election: Option<(Vec<u8>, BlockNumberFor<T>, BlockNumberFor<T>)>,
+		/// The candidates of the election, given as `(candidate_id, name, description)`.
+		/// Identifiers start at `1`, as [`ABSTAIN`] is reserved for abstentions.
+		pub candidates: Vec<(u32, Vec<u8>, Vec<u8>)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			let Some((title, start_block, end_block)) = &self.election else {
+				assert!(
+					self.candidates.is_empty(),
+					"Genesis candidates require a genesis election"
+				);
+				return
+			};
+			let election_id = Pallet::<T>::insert_election(
+				title.clone(),
+				VotingWindow::Blocks { start: *start_block, end: *end_block },
+				ElectionKind::Plurality,
+				false,
+				None,
+				1,
+				T::MaxCandidates::get(),
+				false,
+				false,
+				None,
+				None,
+			)
+			.expect("Genesis election is invalid");
+			for (candidate_id, name, description) in &self.candidates {
+				Pallet::<T>::insert_candidate_with_id(
+					election_id,
+					*candidate_id,
+					name.clone(),
+					description.clone(),
+					None,
+				)
+				.expect("Genesis candidate is invalid");
+			}
+			CandidatesLocked::<T>::insert(election_id, true);
+		}
+	}
+
+	/// Events that functions in this pallet can emit.
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A new election has been created.
+		ElectionCreated {
+			/// The identifier assigned to the election.
+			election_id: ElectionId,
+			/// The title of the election.
+			title: Vec<u8>,
+		},
+		/// A candidate has been added to an election.
+		CandidateAdded {
+			/// The election the candidate stands in.
+			election_id: ElectionId,
+			/// The identifier of the candidate.
+			candidate_id: u32,
+			/// The name of the candidate.
+			name: Vec<u8>,
+			/// The description or manifesto of the candidate.
+			description: Vec<u8>,
+			/// The number of candidates standing in the election, including this one.
+			total_candidates: u32,
+		},
+		/// An election has been started.
+		ElectionStarted {
+			/// The election that was started.
+			election_id: ElectionId,
+			/// The block in which the election was started.
+			block: BlockNumberFor<T>,
+		},
+		/// A vote has been cast. Only deposited if [`Config::EmitPerVoteEvents`] is set.
+		///
+		/// Deposited with the topic `T::Hashing::hash_of(&candidate_id)`, so that clients can
+		/// subscribe to the votes for one candidate. Candidate identifiers are only unique within
+		/// an election, so the votes for candidates of other elections with the same identifier
+		/// come with the same topic. While the results of the election are hidden, the candidate
+		/// and the counts are left out, and the event has no topic.
+		VoteCast {
+			/// The election the vote was cast in.
+			election_id: ElectionId,
+			/// The account who voted.
+			voter: T::AccountId,
+			/// The candidate voted for, or `None` while the results of the election are hidden.
+			candidate_id: Option<u32>,
+			/// The number of votes of the candidate, or of abstentions, including this vote, or
+			/// `None` while the results of the election are hidden.
+			new_count: Option<u32>,
+			/// The total number of votes cast in the election, including this vote, or `None`
+			/// while the results of the election are hidden.
+			total_votes: Option<u32>,
+			/// The receipt stored for the voter in [`Receipts`].
+			receipt: T::Hash,
+		},
+		/// An election has been ended.
+		ElectionEnded {
+			/// The election that was ended.
+			election_id: ElectionId,
+			/// The block in which the election was ended.
+			block: BlockNumberFor<T>,
+		},
+		/// The results of an election have been frozen.
+		ElectionFinalized {
+			/// The election that was finalized.
+			election_id: ElectionId,
+			/// The total number of votes cast in the election.
+			total_votes: u32,
+		},
+		/// The candidate bonds of an election being reset have been returned or slashed to
+		/// [`Config::SlashedFundsDestination`].
+		BondsSettled {
+			/// The election being reset.
+			election_id: ElectionId,
+			/// The number of bonds settled.
+			count: u32,
+		},
+		/// An election and all of its storage have been removed.
+		ElectionReset {
+			/// The election that was removed.
+			election_id: ElectionId,
+		},
+		/// A candidate has been removed from an election.
+		CandidateRemoved {
+			/// The election the candidate stood in.
+			election_id: ElectionId,
+			/// The identifier of the removed candidate.
+			candidate_id: u32,
+		},
+		/// The name and description of a candidate have been updated.
+		CandidateUpdated {
+			/// The election the candidate stands in.
+			election_id: ElectionId,
+			/// The identifier of the candidate.
+			candidate_id: u32,
+			/// The new name of the candidate.
+			name: Vec<u8>,
+		},
+		/// A voter has moved their vote to another candidate.
+		///
+		/// The candidates are `None` while the results of the election are hidden.
+		VoteChanged {
+			/// The election the vote was cast in.
+			election_id: ElectionId,
+			/// The account who voted.
+			voter: T::AccountId,
+			/// The candidate previously voted for.
+			old_candidate_id: Option<u32>,
+			/// The candidate now voted for.
+			new_candidate_id: Option<u32>,
+		},
+		/// A voter has withdrawn their vote.
+		VoteRetracted {
+			/// The election the vote was cast in.
+			election_id: ElectionId,
+			/// The account who voted.
+			voter: T::AccountId,
+			/// The candidate that had been voted for, or `None` while the results of the election
+			/// are hidden.
+			candidate_id: Option<u32>,
+		},
+		/// A finalized election has a single winner.
+		WinnerDeclared {
+			/// The finalized election.
+			election_id: ElectionId,
+			/// The winning candidate.
+			candidate_id: u32,
+			/// The number of votes the winner received.
+			votes: u32,
+		},
+		/// A finalized election ended in a tie.
+		ElectionTied {
+			/// The finalized election.
+			election_id: ElectionId,
+			/// The candidates sharing the highest vote count.
+			candidate_ids: Vec<u32>,
+		},
+		/// The instant-runoff tally of a ranked-choice election has been run.
+		RankedResult {
+			/// The finalized election.
+			election_id: ElectionId,
+			/// The number of tally rounds it took to reach the outcome.
+			rounds: u32,
+		},
+		/// A voter has committed to a hidden vote.
+		VoteCommitted {
+			/// The election the vote was committed in.
+			election_id: ElectionId,
+			/// The account who voted.
+			voter: T::AccountId,
+		},
+		/// A committed vote has been revealed and counted.
+		VoteRevealed {
+			/// The election the vote was cast in.
+			election_id: ElectionId,
+			/// The account who voted.
+			voter: T::AccountId,
+			/// The candidate voted for, or `None` while the results of the election are hidden.
+			candidate_id: Option<u32>,
+		},
+		/// An account has been registered as an eligible voter.
+		VoterRegistered {
+			/// The registered account.
+			who: T::AccountId,
+		},
+		/// An account has been removed from the eligible voters.
+		VoterDeregistered {
+			/// The deregistered account.
+			who: T::AccountId,
+		},
+		/// An account has been made an election admin.
+		AdminAdded {
+			/// The new admin.
+			who: T::AccountId,
+		},
+		/// An account is no longer an election admin.
+		AdminRemoved {
+			/// The removed admin.
+			who: T::AccountId,
+		},
+		/// An account has registered itself as a candidate.
+		CandidateSelfRegistered {
+			/// The election the candidate stands in.
+			election_id: ElectionId,
+			/// The identifier of the candidate.
+			candidate_id: u32,
+			/// The registered account.
+			who: T::AccountId,
+		},
+		/// A self-registered candidate has withdrawn from an election.
+		CandidateWithdrawn {
+			/// The election the candidate stood in.
+			election_id: ElectionId,
+			/// The identifier of the withdrawn candidate.
+			candidate_id: u32,
+		},
+		/// A finalized election did not reach the quorum and has no winner.
+		ElectionFailedQuorum {
+			/// The finalized election.
+			election_id: ElectionId,
+			/// The total number of votes cast in the election.
+			total_votes: u32,
+			/// The number of votes required by the quorum.
+			required: u32,
+		},
+		/// A tie in a finalized election has been broken with on-chain randomness.
+		TieBrokenRandomly {
+			/// The finalized election.
+			election_id: ElectionId,
+			/// The tied candidate that was picked as the winner.
+			winner: u32,
+			/// The candidates that shared the highest vote count.
+			tied: Vec<u32>,
+		},
+		/// A finalized election with several seats has filled them.
+		WinnersDeclared {
+			/// The finalized election.
+			election_id: ElectionId,
+			/// The winning candidates, from most to fewest votes.
+			candidate_ids: Vec<u32>,
+		},
+		/// An account has delegated its vote.
+		VoteDelegated {
+			/// The account who delegated its vote.
+			delegator: T::AccountId,
+			/// The account the vote was delegated to.
+			delegate: T::AccountId,
+		},
+		/// An account has taken back the delegation of its vote.
+		VoteUndelegated {
+			/// The account who had delegated its vote.
+			delegator: T::AccountId,
+			/// The account the vote had been delegated to.
+			delegate: T::AccountId,
+		},
+		/// The voting window of an active election has been extended.
+		ElectionExtended {
+			/// The extended election.
+			election_id: ElectionId,
+			/// The new block from which votes are no longer accepted.
+			new_end_block: BlockNumberFor<T>,
+		},
+		/// Voting in an active election has been halted.
+		ElectionPaused {
+			/// The paused election.
+			election_id: ElectionId,
+		},
+		/// Voting in a paused election has been resumed.
+		ElectionResumed {
+			/// The resumed election.
+			election_id: ElectionId,
+		},
+		/// The summary of a finalized or cancelled election has been added to [`PastElections`].
+		ElectionArchived {
+			/// The finalized or cancelled election.
+			election_id: ElectionId,
+			/// The identifier of the summary in the archive.
+			archive_id: u32,
+		},
+		/// Several candidates have been added to an election at once.
+		CandidatesBatchAdded {
+			/// The election the candidates stand in.
+			election_id: ElectionId,
+			/// The number of candidates added.
+			count: u32,
+		},
+		/// The turnout of an active election, reported every [`Config::TurnoutReportInterval`]
+		/// blocks. Not reported while the results of the election are hidden.
+		TurnoutSnapshot {
+			/// The election the turnout is reported for.
+			election_id: ElectionId,
+			/// The block the snapshot was taken at.
+			block: BlockNumberFor<T>,
+			/// The number of votes cast so far, including abstentions.
+			total_votes: u32,
+			/// The number of accounts that cast a ballot so far, see [`BallotCount`].
+			voters: u32,
+			/// The number of accounts in [`RegisteredVoters`], see [`RegisteredVoterCount`].
+			registered: u32,
+		},
+		/// An election has been cancelled and archived without declaring a winner.
+		ElectionCancelled {
+			/// The cancelled election.
+			election_id: ElectionId,
+			/// The reason given for the cancellation.
+			reason: Vec<u8>,
+		},
+		/// A vote has been cast in an unlisted election.
+		UnlistedVoteCast {
+			/// The election the vote was cast in.
+			election_id: ElectionId,
+			/// The candidate voted for, or `None` while the results of the election are hidden.
+			candidate_id: Option<u32>,
+		},
+		/// A vote has been cast for a write-in candidate.
+		///
+		/// The candidate and its name are `None` while the results of the election are hidden.
+		WriteInVoteCast {
+			/// The election the vote was cast in.
+			election_id: ElectionId,
+			/// The account that voted.
+			voter: T::AccountId,
+			/// The candidate voted for, which was added if it did not stand yet.
+			candidate_id: Option<u32>,
+			/// The name of the candidate.
+			name: Option<Vec<u8>>,
+		},
+		/// An election has reached [`Config::ExpectedCandidates`] candidates.
+		CandidateSetComplete {
+			/// The election whose candidates were added.
+			election_id: ElectionId,
+			/// The number of candidates standing in the election.
+			total_candidates: u32,
+		},
+		/// The candidates of an election have been locked, so that it can be started.
+		CandidatesLocked {
+			/// The election whose candidates were locked.
+			election_id: ElectionId,
+		},
+		/// A finalized election has been reopened for a recount.
+		ElectionReopened {
+			/// The election that was reopened.
+			election_id: ElectionId,
+			/// How many times the election has been reopened so far.
+			reopen_count: u32,
+		},
+		/// The reset of an election has been requested and waits for confirmation.
+		ResetRequested {
+			/// The election to reset.
+			election_id: ElectionId,
+			/// The nonce the reset has to be confirmed with.
+			nonce: u32,
+			/// The last block in which the reset can be confirmed.
+			expires_at: BlockNumberFor<T>,
+		},
+		/// A requested reset has been confirmed and the election removed.
+		ResetConfirmed {
+			/// The election that was reset.
+			election_id: ElectionId,
+		},
+		/// Part of the votes of an election that is being reset have been removed.
+		/// [`Pallet::reset_election`] has to be called again to remove the rest.
+		ElectionPartiallyReset {
+			/// The election that is being reset.
+			election_id: ElectionId,
+		},
+		/// The votes of a candidate have been corrected by root.
+		VoteCountAdjusted {
+			/// The election the candidate stands in.
+			election_id: ElectionId,
+			/// The candidate whose votes were corrected.
+			candidate_id: u32,
+			/// The votes added to, or if negative removed from, the candidate.
+			delta: i32,
+		},
+		/// The results of an election have been hidden or revealed until it is finalized.
+		ResultsVisibilityChanged {
+			/// The election whose results were hidden or revealed.
+			election_id: ElectionId,
+			/// Whether the results are now hidden.
+			hidden: bool,
+		},
+		/// The metadata URI of an election has been set or cleared.
+		MetadataUpdated {
+			/// The election whose metadata URI was changed.
+			election_id: ElectionId,
+			/// The new metadata URI, or `None` if it was cleared.
+			uri: Option<Vec<u8>>,
+		},
+		/// The votes of an election have been removed, keeping its candidates.
+		VotesReset {
+			/// The election whose votes were removed.
+			election_id: ElectionId,
+		},
+		/// Part of the votes of an election have been removed by [`Pallet::reset_votes`], which
+		/// has to be called again to remove the rest.
+		VotesPartiallyReset {
+			/// The election whose votes are being removed.
+			election_id: ElectionId,
+		},
+		/// The top candidate of a finalized election did not reach its win threshold, so that no
+		/// winner was declared.
+		ThresholdNotMet {
+			/// The finalized election.
+			election_id: ElectionId,
+			/// The candidate with the most votes.
+			top_candidate: u32,
+			/// The share of the total votes the candidate received.
+			share: Permill,
+		},
+		/// The win threshold of an election has been set or cleared.
+		WinThresholdChanged {
+			/// The election whose threshold was changed.
+			election_id: ElectionId,
+			/// The new threshold, or `None` if it was cleared.
+			threshold: Option<Permill>,
+		},
+		/// An election has been finalized, with its full results so that indexers do not have to
+		/// read them from storage. Emitted after [`Event::ElectionFinalized`] and the events
+		/// declaring the outcome.
+		ElectionFinalizedDetailed {
+			/// The election that was finalized.
+			election_id: ElectionId,
+			/// Human readable title of the election.
+			title: Vec<u8>,
+			/// The final votes of every candidate as `(candidate_id, votes)`, holding at most
+			/// [`Config::MaxCandidates`] entries.
+			results: Vec<(u32, u32)>,
+			/// The total number of votes cast in the election, including abstentions.
+			total_votes: u32,
+			/// The winner of the election, or of its first seat. `None` if no winner was
+			/// declared.
+			winner: Option<u32>,
+		},
+		/// An election admin has handed its rights over to another account.
+		AdminTransferred {
+			/// The former admin.
+			from: T::AccountId,
+			/// The new admin.
+			to: T::AccountId,
+		},
+		/// The finalization of an election has been scheduled, or rescheduled after its voting
+		/// window was extended.
+		FinalizationScheduled {
+			/// The election that will be finalized.
+			election_id: ElectionId,
+			/// The block in which the election will be finalized.
+			at: BlockNumberFor<T>,
+		},
+		/// The dispute window of an election has been changed.
+		DisputeWindowChanged {
+			/// The election whose dispute window was changed.
+			election_id: ElectionId,
+			/// The new number of blocks after the end of the voting window.
+			dispute_window: BlockNumberFor<T>,
+		},
+		/// The results of an election have been disputed, which blocks its finalization.
+		DisputeFlagged {
+			/// The disputed election.
+			election_id: ElectionId,
+			/// The reason given for the dispute.
+			reason: Vec<u8>,
+		},
+		/// The dispute of an election has been resolved, so that it can be finalized again.
+		DisputeResolved {
+			/// The election whose dispute was resolved.
+			election_id: ElectionId,
+		},
+		/// Votes have been cast in this block, for which no [`Event::VoteCast`] was deposited
+		/// because [`Config::EmitPerVoteEvents`] is not set.
+		VotesBatchRecorded {
+			/// The number of votes cast in the block, across all elections.
+			count: u32,
+			/// The combined weight of the votes.
+			total_votes: u32,
+		},
+		/// A registered voter has endorsed a candidate.
+		CandidateEndorsed {
+			/// The election the candidate stands in.
+			election_id: ElectionId,
+			/// The endorsed candidate.
+			candidate_id: u32,
+			/// The account that endorsed the candidate.
+			endorser: T::AccountId,
+			/// The number of endorsements of the candidate, including this one.
+			endorsements: u32,
+		},
+		/// The voting window of an election closed before the election was started, so it is no
+		/// longer started or ended automatically. It can still be cancelled.
+		ElectionExpired {
+			/// The election that expired.
+			election_id: ElectionId,
+		},
+		/// The balance of an account has been released from the elections that it was locked for
+		/// and that are closed.
+		VoteBalanceUnlocked {
+			/// The account whose balance was released.
+			who: T::AccountId,
+			/// The balance that is still locked for elections that are not closed.
+			locked: BalanceOf<T>,
+		},
+	}
+
+	/// Errors that can be returned by this pallet.
+	#[pallet::error]
+	pub enum Error<T> {
+		/// No election exists with the given identifier.
+		NoElectionExists,
+		/// A candidate name is longer than `MaxNameLength`.
+		NameTooLong,
+		/// The election already holds as many candidates as can stand in it.
+		TooManyCandidates,
+		/// The end block of the voting window is not after its start block.
+		InvalidTimeRange,
+		/// The election has not been started or has already been ended.
+		ElectionNotActive,
+		/// The election is active, but the operation requires it not to be.
+		ElectionIsActive,
+		/// The election has already been finalized.
+		AlreadyFinalized,
+		/// The voting window of the election has not opened yet.
+		VotingNotStarted,
+		/// The voting window of the election has already closed.
+		VotingEnded,
+		/// The account has already voted in this election.
+		AlreadyVoted,
+		/// No candidate exists with the given identifier.
+		InvalidCandidate,
+		/// The election identifier space has been exhausted.
+		ElectionIdOverflow,
+		/// A candidate with the given identifier already exists in the election.
+		DuplicateCandidate,
+		/// The candidate identifier space of the election has been exhausted.
+		CandidateIdOverflow,
+		/// The account has not voted in this election.
+		HasNotVoted,
+		/// The operation is not supported by the kind of the election.
+		WrongElectionKind,
+		/// A ranked ballot lists no candidates.
+		EmptyRanking,
+		/// A ranked ballot lists the same candidate more than once.
+		DuplicateRanking,
+		/// The account has not committed to a vote in this election.
+		CommitmentNotFound,
+		/// The revealed vote does not match the commitment.
+		RevealMismatch,
+		/// The reveal period of the election has already closed.
+		RevealPeriodEnded,
+		/// The reveal period of the election is still open.
+		RevealPeriodOpen,
+		/// The account is not registered as an eligible voter.
+		NotRegistered,
+		/// The account is already registered as an eligible voter.
+		AlreadyRegistered,
+		/// The account is not an election admin.
+		NotAdmin,
+		/// The account is already an election admin.
+		AlreadyAdmin,
+		/// The maximum number of election admins has been reached.
+		TooManyAdmins,
+		/// The account cannot afford the deposit.
+		InsufficientBalance,
+		/// The account has already registered as a candidate in this election.
+		AlreadyCandidate,
+		/// The `voters` witness is lower than the number of accounts that voted in the election.
+		WitnessTooLow,
+		/// An election title is longer than `MaxTitleLength`.
+		TitleTooLong,
+		/// An election must fill at least one seat.
+		InvalidSeatCount,
+		/// An account cannot delegate its vote to itself.
+		SelfDelegation,
+		/// The account has not delegated its vote.
+		NotDelegating,
+		/// The maximum number of accounts delegating to the delegate has been reached.
+		TooManyDelegators,
+		/// Voting in the election has been paused.
+		ElectionPaused,
+		/// Voting in the election has not been paused.
+		ElectionNotPaused,
+		/// The operation is only supported for elections with a block-based voting window.
+		BlockWindowRequired,
+		/// The election has no candidates.
+		NoCandidates,
+		/// The election has fewer than [`Config::MinCandidates`] candidates.
+		TooFewCandidates,
+		/// The voter does not have a verified identity, which is required by
+		/// [`Config::RequireVerifiedIdentity`].
+		IdentityNotVerified,
+		/// The election has been cancelled.
+		ElectionCancelled,
+		/// The reason for cancelling or disputing an election is longer than
+		/// [`Config::MaxReasonLength`].
+		ReasonTooLong,
+		/// The election has reached [`Config::MaxVoters`] votes.
+		MaxVotersReached,
+		/// The voting window starts before the current time, or sooner than
+		/// [`Config::MinCreationLead`] blocks after the current block.
+		StartBlockInPast,
+		/// The election does not accept votes for write-in candidates.
+		WriteInsNotAllowed,
+		/// A candidate name or election title is not valid UTF-8.
+		InvalidUtf8,
+		/// The candidates of the election have to be locked before it can be started.
+		CandidatesNotLocked,
+		/// The candidates of the election have been locked and can no longer be changed.
+		CandidatesLocked,
+		/// The election has not been finalized.
+		ElectionNotFinalized,
+		/// A candidate description is longer than `MaxDescriptionLength`.
+		DescriptionTooLong,
+		/// Elections that have not been finalized or cancelled can only be reset through
+		/// [`Pallet::request_reset`] and [`Pallet::confirm_reset`].
+		ResetNotConfirmed,
+		/// No reset of the election has been requested.
+		NoPendingReset,
+		/// The nonce does not match the one of the requested reset.
+		WrongResetNonce,
+		/// The requested reset was not confirmed within [`Config::ResetConfirmationWindow`].
+		ResetRequestExpired,
+		/// The account was registered less than [`Config::VotingEligibilityDelay`] blocks ago.
+		EligibilityPending,
+		/// A candidate description is shorter than `MinDescriptionLength`.
+		DescriptionTooShort,
+		/// A candidate name is empty.
+		NameEmpty,
+		/// A metadata URI is longer than [`MAX_METADATA_URI_LENGTH`].
+		MetadataUriTooLong,
+		/// The votes of the election are still being removed through [`Pallet::reset_votes`].
+		VotesResetInProgress,
+		/// A win threshold was set for an election with several seats.
+		ThresholdRequiresSingleSeat,
+		/// A voting window spans more than [`Config::MaxElectionDuration`] blocks.
+		DurationTooLong,
+		/// An approval ballot does not approve any candidate.
+		EmptyApproval,
+		/// An approval ballot approves the same candidate twice.
+		DuplicateApproval,
+		/// A candidate was given the identifier [`ABSTAIN`], which is reserved for abstentions.
+		ReservedCandidateId,
+		/// The number of candidates that can stand in an election is zero or above
+		/// `MaxCandidates`.
+		InvalidMaxCandidates,
+		/// The dispute window of the election is not over yet.
+		DisputeWindowOpen,
+		/// The election has no dispute window, or it is over.
+		DisputeWindowClosed,
+		/// The election is disputed and cannot be finalized until the dispute is resolved.
+		ElectionDisputed,
+		/// The election is not disputed.
+		NotDisputed,
+		/// The account has already endorsed the candidate.
+		AlreadyEndorsed,
+		/// Self-registered candidates cannot endorse themselves.
+		SelfEndorsement,
+		/// A candidate of the election has fewer than [`Config::MinEndorsements`] endorsements.
+		InsufficientEndorsements,
+		/// A quadratic ballot costs more than [`Config::VoteCreditBudget`] credits.
+		InsufficientCredits,
+		/// A quadratic ballot lists no candidates, or casts no votes for one of them.
+		EmptyQuadraticBallot,
+		/// A quadratic ballot lists the same candidate twice.
+		DuplicateQuadraticVote,
+		/// [`Config::MaxScheduledElections`] elections are already waiting for their voting
+		/// window to open or to close.
+		TooManyScheduledElections,
+		/// The election has not been started yet.
+		ElectionNotStarted,
+		/// The balance of the account is already locked for [`Config::MaxVoteLocks`] weighted
+		/// elections.
+		TooManyVoteLocks,
+		/// No balance of the account is locked for votes.
+		NoVoteLock,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Drive the lifecycle of elections by their voting window.
+		///
+		/// Only the elections in [`ScheduledElections`] are looked at. Every election whose
+		/// voting window has opened, that has not been started yet and whose
+		/// candidates are locked is started, as long as it has enough candidates and they have
+		/// enough endorsements, and every active
+		/// election whose voting window has closed is ended. Elections that were started and
+		/// ended by an administrator before their window closed are left alone. Time-based
+		/// windows are compared against the time reported by [`Config::Time`] when the block is
+		/// initialized, which for `pallet_timestamp` is the timestamp of the previous block.
+		///
+		/// Every [`Config::TurnoutReportInterval`] blocks, a [`Event::TurnoutSnapshot`] is
+		/// emitted for every election that is active once the block is initialized.
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let moment = T::Time::now();
+			let mut reads = 2u64;
+			let mut writes = 0u64;
+			if !T::EmitPerVoteEvents::get() {
+				// Taken in `on_finalize`.
+				reads += 1;
+				writes += 1;
+			}
+			let mut to_start = Vec::new();
+			let mut to_end = Vec::new();
+			let mut still_active = Vec::new();
+			let mut unscheduled = Vec::new();
+			let mut expired = Vec::new();
+			let mut hidden = Vec::new();
+			for election_id in ScheduledElections::<T>::get() {
+				reads += 1;
+				let Some(election) = Election::<T>::get(election_id) else {
+					unscheduled.push(election_id);
+					continue
+				};
+				if election.results_hidden {
+					hidden.push(election_id);
+				}
+				if election.status.is_active() && election.window.has_closed(&now, &moment) {
+					to_end.push(election_id);
+				} else if election.status.is_active() {
+					still_active.push(election_id);
+				} else if election.status == ElectionStatus::Created
+					&& election.window.has_closed(&now, &moment)
+				{
+					expired.push(election_id);
+				} else if election.status == ElectionStatus::Created
+					&& election.window.has_opened(&now, &moment)
+					&& !election.window.has_closed(&now, &moment)
+				{
+					reads += 2;
+					if !T::MinEndorsements::get().is_zero() {
+						reads += 1 + u64::from(T::MaxCandidates::get());
+					}
+					if CandidatesLocked::<T>::get(election_id)
+						&& Self::ensure_enough_candidates(election_id).is_ok()
+						&& Self::ensure_endorsed(election_id).is_ok()
+					{
+						to_start.push(election_id);
+					}
+				}
+			}
+
+			for election_id in &to_start {
+				Election::<T>::mutate(election_id, |maybe_election| {
+					if let Some(election) = maybe_election {
+						election.status = ElectionStatus::Active;
+					}
+				});
+				Self::deposit_event(Event::ElectionStarted {
+					election_id: *election_id,
+					block: now,
+				});
+			}
+			for election_id in &to_end {
+				Election::<T>::mutate(election_id, |maybe_election| {
+					if let Some(election) = maybe_election {
+						election.status = ElectionStatus::Ended;
+					}
+				});
+				Self::deposit_event(Event::ElectionEnded { election_id: *election_id, block: now });
+				#[cfg(feature = "scheduler")]
+				if T::FinalizationDelay::get().is_some() {
+					// Looking up, cancelling and scheduling the finalization task, and the number
+					// of ranked ballots.
+					reads += 6;
+					writes += 4;
+					let _ = Self::bound_finalization(*election_id);
+				}
+			}
+			for election_id in &expired {
+				Self::deposit_event(Event::ElectionExpired { election_id: *election_id });
+			}
+			unscheduled.extend_from_slice(&to_end);
+			unscheduled.extend_from_slice(&expired);
+			if !unscheduled.is_empty() {
+				ScheduledElections::<T>::mutate(|scheduled| {
+					scheduled.retain(|election_id| !unscheduled.contains(election_id))
+				});
+				writes += 1;
+			}
+
+			let interval = T::TurnoutReportInterval::get();
+			if !interval.is_zero() && (now % interval).is_zero() {
+				let registered = RegisteredVoterCount::<T>::get();
+				reads += 1;
+				for election_id in still_active
+					.into_iter()
+					.chain(to_start.iter().copied())
+					.filter(|election_id| !hidden.contains(election_id))
+				{
+					reads += 2;
+					Self::deposit_event(Event::TurnoutSnapshot {
+						election_id,
+						block: now,
+						total_votes: TotalVotes::<T>::get(election_id),
+						voters: BallotCount::<T>::get(election_id),
+						registered,
+					});
+				}
+			}
+
+			writes += (to_start.len() + to_end.len()) as u64;
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		/// Summarize the votes of the block in a [`Event::VotesBatchRecorded`] if
+		/// [`Config::EmitPerVoteEvents`] is not set.
+		fn on_finalize(_: BlockNumberFor<T>) {
+			if T::EmitPerVoteEvents::get() {
+				return
+			}
+			let (count, total_votes) = VotesInBlock::<T>::take();
+			if count > 0 {
+				Self::deposit_event(Event::VotesBatchRecorded { count, total_votes });
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(
+			_: BlockNumberFor<T>,
+		) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a new election with the given title, voting window and kind.
+		///
+		/// The voting `window` is given either in blocks or in timestamps of [`Config::Time`], and
+		/// must not have started yet. Block-based windows must start at least
+		/// [`Config::MinCreationLead`] blocks after the current block and span at most
+		/// [`Config::MaxElectionDuration`] blocks.
+		/// If `weighted` is set, every vote counts with a weight derived from the free balance of
+		/// the voter through [`Config::BalanceToVoteWeight`], and that balance is locked until the
+		/// election is closed; quadratic elections cannot be weighted. If `reveal_end_block` is
+		/// given, the election is run in commit-reveal mode and committed votes can be revealed
+		/// until that block; this is only supported for plurality elections with a block-based
+		/// window. Plurality, approval and quadratic elections can fill several `seats`, which go
+		/// to the candidates with the most votes. Votes in `unlisted` elections are cast with
+		/// [`Pallet::cast_unlisted_vote`] and leave no entry in [`HasVoted`]; only plurality
+		/// elections whose votes are cast openly can be unlisted. If `allow_write_ins` is set,
+		/// voters can also vote for candidates that are not standing
+		/// with [`Pallet::cast_write_in_vote`]; this is only supported for plurality elections
+		/// whose votes are cast openly and are not unlisted. `max_candidates` lowers the number of
+		/// candidates that can stand in the election below [`Config::MaxCandidates`], which
+		/// applies if it is `None`. `metadata_uri` can point to off-chain documentation of the
+		/// election and be changed through [`Pallet::set_election_metadata`].
+		///
+		/// The election is assigned the next free [`ElectionId`], which is reported in
+		/// [`Event::ElectionCreated`] so that clients can refer to the election they created,
+		/// including the first one with identifier `0`. Must be called by an election admin. Admins
+		/// other than [`Config::AdminOrigin`] have [`Config::ElectionDeposit`] reserved until the
+		/// election is finalized.
+		///
+		/// With the `scheduler` feature, the finalization of elections with a block-based window
+		/// is scheduled `Config::FinalizationDelay` blocks after their window, or their reveal
+		/// period, closes, which is reported in [`Event::FinalizationScheduled`].
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::create_election())]
+		#[allow(clippy::useless_conversion)]
+		pub fn create_election(
+			origin: OriginFor<T>,
+			title: Vec<u8>,
+			window: VotingWindowOf<T>,
+			kind: ElectionKind,
+			weighted: bool,
+			reveal_end_block: Option<BlockNumberFor<T>>,
+			seats: u32,
+			unlisted: bool,
+			allow_write_ins: bool,
+			metadata_uri: Option<Vec<u8>>,
+			max_candidates: Option<u32>,
+		) -> DispatchResultWithPostInfo {
+			let depositor = Self::ensure_admin(origin)?;
+
+			let starts_ahead = match window {
+				VotingWindow::Blocks { start, .. } => {
+					let now = frame_system::Pallet::<T>::block_number();
+					start >= now.saturating_add(T::MinCreationLead::get())
+				},
+				VotingWindow::Time { start, .. } => start >= T::Time::now(),
+			};
+			ensure!(starts_ahead, Error::<T>::StartBlockInPast);
+			#[cfg(feature = "scheduler")]
+			let closes = match window {
+				VotingWindow::Blocks { end, .. } => Some(reveal_end_block.unwrap_or(end)),
+				VotingWindow::Time { .. } => None,
+			};
+			let deposit = match depositor {
+				Some(who) => {
+					let amount = T::ElectionDeposit::get();
+					T::Currency::reserve(&who, amount)
+						.map_err(|_| Error::<T>::InsufficientBalance)?;
+					Some((who, amount))
+				},
+				None => None,
+			};
+			let election_id = Self::insert_election(
+				title.clone(),
+				window,
+				kind,
+				weighted,
+				reveal_end_block,
+				seats,
+				max_candidates.unwrap_or_else(T::MaxCandidates::get),
+				unlisted,
+				allow_write_ins,
+				metadata_uri,
+				deposit,
+			)?;
+
+			Self::deposit_event(Event::ElectionCreated { election_id, title });
+			#[cfg(feature = "scheduler")]
+			if let Some(closes) = closes {
+				Self::schedule_finalization(election_id, closes)?;
+			}
+			Ok(().into())
+		}
+
+		/// Add a candidate to an election. Must be called by an election admin.
+		///
+		/// The candidate is assigned the next free identifier of the election, which is reported
+		/// in [`Event::CandidateAdded`]. Candidates can only be added until they are locked with
+		/// [`Pallet::lock_candidates`].
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::add_candidate(T::MaxCandidates::get()))]
+		pub fn add_candidate(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			name: Vec<u8>,
+			description: Vec<u8>,
+		) -> DispatchResult {
+			Self::ensure_admin(origin)?;
+
+			let election = Election::<T>::get(election_id).ok_or(Error::<T>::NoElectionExists)?;
+			ensure!(election.status != ElectionStatus::Finalized, Error::<T>::AlreadyFinalized);
+			Self::ensure_candidates_unlocked(election_id, &election)?;
+			Self::ensure_description_long_enough(&description)?;
+
+			let candidate_id =
+				Self::insert_candidate(election_id, name.clone(), description.clone(), None)?;
+			let total_candidates = Self::candidate_count(election_id);
+
+			Self::deposit_event(Event::CandidateAdded {
+				election_id,
+				candidate_id,
+				name,
+				description,
+				total_candidates,
+			});
+			Self::note_candidates_added(election_id, total_candidates.saturating_sub(1));
+			Ok(())
+		}
+
+		/// Start an election so that votes can be cast. Must be called by an election admin.
+		///
+		/// Only elections that have not been started yet can be started; elections that have
+		/// ended, including reopened ones, can only be finalized. The election needs at least one
+		/// candidate, and at least [`Config::MinCandidates`], and its candidates must have been
+		/// locked with [`Pallet::lock_candidates`]. Every candidate needs at least
+		/// [`Config::MinEndorsements`] endorsements.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::start_election())]
+		pub fn start_election(origin: OriginFor<T>, election_id: ElectionId) -> DispatchResult {
+			Self::ensure_admin(origin)?;
+
+			Election::<T>::try_mutate(election_id, |maybe_election| -> DispatchResult {
+				let election = maybe_election.as_mut().ok_or(Error::<T>::NoElectionExists)?;
+				ensure!(
+					!VotesResetCursor::<T>::contains_key(election_id),
+					Error::<T>::VotesResetInProgress
+				);
+				match election.status {
+					ElectionStatus::Created => {},
+					ElectionStatus::Active | ElectionStatus::Paused =>
+						return Err(Error::<T>::ElectionIsActive.into()),
+					ElectionStatus::Ended => return Err(Error::<T>::VotingEnded.into()),
+					ElectionStatus::Finalized => return Err(Error::<T>::AlreadyFinalized.into()),
+					ElectionStatus::Cancelled => return Err(Error::<T>::ElectionCancelled.into()),
+				}
+				Self::ensure_enough_candidates(election_id)?;
+				ensure!(CandidatesLocked::<T>::get(election_id), Error::<T>::CandidatesNotLocked);
+				Self::ensure_endorsed(election_id)?;
+				election.status = ElectionStatus::Active;
+				Ok(())
+			})?;
+			Self::schedule(election_id)?;
+
+			Self::deposit_event(Event::ElectionStarted {
+				election_id,
+				block: frame_system::Pallet::<T>::block_number(),
+			});
+			Ok(())
+		}
+
+		/// Cast a vote for a candidate of an active election.
+		///
+		/// Every account can vote once per election, and only while the current block is inside
+		/// the election's voting window. Voting for [`ABSTAIN`] records an abstention. Elections
+		/// in commit-reveal mode only accept votes through [`Pallet::commit_vote`], and unlisted
+		/// elections only through [`Pallet::cast_unlisted_vote`]. If
+		/// [`Config::RequireVerifiedIdentity`] is set, the voter needs a verified identity. Once
+		/// [`Config::MaxVoters`] votes were cast, no more are accepted.
+		///
+		/// The vote also counts with the weight of every account that delegated to the voter and
+		/// has not voted itself. If the voter's own weight had been counted in the vote of its
+		/// delegate, it is taken back out of that vote first.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::cast_vote(T::MaxDelegators::get()))]
+		pub fn cast_vote(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			candidate_id: u32,
+		) -> DispatchResult {
+			let voter = ensure_signed(origin)?;
+
+			let election = Self::ensure_can_vote(&voter, election_id, candidate_id)?;
+			let (weight, new_count, total_votes) =
+				Self::count_vote(election_id, &election, &voter, candidate_id)?;
+			let receipt = Self::issue_receipt(election_id, &voter, candidate_id);
+
+			Self::deposit_vote_cast(
+				&election,
+				weight,
+				Event::VoteCast {
+					election_id,
+					voter,
+					candidate_id: Some(candidate_id),
+					new_count: Some(new_count),
+					total_votes: Some(total_votes),
+					receipt,
+				},
+			);
+			Ok(())
+		}
+
+		/// End an active election so that no more votes are accepted. Must be called by an
+		/// election admin.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::end_election())]
+		pub fn end_election(origin: OriginFor<T>, election_id: ElectionId) -> DispatchResult {
+			Self::ensure_admin(origin)?;
+
+			Election::<T>::try_mutate(election_id, |maybe_election| -> DispatchResult {
+				let election = maybe_election.as_mut().ok_or(Error::<T>::NoElectionExists)?;
+				ensure!(election.status.is_active(), Error::<T>::ElectionNotActive);
+				election.status = ElectionStatus::Ended;
+				Ok(())
+			})?;
+			Self::unschedule(election_id);
+			#[cfg(feature = "scheduler")]
+			Self::bound_finalization(election_id)?;
+
+			Self::deposit_event(Event::ElectionEnded {
+				election_id,
+				block: frame_system::Pallet::<T>::block_number(),
+			});
+			Ok(())
+		}
+
+		/// Freeze the results of an ended election and return its creation deposit. Must be called
+		/// by the admin origin.
+		///
+		/// If fewer than [`Config::MinQuorum`] votes were cast, the election is marked as failed
+		/// and no winner is declared. A tie is broken with on-chain randomness if
+		/// [`Config::RandomTieBreaking`] is set.
+		///
+		/// `voters` must be at least the number of ranked ballots cast in a ranked-choice
+		/// election, as kept in [`RankedBallotCount`], which bounds the weight of the
+		/// instant-runoff tally. It is not checked for plurality elections, for which `0` can be
+		/// passed.
+		///
+		/// Elections that were never started cannot be finalized; they can be cancelled through
+		/// [`Pallet::cancel_election`] instead.
+		///
+		/// An election with a dispute window cannot be finalized before the window is over, nor
+		/// while it is disputed, see [`Pallet::flag_dispute`].
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::finalize_election(*voters))]
+		pub fn finalize_election(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			voters: u32,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::do_finalize(election_id, voters, false)
+		}
+
+		/// Remove a finalized or cancelled election together with its candidates and votes. Must
+		/// be called by the admin origin.
+		///
+		/// Only the storage of `election_id` is cleared; other elections and the summaries in
+		/// [`PastElections`] are left untouched. The bonds of self-registered candidates are
+		/// settled according to [`Config::SlashedFundsDestination`]. Elections that have not been
+		/// finalized or cancelled can only be reset in two steps, through
+		/// [`Pallet::request_reset`] and [`Pallet::confirm_reset`].
+		///
+		/// At most [`Config::ResetBatchSize`] votes and vote-related entries are removed per call.
+		/// If more remain, [`Event::ElectionPartiallyReset`] is emitted and the call has to be
+		/// repeated until [`Event::ElectionReset`] is emitted.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::reset_election(T::ResetBatchSize::get()))]
+		pub fn reset_election(origin: OriginFor<T>, election_id: ElectionId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::do_reset(election_id, false)
+		}
+
+		/// Remove a candidate from an election that has not been started yet, together with its
+		/// vote and endorsement counts. The bond of a self-registered candidate is returned. Must
+		/// be called by an election admin.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::remove_candidate())]
+		pub fn remove_candidate(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			candidate_id: u32,
+		) -> DispatchResult {
+			Self::ensure_admin(origin)?;
+
+			let election = Election::<T>::get(election_id).ok_or(Error::<T>::NoElectionExists)?;
+			ensure!(!election.status.is_active(), Error::<T>::ElectionIsActive);
+			ensure!(election.status != ElectionStatus::Finalized, Error::<T>::AlreadyFinalized);
+			Self::ensure_candidates_unlocked(election_id, &election)?;
+
+			Self::take_candidate(election_id, candidate_id)?;
+			EndorsementCount::<T>::remove(election_id, candidate_id);
+			let owner = CandidateOwners::<T>::iter_prefix(election_id)
+				.find(|(_, (id, _))| *id == candidate_id);
+			if let Some((who, (_, bond))) = owner {
+				CandidateOwners::<T>::remove(election_id, &who);
+				T::Currency::unreserve(&who, bond);
+				Self::release_candidacy(&who);
+			}
+
+			Self::deposit_event(Event::CandidateRemoved { election_id, candidate_id });
+			Ok(())
+		}
+
+		/// Replace the name and description of a candidate of an election that has not been
+		/// started yet. Must be called by an election admin.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::update_candidate())]
+		pub fn update_candidate(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			candidate_id: u32,
+			name: Vec<u8>,
+			description: Vec<u8>,
+		) -> DispatchResult {
+			Self::ensure_admin(origin)?;
+
+			let election = Election::<T>::get(election_id).ok_or(Error::<T>::NoElectionExists)?;
+			ensure!(!election.status.is_active(), Error::<T>::ElectionIsActive);
+			ensure!(election.status != ElectionStatus::Finalized, Error::<T>::AlreadyFinalized);
+			Self::ensure_candidates_unlocked(election_id, &election)?;
+
+			ensure!(!name.is_empty(), Error::<T>::NameEmpty);
+			Self::ensure_utf8(&name)?;
+			Self::ensure_description_long_enough(&description)?;
+			let bounded_name: BoundedVec<u8, T::MaxNameLength> =
+				name.clone().try_into().map_err(|_| Error::<T>::NameTooLong)?;
+			let bounded_description: BoundedVec<u8, T::MaxDescriptionLength> =
+				description.try_into().map_err(|_| Error::<T>::DescriptionTooLong)?;
+
+			Candidates::<T>::try_mutate(election_id, |candidates| -> DispatchResult {
+				let candidate = candidates
+					.iter_mut()
+					.find(|c| c.id == candidate_id)
+					.ok_or(Error::<T>::InvalidCandidate)?;
+				candidate.name = bounded_name;
+				candidate.description = bounded_description;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::CandidateUpdated { election_id, candidate_id, name });
+			Ok(())
+		}
+
+		/// Move an already cast vote to another candidate while voting is still open.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::change_vote())]
+		pub fn change_vote(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			new_candidate_id: u32,
+		) -> DispatchResult {
+			let voter = ensure_signed(origin)?;
+
+			let election = Self::ensure_voting_open(election_id)?;
+			ensure!(election.kind == ElectionKind::Plurality, Error::<T>::WrongElectionKind);
+			let VoteRecord { candidate_id: old_candidate_id, weight, .. } =
+				HasVoted::<T>::get(election_id, &voter).ok_or(Error::<T>::HasNotVoted)?;
+			Self::ensure_choice_exists(election_id, new_candidate_id)?;
+
+			Self::remove_from_tally(election_id, old_candidate_id, weight);
+			Self::add_to_tally(election_id, new_candidate_id, weight);
+			HasVoted::<T>::insert(
+				election_id,
+				&voter,
+				VoteRecord {
+					candidate_id: new_candidate_id,
+					weight,
+					block: frame_system::Pallet::<T>::block_number(),
+				},
+			);
+
+			let shown = !election.results_hidden;
+			Self::deposit_event(Event::VoteChanged {
+				election_id,
+				voter,
+				old_candidate_id: shown.then_some(old_candidate_id),
+				new_candidate_id: shown.then_some(new_candidate_id),
+			});
+			Ok(())
+		}
+
+		/// Withdraw an already cast vote, ranked ballot or approval ballot, while voting is still
+		/// open. Quadratic ballots cannot be withdrawn.
+		///
+		/// The weight delegated to the voter is withdrawn together with the vote, and from every
+		/// candidate approved by an approval ballot.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::retract_vote(T::MaxDelegators::get()))]
+		pub fn retract_vote(origin: OriginFor<T>, election_id: ElectionId) -> DispatchResult {
+			let voter = ensure_signed(origin)?;
+
+			let election = Self::ensure_voting_open(election_id)?;
+			ensure!(election.kind != ElectionKind::Quadratic, Error::<T>::WrongElectionKind);
+			let VoteRecord { candidate_id, weight, .. } =
+				HasVoted::<T>::take(election_id, &voter).ok_or(Error::<T>::HasNotVoted)?;
+
+			if Rankings::<T>::take(election_id, &voter).is_some() {
+				RankedBallotCount::<T>::mutate(election_id, |count| {
+					*count = count.saturating_sub(1)
+				});
+			}
+			for delegator in AppliedDelegations::<T>::take(election_id, &voter).unwrap_or_default()
+			{
+				DelegatedVotes::<T>::remove(election_id, delegator);
+			}
+			Self::remove_vote_from_tally(election_id, &voter, candidate_id, weight);
+			Approvals::<T>::remove(election_id, &voter);
+			TotalVotes::<T>::mutate(election_id, |total| *total = total.saturating_sub(weight));
+			BallotCount::<T>::mutate(election_id, |count| *count = count.saturating_sub(1));
+
+			Self::deposit_event(Event::VoteRetracted {
+				election_id,
+				voter,
+				candidate_id: (!election.results_hidden).then_some(candidate_id),
+			});
+			Ok(())
+		}
+
+		/// Cast a ranked ballot in an active ranked-choice election.
+		///
+		/// `rankings` lists candidate identifiers from most to least preferred. It does not need
+		/// to rank every candidate, but must not list a candidate twice. The first preference is
+		/// reflected in [`VoteCount`] right away; the full ballot is only used by the
+		/// instant-runoff tally at finalization.
+		///
+		/// Delegated votes are counted like in [`Pallet::cast_vote`].
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::cast_ranked_vote(T::MaxDelegators::get()))]
+		pub fn cast_ranked_vote(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			rankings: Vec<u32>,
+		) -> DispatchResult {
+			let voter = ensure_signed(origin)?;
+
+			let election = Self::ensure_voting_open(election_id)?;
+			Self::ensure_eligible(&voter)?;
+			ensure!(election.kind == ElectionKind::RankedChoice, Error::<T>::WrongElectionKind);
+			ensure!(!HasVoted::<T>::contains_key(election_id, &voter), Error::<T>::AlreadyVoted);
+			Self::ensure_below_max_voters(election_id)?;
+
+			let rankings: BoundedVec<u32, T::MaxCandidates> =
+				rankings.try_into().map_err(|_| Error::<T>::TooManyCandidates)?;
+			let first_preference = *rankings.first().ok_or(Error::<T>::EmptyRanking)?;
+			// A whole ballot is cheaper to check against all candidates at once than through
+			// `CandidateExists`.
+			let candidates = Candidates::<T>::get(election_id);
+			for (index, candidate_id) in rankings.iter().enumerate() {
+				ensure!(!rankings[..index].contains(candidate_id), Error::<T>::DuplicateRanking);
+				ensure!(
+					candidates.iter().any(|c| c.id == *candidate_id),
+					Error::<T>::InvalidCandidate
+				);
+			}
+
+			Self::unwind_delegation(election_id, &voter);
+			let weight = Self::vote_weight(election_id, &election, &voter)?
+				.saturating_add(Self::apply_delegations(election_id, &election, &voter));
+			HasVoted::<T>::insert(
+				election_id,
+				&voter,
+				VoteRecord {
+					candidate_id: first_preference,
+					weight,
+					block: frame_system::Pallet::<T>::block_number(),
+				},
+			);
+			Rankings::<T>::insert(election_id, &voter, rankings);
+			RankedBallotCount::<T>::mutate(election_id, |count| *count = count.saturating_add(1));
+			let new_count = VoteCount::<T>::mutate(election_id, first_preference, |count| {
+				*count = count.saturating_add(weight);
+				*count
+			});
+			BallotCount::<T>::mutate(election_id, |count| *count = count.saturating_add(1));
+			let total_votes = TotalVotes::<T>::mutate(election_id, |total| {
+				*total = total.saturating_add(weight);
+				*total
+			});
+			let receipt = Self::issue_receipt(election_id, &voter, first_preference);
+
+			Self::deposit_vote_cast(
+				&election,
+				weight,
+				Event::VoteCast {
+					election_id,
+					voter,
+					candidate_id: Some(first_preference),
+					new_count: Some(new_count),
+					total_votes: Some(total_votes),
+					receipt,
+				},
+			);
+			Ok(())
+		}
+
+		/// Commit to a hidden vote in an active election run in commit-reveal mode.
+		///
+		/// `commitment` is the hash of the SCALE encoded `(candidate_id, salt)` tuple, computed
+		/// with the runtime's hashing algorithm. The vote is only counted once it is revealed
+		/// with [`Pallet::reveal_vote`].
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::commit_vote())]
+		pub fn commit_vote(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			commitment: T::Hash,
+		) -> DispatchResult {
+			let voter = ensure_signed(origin)?;
+
+			let election = Self::ensure_voting_open(election_id)?;
+			Self::ensure_eligible(&voter)?;
+			ensure!(election.reveal_end_block.is_some(), Error::<T>::WrongElectionKind);
+			ensure!(!Commitments::<T>::contains_key(election_id, &voter), Error::<T>::AlreadyVoted);
+
+			Commitments::<T>::insert(election_id, &voter, commitment);
+
+			Self::deposit_event(Event::VoteCommitted { election_id, voter });
+			Ok(())
+		}
+
+		/// Reveal and count a vote committed with [`Pallet::commit_vote`].
+		///
+		/// Votes can be revealed once the election has ended and until its `reveal_end_block`.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::reveal_vote())]
+		pub fn reveal_vote(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			candidate_id: u32,
+			salt: [u8; 32],
+		) -> DispatchResult {
+			let voter = ensure_signed(origin)?;
+
+			let election = Election::<T>::get(election_id).ok_or(Error::<T>::NoElectionExists)?;
+			let reveal_end_block =
+				election.reveal_end_block.ok_or(Error::<T>::WrongElectionKind)?;
+			Self::ensure_ended(election.status)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() < reveal_end_block,
+				Error::<T>::RevealPeriodEnded
+			);
+
+			let commitment = Commitments::<T>::take(election_id, &voter)
+				.ok_or(Error::<T>::CommitmentNotFound)?;
+			ensure!(
+				T::Hashing::hash_of(&(candidate_id, salt)) == commitment,
+				Error::<T>::RevealMismatch
+			);
+			Self::ensure_choice_exists(election_id, candidate_id)?;
+
+			let weight = Self::vote_weight(election_id, &election, &voter)?;
+			let block = frame_system::Pallet::<T>::block_number();
+			HasVoted::<T>::insert(election_id, &voter, VoteRecord { candidate_id, weight, block });
+			Self::add_to_tally(election_id, candidate_id, weight);
+			TotalVotes::<T>::mutate(election_id, |total| *total = total.saturating_add(weight));
+			BallotCount::<T>::mutate(election_id, |count| *count = count.saturating_add(1));
+
+			Self::deposit_event(Event::VoteRevealed {
+				election_id,
+				voter,
+				candidate_id: (!election.results_hidden).then_some(candidate_id),
+			});
+			Ok(())
+		}
+
+		/// Register an account as an eligible voter. Must be called by the admin origin.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::register_voter())]
+		pub fn register_voter(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ensure!(!RegisteredVoters::<T>::contains_key(&who), Error::<T>::AlreadyRegistered);
+			RegisteredVoters::<T>::insert(&who, ());
+			RegisteredVoterCount::<T>::mutate(|count| *count = count.saturating_add(1));
+			VoterRegisteredAt::<T>::insert(&who, frame_system::Pallet::<T>::block_number());
+
+			Self::deposit_event(Event::VoterRegistered { who });
+			Ok(())
+		}
+
+		/// Remove an account from the eligible voters. Must be called by the admin origin.
+		///
+		/// Votes the account has already cast are kept.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::deregister_voter())]
+		pub fn deregister_voter(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ensure!(RegisteredVoters::<T>::contains_key(&who), Error::<T>::NotRegistered);
+			RegisteredVoters::<T>::remove(&who);
+			RegisteredVoterCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+			VoterRegisteredAt::<T>::remove(&who);
+
+			Self::deposit_event(Event::VoterDeregistered { who });
+			Ok(())
+		}
+
+		/// Make an account an election admin. Must be called by the admin origin.
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::add_admin())]
+		pub fn add_admin(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			Admins::<T>::try_mutate(|admins| -> DispatchResult {
+				ensure!(!admins.contains(&who), Error::<T>::AlreadyAdmin);
+				admins.try_push(who.clone()).map_err(|_| Error::<T>::TooManyAdmins)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::AdminAdded { who });
+			Ok(())
+		}
+
+		/// Revoke the election admin rights of an account. Must be called by the admin origin.
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::remove_admin())]
+		pub fn remove_admin(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			Admins::<T>::try_mutate(|admins| -> DispatchResult {
+				let index = admins.iter().position(|a| a == &who).ok_or(Error::<T>::NotAdmin)?;
+				admins.remove(index);
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::AdminRemoved { who });
+			Ok(())
+		}
+
+		/// Register the caller as a candidate of an election that has not been started yet.
+		///
+		/// [`Config::CandidateBond`] is reserved from the caller. Every account can register once
+		/// per election, and only in one election at a time unless
+		/// [`Config::AllowMultipleCandidaciesPerAccount`] is set.
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::register_as_candidate())]
+		pub fn register_as_candidate(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			name: Vec<u8>,
+			description: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let election = Election::<T>::get(election_id).ok_or(Error::<T>::NoElectionExists)?;
+			Self::ensure_not_closed(election.status)?;
+			Self::ensure_candidates_unlocked(election_id, &election)?;
+			ensure!(
+				!CandidateOwners::<T>::contains_key(election_id, &who),
+				Error::<T>::AlreadyCandidate
+			);
+			ensure!(
+				T::AllowMultipleCandidaciesPerAccount::get() || Candidacies::<T>::get(&who) == 0,
+				Error::<T>::AlreadyCandidate
+			);
+			Self::ensure_description_long_enough(&description)?;
+
+			let candidate_id =
+				Self::insert_candidate(election_id, name, description, Some(who.clone()))?;
+			let bond = T::CandidateBond::get();
+			T::Currency::reserve(&who, bond).map_err(|_| Error::<T>::InsufficientBalance)?;
+			CandidateOwners::<T>::insert(election_id, &who, (candidate_id, bond));
+			Candidacies::<T>::mutate(&who, |count| *count = count.saturating_add(1));
+
+			Self::deposit_event(Event::CandidateSelfRegistered { election_id, candidate_id, who });
+			Ok(())
+		}
+
+		/// Withdraw the candidacy registered with [`Pallet::register_as_candidate`] from an
+		/// election that has not been started yet and whose candidates are not locked, returning
+		/// the bond.
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::withdraw_candidacy())]
+		pub fn withdraw_candidacy(origin: OriginFor<T>, election_id: ElectionId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let election = Election::<T>::get(election_id).ok_or(Error::<T>::NoElectionExists)?;
+			Self::ensure_created(election.status)?;
+			ensure!(!CandidatesLocked::<T>::get(election_id), Error::<T>::CandidatesLocked);
+			let (candidate_id, bond) = CandidateOwners::<T>::take(election_id, &who)
+				.ok_or(Error::<T>::InvalidCandidate)?;
+
+			Self::take_candidate(election_id, candidate_id)?;
+			T::Currency::unreserve(&who, bond);
+			Self::release_candidacy(&who);
+
+			Self::deposit_event(Event::CandidateWithdrawn { election_id, candidate_id });
+			Ok(())
+		}
+
+		/// Delegate the vote of the caller to another account.
+		///
+		/// Whenever `to` votes, the weight of the caller is counted with that vote in every
+		/// election the caller has not voted in itself. Voting directly later takes the weight
+		/// back out of the vote of `to`. Delegations are resolved one level deep, so votes
+		/// delegated to the caller are not passed on to `to`. Delegating again replaces the
+		/// previous delegation.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::delegate_vote())]
+		pub fn delegate_vote(origin: OriginFor<T>, to: T::AccountId) -> DispatchResult {
+			let delegator = ensure_signed(origin)?;
+			ensure!(delegator != to, Error::<T>::SelfDelegation);
+
+			if let Some(previous) = Delegations::<T>::get(&delegator) {
+				Self::remove_delegator(&previous, &delegator);
+			}
+			Delegators::<T>::try_mutate(&to, |delegators| {
+				delegators.try_push(delegator.clone()).map_err(|_| Error::<T>::TooManyDelegators)
+			})?;
+			Delegations::<T>::insert(&delegator, &to);
+
+			Self::deposit_event(Event::VoteDelegated { delegator, delegate: to });
+			Ok(())
+		}
+
+		/// Take back the delegation of the caller's vote.
+		///
+		/// Votes the delegate has already cast keep counting the weight of the caller, unless the
+		/// caller votes directly in those elections.
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::undelegate())]
+		pub fn undelegate(origin: OriginFor<T>) -> DispatchResult {
+			let delegator = ensure_signed(origin)?;
+
+			let delegate = Delegations::<T>::take(&delegator).ok_or(Error::<T>::NotDelegating)?;
+			Self::remove_delegator(&delegate, &delegator);
+
+			Self::deposit_event(Event::VoteUndelegated { delegator, delegate });
+			Ok(())
+		}
+
+		/// Move the end of the voting window of an active election to `new_end_block`, which
+		/// must be after the current end of the window. Must be called by an election admin.
+		///
+		/// Only block-based windows can be extended, and not beyond
+		/// [`Config::MaxElectionDuration`] blocks. In commit-reveal elections, the window cannot
+		/// be extended up to the end of the reveal period.
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::extend_election())]
+		pub fn extend_election(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			new_end_block: BlockNumberFor<T>,
+		) -> DispatchResult {
+			Self::ensure_admin(origin)?;
+
+			Election::<T>::try_mutate(election_id, |maybe_election| -> DispatchResult {
+				let election = maybe_election.as_mut().ok_or(Error::<T>::NoElectionExists)?;
+				ensure!(election.status != ElectionStatus::Finalized, Error::<T>::AlreadyFinalized);
+				ensure!(election.status.is_active(), Error::<T>::ElectionNotActive);
+				let VotingWindow::Blocks { start, end } = &mut election.window else {
+					return Err(Error::<T>::BlockWindowRequired.into())
+				};
+				ensure!(new_end_block > *end, Error::<T>::InvalidTimeRange);
+				Self::ensure_duration_bounded(*start, new_end_block)?;
+				if let Some(reveal_end_block) = election.reveal_end_block {
+					ensure!(reveal_end_block > new_end_block, Error::<T>::InvalidTimeRange);
+				}
+				*end = new_end_block;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::ElectionExtended { election_id, new_end_block });
+			#[cfg(feature = "scheduler")]
+			Self::reschedule_finalization(election_id, new_end_block);
+			Ok(())
+		}
+
+		/// Halt voting in an active election without ending it. Must be called by an election
+		/// admin.
+		///
+		/// No votes can be cast, changed or retracted until the election is resumed. The voting
+		/// window keeps running, and ending the election also lifts the pause.
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::pause_election())]
+		pub fn pause_election(origin: OriginFor<T>, election_id: ElectionId) -> DispatchResult {
+			Self::ensure_admin(origin)?;
+
+			Election::<T>::try_mutate(election_id, |maybe_election| -> DispatchResult {
+				let election = maybe_election.as_mut().ok_or(Error::<T>::NoElectionExists)?;
+				match election.status {
+					ElectionStatus::Active => election.status = ElectionStatus::Paused,
+					ElectionStatus::Paused => return Err(Error::<T>::ElectionPaused.into()),
+					_ => return Err(Error::<T>::ElectionNotActive.into()),
+				}
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::ElectionPaused { election_id });
+			Ok(())
+		}
+
+		/// Resume voting in a paused election. Must be called by an election admin.
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::resume_election())]
+		pub fn resume_election(origin: OriginFor<T>, election_id: ElectionId) -> DispatchResult {
+			Self::ensure_admin(origin)?;
+
+			Election::<T>::try_mutate(election_id, |maybe_election| -> DispatchResult {
+				let election = maybe_election.as_mut().ok_or(Error::<T>::NoElectionExists)?;
+				ensure!(election.status == ElectionStatus::Paused, Error::<T>::ElectionNotPaused);
+				election.status = ElectionStatus::Active;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::ElectionResumed { election_id });
+			Ok(())
+		}
+
+		/// Add several candidates, given as `(name, description)`, to an election at once. Must
+		/// be called by an election admin.
+		///
+		/// The candidates are assigned consecutive identifiers in the given order. Either all of
+		/// them are added or, if any is invalid or they would not all fit, none is.
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::batch_add_candidates(candidates.len() as u32))]
+		pub fn batch_add_candidates(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			candidates: Vec<(Vec<u8>, Vec<u8>)>,
+		) -> DispatchResult {
+			Self::ensure_admin(origin)?;
+
+			let election = Election::<T>::get(election_id).ok_or(Error::<T>::NoElectionExists)?;
+			ensure!(election.status != ElectionStatus::Finalized, Error::<T>::AlreadyFinalized);
+			Self::ensure_candidates_unlocked(election_id, &election)?;
+			let count = candidates.len() as u32;
+			let previous = Self::candidate_count(election_id);
+			ensure!(
+				previous.saturating_add(count) <= election.max_candidates,
+				Error::<T>::TooManyCandidates
+			);
+
+			for (name, description) in candidates {
+				Self::ensure_description_long_enough(&description)?;
+				Self::insert_candidate(election_id, name, description, None)?;
+			}
+
+			Self::deposit_event(Event::CandidatesBatchAdded { election_id, count });
+			Self::note_candidates_added(election_id, previous);
+			Ok(())
+		}
+
+		/// End and finalize an election at once, even while voting is still open. Must be called
+		/// by root.
+		///
+		/// Meant for emergencies. The election is finalized exactly as by
+		/// [`Pallet::finalize_election`], which also applies to `voters`; an active election is
+		/// ended first and [`Event::ElectionEnded`] is emitted before
+		/// [`Event::ElectionFinalized`]. The reveal period of commit-reveal elections is still
+		/// respected, and so are disputes, but not the dispute window.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::force_finalize(*voters))]
+		pub fn force_finalize(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			voters: u32,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::do_finalize(election_id, voters, true)
+		}
+
+		/// Cancel an election that has not been finalized, keeping a record of it in
+		/// [`PastElections`]. Must be called by the admin origin.
+		///
+		/// An active election is ended. No winner is declared, the creation deposit and the bonds
+		/// of self-registered candidates are returned, and the summary is archived with `reason`.
+		/// A cancelled election accepts no more votes, cannot be started or finalized, and can
+		/// only be removed with [`Pallet::reset_election`].
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::cancel_election())]
+		pub fn cancel_election(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			reason: Vec<u8>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let bounded_reason: BoundedVec<u8, T::MaxReasonLength> =
+				reason.clone().try_into().map_err(|_| Error::<T>::ReasonTooLong)?;
+			let (title, deposit) = Election::<T>::try_mutate(
+				election_id,
+				|maybe_election| -> Result<_, DispatchError> {
+					let election = maybe_election.as_mut().ok_or(Error::<T>::NoElectionExists)?;
+					Self::ensure_not_closed(election.status)?;
+					election.status = ElectionStatus::Cancelled;
+					Ok((election.title.clone(), election.deposit.take()))
+				},
+			)?;
+			if let Some((who, amount)) = deposit {
+				T::Currency::unreserve(&who, amount);
+			}
+			for (who, (_, bond)) in CandidateOwners::<T>::drain_prefix(election_id) {
+				T::Currency::unreserve(&who, bond);
+				Self::release_candidacy(&who);
+			}
+
+			Disputes::<T>::remove(election_id);
+			Self::unschedule(election_id);
+			#[cfg(feature = "scheduler")]
+			let _ = T::Scheduler::cancel_named(Self::finalization_task(election_id));
+
+			Self::deposit_event(Event::ElectionCancelled { election_id, reason });
+			Self::archive(
+				election_id,
+				title,
+				Vec::new(),
+				TotalVotes::<T>::get(election_id),
+				Some(bounded_reason),
+			);
+			Ok(())
+		}
+
+		/// Cast a vote for a candidate of an active unlisted election.
+		///
+		/// Instead of recording the vote of the caller in [`HasVoted`], the nullifier of the
+		/// caller for the election, derived by [`Pallet::nullifier`], is recorded in
+		/// [`Nullifiers`], and only the tally is updated. A second vote of the caller yields the
+		/// same nullifier and is rejected. The caller must be eligible to vote like in
+		/// [`Pallet::cast_vote`], but delegations are not applied.
+		///
+		/// This only keeps the votes out of the per-voter storage and is not anonymous: the
+		/// extrinsic is signed by the voter and names the candidate.
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::cast_unlisted_vote())]
+		pub fn cast_unlisted_vote(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			candidate_id: u32,
+		) -> DispatchResult {
+			let voter = ensure_signed(origin)?;
+
+			let election = Self::ensure_voting_open(election_id)?;
+			Self::ensure_eligible(&voter)?;
+			ensure!(election.unlisted, Error::<T>::WrongElectionKind);
+			let nullifier = Self::nullifier(election_id, &voter);
+			ensure!(
+				!Nullifiers::<T>::contains_key(election_id, nullifier),
+				Error::<T>::AlreadyVoted
+			);
+			Self::ensure_below_max_voters(election_id)?;
+			Self::ensure_choice_exists(election_id, candidate_id)?;
+
+			let weight = Self::vote_weight(election_id, &election, &voter)?;
+			Nullifiers::<T>::insert(election_id, nullifier, ());
+			Self::add_to_tally(election_id, candidate_id, weight);
+			TotalVotes::<T>::mutate(election_id, |total| *total = total.saturating_add(weight));
+			BallotCount::<T>::mutate(election_id, |count| *count = count.saturating_add(1));
+
+			Self::deposit_event(Event::UnlistedVoteCast {
+				election_id,
+				candidate_id: (!election.results_hidden).then_some(candidate_id),
+			});
+			Ok(())
+		}
+
+		/// Cast a vote for the candidate named `name` in an active election that allows write-ins.
+		///
+		/// If no candidate with exactly that name stands, a candidate without description is added
+		/// with the next free identifier, as long as the election can take another candidate. The
+		/// vote is then cast like with [`Pallet::cast_vote`], followed by a
+		/// [`Event::WriteInVoteCast`].
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::cast_write_in_vote(T::MaxDelegators::get()))]
+		pub fn cast_write_in_vote(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			name: Vec<u8>,
+		) -> DispatchResult {
+			let voter = ensure_signed(origin)?;
+
+			let election = Election::<T>::get(election_id).ok_or(Error::<T>::NoElectionExists)?;
+			ensure!(election.allow_write_ins, Error::<T>::WriteInsNotAllowed);
+			let standing = Candidates::<T>::get(election_id)
+				.iter()
+				.find(|candidate| candidate.name[..] == name[..])
+				.map(|candidate| candidate.id);
+			let candidate_id = match standing {
+				Some(candidate_id) => candidate_id,
+				None => {
+					let candidate_id =
+						Self::insert_candidate(election_id, name.clone(), Vec::new(), None)?;
+					Self::deposit_event(Event::CandidateAdded {
+						election_id,
+						candidate_id,
+						name: name.clone(),
+						description: Vec::new(),
+						total_candidates: Self::candidate_count(election_id),
+					});
+					candidate_id
+				},
+			};
+			let election = Self::ensure_can_vote(&voter, election_id, candidate_id)?;
+			let (weight, new_count, total_votes) =
+				Self::count_vote(election_id, &election, &voter, candidate_id)?;
+			let receipt = Self::issue_receipt(election_id, &voter, candidate_id);
+
+			Self::deposit_vote_cast(
+				&election,
+				weight,
+				Event::VoteCast {
+					election_id,
+					voter: voter.clone(),
+					candidate_id: Some(candidate_id),
+					new_count: Some(new_count),
+					total_votes: Some(total_votes),
+					receipt,
+				},
+			);
+			let shown = !election.results_hidden;
+			Self::deposit_event(Event::WriteInVoteCast {
+				election_id,
+				voter,
+				candidate_id: shown.then_some(candidate_id),
+				name: shown.then_some(name),
+			});
+			Ok(())
+		}
+
+		/// Lock the candidates of an election that has not been started yet, so that it can be
+		/// started. Must be called by an election admin.
+		///
+		/// Candidates can no longer be added, updated, removed, registered or withdrawn once they
+		/// are locked, and self-registered candidates keep their candidacy until the election is
+		/// reset. Write-in candidates are still added through [`Pallet::cast_write_in_vote`] while
+		/// the election is active.
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::lock_candidates())]
+		pub fn lock_candidates(origin: OriginFor<T>, election_id: ElectionId) -> DispatchResult {
+			Self::ensure_admin(origin)?;
+
+			let election = Election::<T>::get(election_id).ok_or(Error::<T>::NoElectionExists)?;
+			Self::ensure_not_closed(election.status)?;
+			Self::ensure_candidates_unlocked(election_id, &election)?;
+			CandidatesLocked::<T>::insert(election_id, true);
+
+			Self::deposit_event(Event::CandidatesLocked { election_id });
+			Ok(())
+		}
+
+		/// Request the reset of an election, which has to be confirmed through
+		/// [`Pallet::confirm_reset`] with the nonce in [`Event::ResetRequested`] within
+		/// [`Config::ResetConfirmationWindow`] blocks. Must be called by the admin origin.
+		///
+		/// Unless [`Config::AllowResetDuringVoting`] is set, only finalized or cancelled elections
+		/// can be reset. Requesting the reset again replaces the nonce of the previous request.
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::request_reset())]
+		pub fn request_reset(origin: OriginFor<T>, election_id: ElectionId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let election = Election::<T>::get(election_id).ok_or(Error::<T>::NoElectionExists)?;
+			ensure!(
+				matches!(election.status, ElectionStatus::Finalized | ElectionStatus::Cancelled)
+					|| T::AllowResetDuringVoting::get(),
+				Error::<T>::ElectionIsActive
+			);
+			let nonce = NextResetNonce::<T>::mutate(|next| {
+				let nonce = *next;
+				*next = next.wrapping_add(1);
+				nonce
+			});
+			let requested_at = frame_system::Pallet::<T>::block_number();
+			PendingResets::<T>::insert(election_id, PendingReset { nonce, requested_at });
+
+			let expires_at = requested_at.saturating_add(T::ResetConfirmationWindow::get());
+			Self::deposit_event(Event::ResetRequested { election_id, nonce, expires_at });
+			Ok(())
+		}
+
+		/// Confirm a reset requested through [`Pallet::request_reset`] and remove the election
+		/// as [`Pallet::reset_election`] does. Must be called by the admin origin.
+		///
+		/// The creation deposit of an election that has not been finalized yet is slashed. A reset
+		/// that does not fit in a single call is continued through [`Pallet::reset_election`].
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::confirm_reset(T::ResetBatchSize::get()))]
+		pub fn confirm_reset(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			nonce: u32,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let pending = PendingResets::<T>::get(election_id).ok_or(Error::<T>::NoPendingReset)?;
+			ensure!(pending.nonce == nonce, Error::<T>::WrongResetNonce);
+			ensure!(
+				frame_system::Pallet::<T>::block_number()
+					<= pending.requested_at.saturating_add(T::ResetConfirmationWindow::get()),
+				Error::<T>::ResetRequestExpired
+			);
+			Self::do_reset(election_id, true)?;
+
+			Self::deposit_event(Event::ResetConfirmed { election_id });
+			Ok(())
+		}
+
+		/// Reopen a finalized election so that its votes can be recounted. Must be called by root.
+		///
+		/// The election is left ended and unfinalized with its votes in place, so that finalizing
+		/// it again tallies the same votes and archives another summary in [`PastElections`].
+		/// The creation deposit was returned when the election was first finalized and is not
+		/// returned again. Every reopening is counted in [`ElectionInfo::reopen_count`].
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::WeightInfo::reopen_election())]
+		pub fn reopen_election(origin: OriginFor<T>, election_id: ElectionId) -> DispatchResult {
+			ensure_root(origin)?;
+
+			let reopen_count = Election::<T>::try_mutate(
+				election_id,
+				|maybe_election| -> Result<u32, DispatchError> {
+					let election = maybe_election.as_mut().ok_or(Error::<T>::NoElectionExists)?;
+					ensure!(
+						election.status == ElectionStatus::Finalized,
+						Error::<T>::ElectionNotFinalized
+					);
+					election.status = ElectionStatus::Ended;
+					election.is_failed = false;
+					election.deposit = None;
+					election.reopen_count = election.reopen_count.saturating_add(1);
+					Ok(election.reopen_count)
+				},
+			)?;
+			FinalResults::<T>::remove(election_id);
+
+			Self::deposit_event(Event::ElectionReopened { election_id, reopen_count });
+			Ok(())
+		}
+
+		/// Correct the votes of a candidate by `delta`, for example after a reveal dispute. Must
+		/// be called by root.
+		///
+		/// Only allowed once the election has ended and before it is finalized. The votes of the
+		/// candidate saturate rather than overflow or drop below zero, and [`TotalVotes`] is
+		/// adjusted by the amount they actually changed by.
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::adjust_vote_count())]
+		pub fn adjust_vote_count(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			candidate_id: u32,
+			delta: i32,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			let election = Election::<T>::get(election_id).ok_or(Error::<T>::NoElectionExists)?;
+			Self::ensure_ended(election.status)?;
+			Self::ensure_candidate_exists(election_id, candidate_id)?;
+
+			let adjust = |count: u32, amount: u32| {
+				if delta.is_negative() {
+					count.saturating_sub(amount)
+				} else {
+					count.saturating_add(amount)
+				}
+			};
+			let applied = VoteCount::<T>::mutate(election_id, candidate_id, |count| {
+				let adjusted = adjust(*count, delta.unsigned_abs());
+				let applied = adjusted.abs_diff(*count);
+				*count = adjusted;
+				applied
+			});
+			TotalVotes::<T>::mutate(election_id, |total| *total = adjust(*total, applied));
+
+			Self::deposit_event(Event::VoteCountAdjusted { election_id, candidate_id, delta });
+			Ok(())
+		}
+
+		/// Hide the results of an election until it is finalized, or reveal them again. Must be
+		/// called by an election admin before the election is finalized.
+		///
+		/// Hidden results are still stored and tallied as usual, but [`Pallet::get_results`] and
+		/// the runtime API do not report them until the election is finalized, so that voters
+		/// cannot follow the count while voting.
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::WeightInfo::set_results_hidden())]
+		pub fn set_results_hidden(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			hidden: bool,
+		) -> DispatchResult {
+			Self::ensure_admin(origin)?;
+
+			Election::<T>::try_mutate(election_id, |maybe_election| -> DispatchResult {
+				let election = maybe_election.as_mut().ok_or(Error::<T>::NoElectionExists)?;
+				Self::ensure_not_closed(election.status)?;
+				election.results_hidden = hidden;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::ResultsVisibilityChanged { election_id, hidden });
+			Ok(())
+		}
+
+		/// Set the metadata URI of an election that has not been finalized, or clear it with
+		/// `None`. Must be called by an election admin.
+		#[pallet::call_index(36)]
+		#[pallet::weight(T::WeightInfo::set_election_metadata())]
+		pub fn set_election_metadata(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			uri: Option<Vec<u8>>,
+		) -> DispatchResult {
+			Self::ensure_admin(origin)?;
+
+			let bounded_uri = uri.clone().map(Self::bound_metadata_uri).transpose()?;
+			Election::<T>::try_mutate(election_id, |maybe_election| -> DispatchResult {
+				let election = maybe_election.as_mut().ok_or(Error::<T>::NoElectionExists)?;
+				ensure!(election.status != ElectionStatus::Finalized, Error::<T>::AlreadyFinalized);
+				election.metadata_uri = bounded_uri;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::MetadataUpdated { election_id, uri });
+			Ok(())
+		}
+
+		/// Remove the votes of an election but keep its candidates, so that it can be run again
+		/// with the same candidates. Must be called by the admin origin.
+		///
+		/// The election is ended while its votes are being removed. Once they are all removed,
+		/// it is back to not having been started, with the vote count of every candidate at zero,
+		/// and can be started again through [`Pallet::start_election`]. Unless
+		/// [`Config::AllowResetDuringVoting`] is set, active elections cannot be reset. The
+		/// creation deposit of a finalized election was already returned and is not returned
+		/// again. Like [`Pallet::reset_election`], at most [`Config::ResetBatchSize`] entries are
+		/// removed per call; the call has to be repeated until [`Event::VotesReset`] is emitted.
+		#[pallet::call_index(37)]
+		#[pallet::weight(T::WeightInfo::reset_votes(T::ResetBatchSize::get()))]
+		pub fn reset_votes(origin: OriginFor<T>, election_id: ElectionId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let progress = match VotesResetCursor::<T>::get(election_id) {
+				Some(progress) => progress,
+				None => {
+					Self::start_votes_reset(election_id)?;
+					ResetProgress::default()
+				},
+			};
+
+			match Self::clear_votes(election_id, progress, VOTE_STAGES) {
+				Some(progress) => {
+					VotesResetCursor::<T>::insert(election_id, progress);
+					Self::deposit_event(Event::VotesPartiallyReset { election_id });
+				},
+				None => {
+					VotesResetCursor::<T>::remove(election_id);
+					for candidate in Candidates::<T>::get(election_id) {
+						VoteCount::<T>::insert(election_id, candidate.id, 0);
+					}
+					Election::<T>::mutate(election_id, |maybe_election| {
+						if let Some(election) = maybe_election {
+							election.status = ElectionStatus::Created;
+						}
+					});
+					Self::schedule(election_id)?;
+					Self::deposit_event(Event::VotesReset { election_id });
+				},
+			}
+			Ok(())
+		}
+
+		/// Require the winner of a single-seat election to receive at least `threshold` of the
+		/// total votes, or drop the requirement with `None`. Must be called by an election admin
+		/// before the election is started.
+		///
+		/// If the candidate with the most votes falls short of the threshold at finalization, no
+		/// winner is declared and [`Event::ThresholdNotMet`] is emitted instead of
+		/// [`Event::WinnerDeclared`]. Ties are handled as without a threshold.
+		#[pallet::call_index(38)]
+		#[pallet::weight(T::WeightInfo::set_win_threshold())]
+		pub fn set_win_threshold(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			threshold: Option<Permill>,
+		) -> DispatchResult {
+			Self::ensure_admin(origin)?;
+
+			Election::<T>::try_mutate(election_id, |maybe_election| -> DispatchResult {
+				let election = maybe_election.as_mut().ok_or(Error::<T>::NoElectionExists)?;
+				Self::ensure_created(election.status)?;
+				ensure!(
+					threshold.is_none() || election.seats == 1,
+					Error::<T>::ThresholdRequiresSingleSeat
+				);
+				election.win_threshold = threshold;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::WinThresholdChanged { election_id, threshold });
+			Ok(())
+		}
+
+		/// Cast an approval ballot in an active approval election.
+		///
+		/// `approvals` lists the candidates the voter approves of, each of which receives the full
+		/// weight of the vote. It must approve at least one candidate and must not list a
+		/// candidate twice. [`HasVoted`] records the first approved candidate and the whole ballot
+		/// is kept in [`Approvals`].
+		///
+		/// Delegated votes are counted like in [`Pallet::cast_vote`].
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::cast_approval_vote(T::MaxDelegators::get()))]
+		pub fn cast_approval_vote(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			approvals: Vec<u32>,
+		) -> DispatchResult {
+			let voter = ensure_signed(origin)?;
+
+			let election = Self::ensure_voting_open(election_id)?;
+			Self::ensure_eligible(&voter)?;
+			ensure!(election.kind == ElectionKind::Approval, Error::<T>::WrongElectionKind);
+			ensure!(!HasVoted::<T>::contains_key(election_id, &voter), Error::<T>::AlreadyVoted);
+			Self::ensure_below_max_voters(election_id)?;
+
+			let approvals: BoundedVec<u32, T::MaxCandidates> =
+				approvals.try_into().map_err(|_| Error::<T>::TooManyCandidates)?;
+			let first_approval = *approvals.first().ok_or(Error::<T>::EmptyApproval)?;
+			let candidates = Candidates::<T>::get(election_id);
+			for (index, candidate_id) in approvals.iter().enumerate() {
+				ensure!(!approvals[..index].contains(candidate_id), Error::<T>::DuplicateApproval);
+				ensure!(
+					candidates.iter().any(|c| c.id == *candidate_id),
+					Error::<T>::InvalidCandidate
+				);
+			}
+
+			Self::unwind_delegation(election_id, &voter);
+			let weight = Self::vote_weight(election_id, &election, &voter)?
+				.saturating_add(Self::apply_delegations(election_id, &election, &voter));
+			HasVoted::<T>::insert(
+				election_id,
+				&voter,
+				VoteRecord {
+					candidate_id: first_approval,
+					weight,
+					block: frame_system::Pallet::<T>::block_number(),
+				},
+			);
+			let new_count = Self::add_to_tally(election_id, first_approval, weight);
+			for approved in approvals.iter().skip(1) {
+				Self::add_to_tally(election_id, *approved, weight);
+			}
+			Approvals::<T>::insert(election_id, &voter, approvals);
+			BallotCount::<T>::mutate(election_id, |count| *count = count.saturating_add(1));
+			let total_votes = TotalVotes::<T>::mutate(election_id, |total| {
+				*total = total.saturating_add(weight);
+				*total
+			});
+			let receipt = Self::issue_receipt(election_id, &voter, first_approval);
+
+			Self::deposit_vote_cast(
+				&election,
+				weight,
+				Event::VoteCast {
+					election_id,
+					voter,
+					candidate_id: Some(first_approval),
+					new_count: Some(new_count),
+					total_votes: Some(total_votes),
+					receipt,
+				},
+			);
+			Ok(())
+		}
+
+		/// Hand the election admin rights of the caller over to `new_admin` in a single step, so
+		/// that the set of admins never shrinks or grows during the handover. Must be called by
+		/// an election admin.
+		///
+		/// `new_admin` takes the place of the caller in [`Admins`] and must not be an admin yet.
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::WeightInfo::transfer_admin())]
+		pub fn transfer_admin(origin: OriginFor<T>, new_admin: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Admins::<T>::try_mutate(|admins| -> DispatchResult {
+				ensure!(!admins.contains(&new_admin), Error::<T>::AlreadyAdmin);
+				let admin = admins.iter_mut().find(|a| **a == who).ok_or(Error::<T>::NotAdmin)?;
+				*admin = new_admin.clone();
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::AdminTransferred { from: who, to: new_admin });
+			Ok(())
+		}
+
+		/// Give an election a dispute window of `dispute_window` blocks after the end of its
+		/// voting window, or remove it with `0`. Must be called by an election admin before the
+		/// election is started.
+		///
+		/// Only elections with a block window can have a dispute window. Note that a finalization
+		/// scheduled before the window is over fails.
+		#[pallet::call_index(41)]
+		#[pallet::weight(T::WeightInfo::set_dispute_window())]
+		pub fn set_dispute_window(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			dispute_window: BlockNumberFor<T>,
+		) -> DispatchResult {
+			Self::ensure_admin(origin)?;
+
+			Election::<T>::try_mutate(election_id, |maybe_election| -> DispatchResult {
+				let election = maybe_election.as_mut().ok_or(Error::<T>::NoElectionExists)?;
+				Self::ensure_created(election.status)?;
+				ensure!(
+					dispute_window.is_zero()
+						|| matches!(election.window, VotingWindow::Blocks { .. }),
+					Error::<T>::BlockWindowRequired
+				);
+				election.dispute_window = dispute_window;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::DisputeWindowChanged { election_id, dispute_window });
+			Ok(())
+		}
+
+		/// Dispute the results of an ended election for `reason`. Must be called by an election
+		/// admin during the dispute window of the election.
+		///
+		/// The election cannot be finalized, even after the window is over, until the dispute is
+		/// resolved with [`Pallet::resolve_dispute`]. An election can only be disputed once at a
+		/// time.
+		#[pallet::call_index(42)]
+		#[pallet::weight(T::WeightInfo::flag_dispute())]
+		pub fn flag_dispute(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			reason: Vec<u8>,
+		) -> DispatchResult {
+			Self::ensure_admin(origin)?;
+
+			let election = Election::<T>::get(election_id).ok_or(Error::<T>::NoElectionExists)?;
+			Self::ensure_ended(election.status)?;
+			ensure!(
+				Self::dispute_end(&election)
+					.is_some_and(|end| frame_system::Pallet::<T>::block_number() < end),
+				Error::<T>::DisputeWindowClosed
+			);
+			ensure!(!Disputes::<T>::contains_key(election_id), Error::<T>::ElectionDisputed);
+			let bounded_reason: BoundedVec<u8, T::MaxReasonLength> =
+				reason.clone().try_into().map_err(|_| Error::<T>::ReasonTooLong)?;
+			Disputes::<T>::insert(election_id, bounded_reason);
+
+			Self::deposit_event(Event::DisputeFlagged { election_id, reason });
+			Ok(())
+		}
+
+		/// Resolve the dispute of an election, so that it can be finalized again. Must be called
+		/// by the admin origin.
+		#[pallet::call_index(43)]
+		#[pallet::weight(T::WeightInfo::resolve_dispute())]
+		pub fn resolve_dispute(origin: OriginFor<T>, election_id: ElectionId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			Disputes::<T>::take(election_id).ok_or(Error::<T>::NotDisputed)?;
+
+			Self::deposit_event(Event::DisputeResolved { election_id });
+			Ok(())
+		}
+
+		/// Endorse a candidate of an election that has not been started yet, which it needs
+		/// [`Config::MinEndorsements`] of before the election can be started.
+		///
+		/// Only registered voters can endorse, and every voter can endorse a candidate once.
+		/// Self-registered candidates cannot endorse themselves, but may endorse other candidates.
+		#[pallet::call_index(44)]
+		#[pallet::weight(T::WeightInfo::endorse_candidate())]
+		pub fn endorse_candidate(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			candidate_id: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let election = Election::<T>::get(election_id).ok_or(Error::<T>::NoElectionExists)?;
+			Self::ensure_created(election.status)?;
+			ensure!(RegisteredVoters::<T>::contains_key(&who), Error::<T>::NotRegistered);
+			Self::ensure_candidate_exists(election_id, candidate_id)?;
+			ensure!(
+				CandidateOwners::<T>::get(election_id, &who)
+					.is_none_or(|(id, _)| id != candidate_id),
+				Error::<T>::SelfEndorsement
+			);
+			let key = (candidate_id, who.clone());
+			ensure!(
+				!Endorsements::<T>::contains_key(election_id, &key),
+				Error::<T>::AlreadyEndorsed
+			);
+
+			Endorsements::<T>::insert(election_id, key, ());
+			let endorsements = EndorsementCount::<T>::mutate(election_id, candidate_id, |count| {
+				*count = count.saturating_add(1);
+				*count
+			});
+
+			Self::deposit_event(Event::CandidateEndorsed {
+				election_id,
+				candidate_id,
+				endorser: who,
+				endorsements,
+			});
+			Ok(())
+		}
+
+		/// Cast a quadratic ballot in an active quadratic election.
+		///
+		/// `votes` lists `(candidate_id, votes)` pairs, each of which costs `votes * votes`
+		/// credits. The credits of the whole ballot must not exceed [`Config::VoteCreditBudget`]
+		/// and are recorded in [`SpentCredits`]. Every candidate receives its votes, which are
+		/// the square root of the credits spent on it. The ballot must list at least one
+		/// candidate, must not list a candidate twice and must cast at least one vote for each.
+		/// [`HasVoted`] records the first candidate on the ballot and all votes it cast.
+		///
+		/// Votes in quadratic elections are not weighted and delegations are not counted.
+		#[pallet::call_index(45)]
+		#[pallet::weight(T::WeightInfo::cast_quadratic_vote())]
+		pub fn cast_quadratic_vote(
+			origin: OriginFor<T>,
+			election_id: ElectionId,
+			votes: Vec<(u32, u32)>,
+		) -> DispatchResult {
+			let voter = ensure_signed(origin)?;
+
+			let election = Self::ensure_voting_open(election_id)?;
+			Self::ensure_eligible(&voter)?;
+			ensure!(election.kind == ElectionKind::Quadratic, Error::<T>::WrongElectionKind);
+			ensure!(!HasVoted::<T>::contains_key(election_id, &voter), Error::<T>::AlreadyVoted);
+			Self::ensure_below_max_voters(election_id)?;
+
+			let votes: BoundedVec<(u32, u32), T::MaxCandidates> =
+				votes.try_into().map_err(|_| Error::<T>::TooManyCandidates)?;
+			let (first_candidate, _) = *votes.first().ok_or(Error::<T>::EmptyQuadraticBallot)?;
+			let candidates = Candidates::<T>::get(election_id);
+			let mut credits = 0u32;
+			for (index, (candidate_id, count)) in votes.iter().enumerate() {
+				ensure!(
+					!votes[..index].iter().any(|(id, _)| id == candidate_id),
+					Error::<T>::DuplicateQuadraticVote
+				);
+				ensure!(
+					candidates.iter().any(|c| c.id == *candidate_id),
+					Error::<T>::InvalidCandidate
+				);
+				ensure!(*count > 0, Error::<T>::EmptyQuadraticBallot);
+				credits = u32::checked_mul(*count, *count)
+					.and_then(|cost| credits.checked_add(cost))
+					.ok_or(Error::<T>::InsufficientCredits)?;
+			}
+			ensure!(credits <= T::VoteCreditBudget::get(), Error::<T>::InsufficientCredits);
+
+			// The votes add up to at most the credits, which fit in a `u32`.
+			let weight = votes.iter().fold(0u32, |total, (_, count)| total.saturating_add(*count));
+			HasVoted::<T>::insert(
+				election_id,
+				&voter,
+				VoteRecord {
+					candidate_id: first_candidate,
+					weight,
+					block: frame_system::Pallet::<T>::block_number(),
+				},
+			);
+			SpentCredits::<T>::insert(election_id, &voter, credits);
+			let mut new_count = 0;
+			for (candidate_id, count) in votes {
+				let count = Self::add_to_tally(election_id, candidate_id, count);
+				if candidate_id == first_candidate {
+					new_count = count;
+				}
+			}
+			BallotCount::<T>::mutate(election_id, |count| *count = count.saturating_add(1));
+			let total_votes = TotalVotes::<T>::mutate(election_id, |total| {
+				*total = total.saturating_add(weight);
+				*total
+			});
+			let receipt = Self::issue_receipt(election_id, &voter, first_candidate);
+
+			Self::deposit_vote_cast(
+				&election,
+				weight,
+				Event::VoteCast {
+					election_id,
+					voter,
+					candidate_id: Some(first_candidate),
+					new_count: Some(new_count),
+					total_votes: Some(total_votes),
+					receipt,
+				},
+			);
+			Ok(())
+		}
+
+		/// Release the balance of `who` from the weighted elections it is locked for that have
+		/// been finalized, cancelled or removed since.
+		///
+		/// Any account can release the balance of another. The balance stays locked for the
+		/// elections that are not closed yet.
+		#[pallet::call_index(46)]
+		#[pallet::weight(T::WeightInfo::unlock_vote_balance(T::MaxVoteLocks::get()))]
+		pub fn unlock_vote_balance(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let mut locks = VoteLocks::<T>::get(&who);
+			ensure!(!locks.is_empty(), Error::<T>::NoVoteLock);
+			locks.retain(|(election_id, _)| {
+				Election::<T>::get(election_id)
+					.is_some_and(|election| Self::ensure_not_closed(election.status).is_ok())
+			});
+			let locked = Self::set_vote_lock(&who, &locks);
+			if locks.is_empty() {
+				VoteLocks::<T>::remove(&who);
+			} else {
+				VoteLocks::<T>::insert(&who, locks);
+			}
+
+			Self::deposit_event(Event::VoteBalanceUnlocked { who, locked });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Returns `(candidate_id, name, votes, owner)` for every candidate of an election,
+		/// followed by `(ABSTAIN, "Abstain", abstentions, None)`.
+		///
+		/// `owner` is the account of a self-registered candidate. The votes of finalized elections
+		/// are served from [`FinalResults`]. Nothing is returned while the results are hidden, see
+		/// [`Pallet::results_hidden`].
+		#[allow(clippy::type_complexity)]
+		pub fn get_results(
+			election_id: ElectionId,
+		) -> Vec<(u32, Vec<u8>, u32, Option<T::AccountId>)> {
+			if Self::results_hidden(election_id) {
+				return Vec::new()
+			}
+			let final_results = FinalResults::<T>::get(election_id);
+			let votes = |candidate_id| match &final_results {
+				Some(results) => results
+					.iter()
+					.find(|(id, _)| *id == candidate_id)
+					.map_or(0, |(_, votes)| *votes),
+				None => VoteCount::<T>::get(election_id, candidate_id),
+			};
+			Candidates::<T>::get(election_id)
+				.into_iter()
+				.map(|c| (c.id, c.name.to_vec(), votes(c.id), c.owner))
+				.chain(core::iter::once((
+					ABSTAIN,
+					b"Abstain".to_vec(),
+					AbstainCount::<T>::get(election_id),
+					None,
+				)))
+				.collect()
+		}
+
+		/// Returns `(candidate_id, name, votes)` for every candidate of an election and the
+		/// abstentions like [`Pallet::get_results`], listed in the given `order`.
+		pub fn get_results_ordered(
+			election_id: ElectionId,
+			order: ResultOrder,
+		) -> Vec<(u32, Vec<u8>, u32)> {
+			let mut results: Vec<(u32, Vec<u8>, u32)> = Self::get_results(election_id)
+				.into_iter()
+				.map(|(candidate_id, name, votes, _)| (candidate_id, name, votes))
+				.collect();
+			match order {
+				ResultOrder::ByVotesDesc =>
+					results.sort_by_key(|(_, _, votes)| core::cmp::Reverse(*votes)),
+				ResultOrder::ById => results.sort_by_key(|(candidate_id, _, _)| *candidate_id),
+				ResultOrder::Insertion => {},
+			}
+			results
+		}
+
+		/// Returns the results of an election like [`Pallet::get_results`], with each entry's share
+		/// of [`TotalVotes`] appended.
+		///
+		/// Shares are rounded down, so together they never exceed 100%. All shares are zero if no
+		/// votes have been cast.
+		pub fn results_with_percentage(
+			election_id: ElectionId,
+		) -> Vec<(u32, Vec<u8>, u32, Permill)> {
+			let total = TotalVotes::<T>::get(election_id);
+			Self::get_results(election_id)
+				.into_iter()
+				.map(|(candidate_id, name, votes, _)| {
+					let share = if total == 0 {
+						Permill::zero()
+					} else {
+						Permill::from_rational_with_rounding(votes, total, Rounding::Down)
+							.unwrap_or_else(|_| Permill::one())
+					};
+					(candidate_id, name, votes, share)
+				})
+				.collect()
+		}
+
+		/// Returns the share of the accounts in [`RegisteredVoters`] that cast a ballot in an
+		/// election, including abstentions, see [`BallotCount`].
+		///
+		/// Every ballot counts once, whatever its weight or the kind of the election, and accounts
+		/// that delegated their vote are not counted. The share is rounded down, and zero if no
+		/// accounts are registered. Without [`Config::RequireRegistration`] accounts that are not
+		/// registered can vote too, in which case the turnout is capped at 100%.
+		pub fn turnout(election_id: ElectionId) -> Permill {
+			let registered = RegisteredVoterCount::<T>::get();
+			if registered == 0 {
+				return Permill::zero()
+			}
+			Permill::from_rational_with_rounding(
+				BallotCount::<T>::get(election_id),
+				registered,
+				Rounding::Down,
+			)
+			.unwrap_or_else(|_| Permill::one())
+		}
+
+		/// Returns the share of the votes of a candidate among the votes of an election that were
+		/// not abstentions.
+		///
+		/// The share is rounded down, and zero if no votes other than abstentions have been cast.
+		/// In approval elections the approvals of a candidate can exceed the number of decided
+		/// votes, in which case the share is capped at 100%.
+		pub fn candidate_share_of_decided(election_id: ElectionId, candidate_id: u32) -> Permill {
+			let decided = TotalVotes::<T>::get(election_id)
+				.saturating_sub(AbstainCount::<T>::get(election_id));
+			if decided == 0 {
+				return Permill::zero()
+			}
+			Permill::from_rational_with_rounding(
+				VoteCount::<T>::get(election_id, candidate_id),
+				decided,
+				Rounding::Down,
+			)
+			.unwrap_or_else(|_| Permill::one())
+		}
+
+		/// Returns the title, candidates with their votes, total votes, abstentions and status of
+		/// an election at once, or `None` if no election exists with the given identifier.
+		///
+		/// Elections can be exported at any stage; the results are only final once the election
+		/// is finalized. While the results are hidden, see [`Pallet::results_hidden`], every count
+		/// is reported as `0`.
+		pub fn export_results(election_id: ElectionId) -> Option<ElectionExport<T>> {
+			let election = Election::<T>::get(election_id)?;
+			let hidden = Self::results_hidden(election_id);
+			let count = |votes: u32| if hidden { 0 } else { votes };
+			Some(ElectionExport {
+				title: election.title,
+				candidates: Candidates::<T>::get(election_id)
+					.into_iter()
+					.map(|c| (c.id, c.name, count(VoteCount::<T>::get(election_id, c.id))))
+					.collect(),
+				total_votes: count(TotalVotes::<T>::get(election_id)),
+				abstentions: count(AbstainCount::<T>::get(election_id)),
+				status: election.status,
+				is_failed: election.is_failed,
+			})
+		}
+
+		/// Returns the title, status, voting window, number of candidates and total votes of an
+		/// election at once, or `None` if no election exists with the given identifier.
+		pub fn election_snapshot(election_id: ElectionId) -> Option<ElectionSnapshot<T>> {
+			let election = Election::<T>::get(election_id)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			let moment = T::Time::now();
+			let (start_block, end_block) = match election.window {
+				VotingWindow::Blocks { start, end } => (Some(start), Some(end)),
+				VotingWindow::Time { .. } => (None, None),
+			};
+			Some(ElectionSnapshot {
+				within_window: election.window.has_opened(&now, &moment)
+					&& !election.window.has_closed(&now, &moment),
+				title: election.title,
+				status: election.status,
+				is_failed: election.is_failed,
+				start_block,
+				end_block,
+				candidate_count: Self::candidate_count(election_id),
+				total_votes: if election.results_hidden
+					&& election.status != ElectionStatus::Finalized
+				{
+					0
+				} else {
+					TotalVotes::<T>::get(election_id)
+				},
+			})
+		}
+
+		/// Returns up to `limit` candidates of an election, starting at position `offset` in the
+		/// order they were added.
+		///
+		/// Offsets past the last candidate yield an empty list.
+		pub fn candidates_paged(
+			election_id: ElectionId,
+			offset: u32,
+			limit: u32,
+		) -> Vec<Candidate<T>> {
+			Candidates::<T>::get(election_id)
+				.into_iter()
+				.skip(offset as usize)
+				.take(limit as usize)
+				.collect()
+		}
+
+		/// Returns the candidate of an election with the given identifier, if it stands.
+		pub fn get_candidate(election_id: ElectionId, candidate_id: u32) -> Option<Candidate<T>> {
+			Candidates::<T>::get(election_id).into_iter().find(|c| c.id == candidate_id)
+		}
+
+		/// The number of candidates standing in an election.
+		pub fn candidate_count(election_id: ElectionId) -> u32 {
+			Candidates::<T>::decode_len(election_id).unwrap_or_default() as u32
+		}
+
+		/// Returns the receipt of the latest vote of `who` in an election, as reported in
+		/// [`Event::VoteCast`].
+		pub fn verify_receipt(election_id: ElectionId, who: &T::AccountId) -> Option<T::Hash> {
+			Receipts::<T>::get(election_id, who)
+		}
+
+		/// Whether the results of an election are hidden through [`Pallet::set_results_hidden`] and
+		/// the election has not been finalized yet.
+		pub fn results_hidden(election_id: ElectionId) -> bool {
+			Election::<T>::get(election_id).is_some_and(|election| {
+				election.results_hidden && election.status != ElectionStatus::Finalized
+			})
+		}
+
+		/// Compute the outcome of an election, reporting ties explicitly.
+		///
+		/// Plurality, approval and quadratic elections are won by the candidate with the most
+		/// votes, ranked-choice elections by the outcome of [`Pallet::instant_runoff`].
+		pub fn get_winner(election_id: ElectionId) -> WinnerResult {
+			match Election::<T>::get(election_id).map(|election| election.kind) {
+				Some(ElectionKind::RankedChoice) => Self::instant_runoff(election_id).0,
+				_ => Self::plurality_winner(election_id),
+			}
+		}
+
+		/// Returns `(candidate_id, votes)` of the candidates filling the seats of an election, from
+		/// most to fewest votes.
+		///
+		/// Candidates with equal votes are ordered by identifier, so a tie for the last seat goes
+		/// to the candidate added first. Candidates without votes never win a seat.
+		pub fn get_top_winners(election_id: ElectionId) -> Vec<(u32, u32)> {
+			let seats = Election::<T>::get(election_id).map_or(0, |election| election.seats);
+			let mut results: Vec<(u32, u32)> = Candidates::<T>::get(election_id)
+				.iter()
+				.map(|c| (c.id, VoteCount::<T>::get(election_id, c.id)))
+				.filter(|(_, votes)| *votes > 0)
+				.collect();
+			results.sort_by(|(a_id, a_votes), (b_id, b_votes)| {
+				b_votes.cmp(a_votes).then(a_id.cmp(b_id))
+			});
+			results.truncate(seats as usize);
+			results
+		}
+
+		/// Returns up to `limit` archived election summaries together with their archive
+		/// identifiers, starting at archive identifier `offset`.
+		pub fn past_elections(offset: u32, limit: u32) -> Vec<(u32, ElectionSummary<T>)> {
+			let end = offset.saturating_add(limit).min(NextArchiveId::<T>::get());
+			(offset..end)
+				.filter_map(|archive_id| {
+					PastElections::<T>::get(archive_id).map(|summary| (archive_id, summary))
+				})
+				.collect()
+		}
+
+		/// Compute the candidate with the most votes in an election.
+		fn plurality_winner(election_id: ElectionId) -> WinnerResult {
+			let mut max_votes = 0;
+			let mut leaders = Vec::new();
+			for candidate in Candidates::<T>::get(election_id) {
+				let votes = VoteCount::<T>::get(election_id, candidate.id);
+				if votes > max_votes {
+					max_votes = votes;
+					leaders.clear();
+					leaders.push(candidate.id);
+				} else if votes == max_votes && votes > 0 {
+					leaders.push(candidate.id);
+				}
+			}
+
+			match leaders.len() {
+				0 => WinnerResult::NoVotes,
+				1 => WinnerResult::Winner(leaders[0], max_votes),
+				_ => WinnerResult::Tie(leaders),
+			}
+		}
+
+		/// Check whether `who` could vote for `candidate_id` in an election with
+		/// [`Pallet::cast_vote`] right now, without changing any state.
+		///
+		/// Returns the error the vote would fail with otherwise, so that front-ends can tell
+		/// before asking for a signature.
+		pub fn can_vote(
+			who: &T::AccountId,
+			election_id: ElectionId,
+			candidate_id: u32,
+		) -> Result<(), Error<T>> {
+			Self::ensure_can_vote(who, election_id, candidate_id).map(|_| ())
+		}
+
+		/// Whether an election accepts votes right now: it has been started, is not paused and
+		/// the current block or time is inside its voting window.
+		pub fn is_voting_open(election_id: ElectionId) -> bool {
+			Self::ensure_voting_open(election_id).is_ok()
+		}
+
+		/// Whether `who` has voted in an election.
+		pub fn has_account_voted(election_id: ElectionId, who: &T::AccountId) -> bool {
+			HasVoted::<T>::contains_key(election_id, who)
+		}
+
+		/// The nullifier that records the vote of `who` in an unlisted election.
+		pub fn nullifier(election_id: ElectionId, who: &T::AccountId) -> T::Hash {
+			T::Hashing::hash_of(&(election_id, who))
+		}
+
+		/// The candidate `who` voted for in an election, if any.
+		pub fn get_vote_for_account(election_id: ElectionId, who: &T::AccountId) -> Option<u32> {
+			HasVoted::<T>::get(election_id, who).map(|vote| vote.candidate_id)
+		}
+
+		/// The candidate `who` voted for in an election and the block the vote was cast or last
+		/// changed in, if `who` voted.
+		pub fn vote_record(
+			election_id: ElectionId,
+			who: &T::AccountId,
+		) -> Option<(u32, BlockNumberFor<T>)> {
+			HasVoted::<T>::get(election_id, who).map(|vote| (vote.candidate_id, vote.block))
+		}
+
+		/// The accounts whose vote in an election went to `candidate_id`, or to the first
+		/// preference in ranked-choice elections.
+		///
+		/// Iterates over every vote of the election, so it is meant to be called off-chain
+		/// through the runtime API and not from dispatchables. Nothing is returned while the
+		/// results are hidden, see [`Pallet::results_hidden`].
+		pub fn voters_for_candidate(
+			election_id: ElectionId,
+			candidate_id: u32,
+		) -> Vec<T::AccountId> {
+			if Self::results_hidden(election_id) {
+				return Vec::new()
+			}
+			HasVoted::<T>::iter_prefix(election_id)
+				.filter(|(_, vote)| vote.candidate_id == candidate_id)
+				.map(|(who, _)| who)
+				.collect()
+		}
+
+		/// Every account that voted in an election.
+		///
+		/// Iterates over every vote of the election, so it is meant to be called off-chain
+		/// through the runtime API and not from dispatchables. Nothing is returned while the
+		/// results are hidden, see [`Pallet::results_hidden`].
+		pub fn all_voters(election_id: ElectionId) -> Vec<T::AccountId> {
+			if Self::results_hidden(election_id) {
+				return Vec::new()
+			}
+			HasVoted::<T>::iter_key_prefix(election_id).collect()
+		}
+
+		/// Run the instant-runoff tally of a ranked-choice election.
+		///
+		/// In every round each ballot counts for its most preferred candidate that has not been
+		/// eliminated yet. A candidate holding a majority of the ballots still in play wins;
+		/// otherwise the candidate with the fewest votes is eliminated, the one added last losing
+		/// a tie, and the next round begins. If all remaining candidates hold the same number of
+		/// votes the outcome is a tie.
+		///
+		/// Returns the outcome together with the number of rounds that were run. The vote count
+		/// of a winner is its tally in the final round. In weighted elections every ballot counts
+		/// with the weight recorded in [`HasVoted`].
+		pub fn instant_runoff(election_id: ElectionId) -> (WinnerResult, u32) {
+			Self::tally_instant_runoff(election_id, Self::ranked_ballots(election_id, u32::MAX))
+		}
+
+		/// Load up to `limit` ranked ballots of an election together with their weights.
+		fn ranked_ballots(
+			election_id: ElectionId,
+			limit: u32,
+		) -> Vec<(BoundedVec<u32, T::MaxCandidates>, u32)> {
+			Rankings::<T>::iter_prefix(election_id)
+				.take(limit as usize)
+				.map(|(voter, ballot)| {
+					let weight =
+						HasVoted::<T>::get(election_id, &voter).map_or(1, |vote| vote.weight);
+					(ballot, weight)
+				})
+				.collect()
+		}
+
+		/// Run the instant-runoff tally described in [`Pallet::instant_runoff`] over `ballots`.
+		fn tally_instant_runoff(
+			election_id: ElectionId,
+			ballots: Vec<(BoundedVec<u32, T::MaxCandidates>, u32)>,
+		) -> (WinnerResult, u32) {
+			let mut remaining: Vec<u32> =
+				Candidates::<T>::get(election_id).iter().map(|c| c.id).collect();
+			let mut rounds = 0u32;
+
+			loop {
+				rounds = rounds.saturating_add(1);
+				let mut tallies: Vec<(u32, u32)> = remaining.iter().map(|id| (*id, 0)).collect();
+				let mut active_ballots = 0u32;
+				for (ballot, weight) in &ballots {
+					let preference = ballot.iter().find(|id| remaining.contains(id));
+					if let Some(tally) =
+						preference.and_then(|id| tallies.iter_mut().find(|(c, _)| c == id))
+					{
+						tally.1 = tally.1.saturating_add(*weight);
+						active_ballots = active_ballots.saturating_add(*weight);
+					}
+				}
+
+				if active_ballots == 0 {
+					return (WinnerResult::NoVotes, rounds)
+				}
+				if let Some((winner, votes)) =
+					tallies.iter().find(|(_, votes)| *votes > active_ballots / 2)
+				{
+					return (WinnerResult::Winner(*winner, *votes), rounds)
+				}
+				let lowest = tallies.iter().map(|(_, votes)| *votes).min().unwrap_or_default();
+				if tallies.iter().all(|(_, votes)| *votes == lowest) {
+					return (WinnerResult::Tie(remaining), rounds)
+				}
+				if let Some((eliminated, _)) =
+					tallies.iter().rev().find(|(_, votes)| *votes == lowest)
+				{
+					remaining.retain(|id| id != eliminated);
+				}
+			}
+		}
+
+		/// Compute the receipt of a vote of `voter` for `candidate_id` in an election in the
+		/// current block and store it in [`Receipts`].
+		fn issue_receipt(
+			election_id: ElectionId,
+			voter: &T::AccountId,
+			candidate_id: u32,
+		) -> T::Hash {
+			let block = frame_system::Pallet::<T>::block_number();
+			let digest = BlakeTwo256::hash_of(&(election_id, voter, candidate_id, block));
+			let receipt = T::Hash::decode(&mut TrailingZeroInput::new(digest.as_ref()))
+				.expect("input is padded with zeroes; qed");
+			Receipts::<T>::insert(election_id, voter, receipt);
+			receipt
+		}
+
+		/// Pick one of the `tied` candidates of an election using [`Config::Randomness`].
+		fn break_tie(election_id: ElectionId, tied: &[u32]) -> u32 {
+			let (seed, _) = T::Randomness::random(&(b"voting/tie", election_id).encode());
+			let random = u32::decode(&mut TrailingZeroInput::new(seed.as_ref()))
+				.expect("input is padded with zeroes; qed");
+			tied[random as usize % tied.len()]
+		}
+
+		/// The weight a vote of `voter` counts with in `election_id`.
+		///
+		/// Unweighted elections count every vote once. In weighted elections the free balance of
+		/// the voter is locked until the election is closed, see [`VoteLocks`].
+		fn vote_weight(
+			election_id: ElectionId,
+			election: &ElectionInfo<T>,
+			voter: &T::AccountId,
+		) -> Result<u32, DispatchError> {
+			if !election.weighted {
+				return Ok(1)
+			}
+			let balance = T::Currency::free_balance(voter);
+			VoteLocks::<T>::try_mutate(voter, |locks| -> DispatchResult {
+				match locks.iter_mut().find(|(id, _)| *id == election_id) {
+					Some((_, amount)) => *amount = (*amount).max(balance),
+					None => locks
+						.try_push((election_id, balance))
+						.map_err(|_| Error::<T>::TooManyVoteLocks)?,
+				}
+				Self::set_vote_lock(voter, locks);
+				Ok(())
+			})?;
+			Ok(T::BalanceToVoteWeight::convert(balance))
+		}
+
+		/// Lock the balance of `who` for the largest amount in `locks`, or remove the lock if
+		/// there is none, and return the locked amount.
+		fn set_vote_lock(who: &T::AccountId, locks: &[(ElectionId, BalanceOf<T>)]) -> BalanceOf<T> {
+			match locks.iter().map(|(_, amount)| *amount).max() {
+				Some(amount) => {
+					T::Currency::set_lock(VOTE_LOCK_ID, who, amount, WithdrawReasons::all());
+					amount
+				},
+				None => {
+					T::Currency::remove_lock(VOTE_LOCK_ID, who);
+					Zero::zero()
+				},
+			}
+		}
+
+		/// Store the summary of a just finalized or cancelled election in [`PastElections`].
+		///
+		/// Returns the `(candidate_id, votes)` of every candidate recorded in the summary.
+		fn archive(
+			election_id: ElectionId,
+			title: BoundedVec<u8, T::MaxTitleLength>,
+			winners: Vec<u32>,
+			total_votes: u32,
+			cancellation_reason: Option<BoundedVec<u8, T::MaxReasonLength>>,
+		) -> BoundedVec<(u32, u32), T::MaxCandidates> {
+			let vote_counts: Vec<(u32, u32)> = Candidates::<T>::get(election_id)
+				.iter()
+				.map(|c| (c.id, VoteCount::<T>::get(election_id, c.id)))
+				.collect();
+			// Both hold at most one entry per candidate, of which there are at most
+			// `MaxCandidates`.
+			let vote_counts = BoundedVec::truncate_from(vote_counts);
+			let summary = ElectionSummary {
+				election_id,
+				title,
+				winners: BoundedVec::truncate_from(winners),
+				total_votes,
+				vote_counts: vote_counts.clone(),
+				abstentions: AbstainCount::<T>::get(election_id),
+				cancellation_reason,
+			};
+
+			// An election is archived once per finalization, and saturating at `u32::MAX` reuses
+			// the last identifier only after that many finalizations.
+			let archive_id = NextArchiveId::<T>::mutate(|next| {
+				let archive_id = *next;
+				*next = next.saturating_add(1);
+				archive_id
+			});
+			PastElections::<T>::insert(archive_id, summary);
+			Self::deposit_event(Event::ElectionArchived { election_id, archive_id });
+			vote_counts
+		}
+
+		/// Count the weight of the accounts that delegated to `delegate` into its vote in
+		/// `election`, and return their total weight.
+		///
+		/// Delegators that already voted, whose weight is already counted in the vote of another
+		/// delegate, that may not vote, or whose balance cannot be locked for another election
+		/// are skipped.
+		fn apply_delegations(
+			election_id: ElectionId,
+			election: &ElectionInfo<T>,
+			delegate: &T::AccountId,
+		) -> u32 {
+			let mut applied = BoundedVec::<T::AccountId, T::MaxDelegators>::new();
+			let mut total = 0u32;
+			for delegator in Delegators::<T>::get(delegate) {
+				if HasVoted::<T>::contains_key(election_id, &delegator)
+					|| DelegatedVotes::<T>::contains_key(election_id, &delegator)
+					|| Self::ensure_eligible(&delegator).is_err()
+				{
+					continue
+				}
+				let Ok(weight) = Self::vote_weight(election_id, election, &delegator) else {
+					continue
+				};
+				DelegatedVotes::<T>::insert(election_id, &delegator, (delegate.clone(), weight));
+				total = total.saturating_add(weight);
+				// Cannot fail, as there are at most `MaxDelegators` delegators.
+				let _ = applied.try_push(delegator);
+			}
+			if !applied.is_empty() {
+				AppliedDelegations::<T>::insert(election_id, delegate, applied);
+			}
+			total
+		}
+
+		/// Take the weight of `delegator` back out of the vote of its delegate in an election,
+		/// if it was counted there.
+		fn unwind_delegation(election_id: ElectionId, delegator: &T::AccountId) {
+			let Some((delegate, weight)) = DelegatedVotes::<T>::take(election_id, delegator) else {
+				return
+			};
+			AppliedDelegations::<T>::mutate_exists(election_id, &delegate, |applied| {
+				if let Some(delegators) = applied {
+					delegators.retain(|account| account != delegator);
+					if delegators.is_empty() {
+						*applied = None;
+					}
+				}
+			});
+			HasVoted::<T>::mutate(election_id, &delegate, |vote| {
+				if let Some(vote) = vote {
+					vote.weight = vote.weight.saturating_sub(weight);
+					Self::remove_vote_from_tally(election_id, &delegate, vote.candidate_id, weight);
+				}
+			});
+			TotalVotes::<T>::mutate(election_id, |total| *total = total.saturating_sub(weight));
+		}
+
+		/// Remove `delegator` from the accounts that delegated to `delegate`.
+		fn remove_delegator(delegate: &T::AccountId, delegator: &T::AccountId) {
+			Delegators::<T>::mutate_exists(delegate, |delegators| {
+				if let Some(accounts) = delegators {
+					accounts.retain(|account| account != delegator);
+					if accounts.is_empty() {
+						*delegators = None;
+					}
+				}
+			});
+		}
+
+		/// Remove an election together with its candidates and votes, or continue removing the
+		/// votes of an election whose reset did not fit in a single call.
+		///
+		/// Elections that have not been finalized or cancelled are only removed if the reset was
+		/// `confirmed` through [`Pallet::confirm_reset`].
+		fn do_reset(election_id: ElectionId, confirmed: bool) -> DispatchResult {
+			let progress = match ResetCursor::<T>::get(election_id) {
+				Some(progress) => progress,
+				None => {
+					Self::start_reset(election_id, confirmed)?;
+					ResetProgress::default()
+				},
+			};
+
+			match Self::clear_votes(election_id, progress, RESET_STAGES) {
+				Some(progress) => {
+					ResetCursor::<T>::insert(election_id, progress);
+					Self::deposit_event(Event::ElectionPartiallyReset { election_id });
+				},
+				None => {
+					ResetCursor::<T>::remove(election_id);
+					Self::deposit_event(Event::ElectionReset { election_id });
+				},
+			}
+			Ok(())
+		}
+
+		/// Remove an election together with its candidates, and settle its deposit and the bonds
+		/// of its candidates. Its votes are left to [`Pallet::clear_votes`].
+		fn start_reset(election_id: ElectionId, confirmed: bool) -> DispatchResult {
+			let election = Election::<T>::take(election_id).ok_or(Error::<T>::NoElectionExists)?;
+			Self::unschedule(election_id);
+			if !matches!(election.status, ElectionStatus::Finalized | ElectionStatus::Cancelled) {
+				ensure!(T::AllowResetDuringVoting::get(), Error::<T>::ElectionIsActive);
+				ensure!(confirmed, Error::<T>::ResetNotConfirmed);
+			}
+			let finalized = election.status == ElectionStatus::Finalized;
+			if let (false, Some((who, amount))) = (finalized, election.deposit) {
+				let _ = T::Currency::slash_reserved(&who, amount);
+			}
+
+			Candidates::<T>::remove(election_id);
+			let _ = CandidateExists::<T>::clear_prefix(election_id, T::MaxCandidates::get(), None);
+			NextCandidateId::<T>::remove(election_id);
+			let destination = T::SlashedFundsDestination::get();
+			let mut count = 0;
+			for (who, (_, bond)) in CandidateOwners::<T>::drain_prefix(election_id) {
+				match &destination {
+					Some(destination) => {
+						let _ = T::Currency::repatriate_reserved(
+							&who,
+							destination,
+							bond,
+							BalanceStatus::Free,
+						);
+					},
+					None => {
+						T::Currency::unreserve(&who, bond);
+					},
+				}
+				Self::release_candidacy(&who);
+				count += 1;
+			}
+			AbstainCount::<T>::remove(election_id);
+			TotalVotes::<T>::remove(election_id);
+			RankedBallotCount::<T>::remove(election_id);
+			BallotCount::<T>::remove(election_id);
+			CandidatesLocked::<T>::remove(election_id);
+			PendingResets::<T>::remove(election_id);
+			FinalResults::<T>::remove(election_id);
+			VotesResetCursor::<T>::remove(election_id);
+			Disputes::<T>::remove(election_id);
+
+			Self::deposit_event(Event::BondsSettled { election_id, count });
+			Ok(())
+		}
+
+		/// Mark an election as unfinalized and inactive and remove its tallies, leaving its votes
+		/// to [`Pallet::clear_votes`].
+		fn start_votes_reset(election_id: ElectionId) -> DispatchResult {
+			Election::<T>::try_mutate(election_id, |maybe_election| -> DispatchResult {
+				let election = maybe_election.as_mut().ok_or(Error::<T>::NoElectionExists)?;
+				ensure!(
+					election.status != ElectionStatus::Cancelled,
+					Error::<T>::ElectionCancelled
+				);
+				ensure!(
+					!election.status.is_active() || T::AllowResetDuringVoting::get(),
+					Error::<T>::ElectionIsActive
+				);
+				if election.status == ElectionStatus::Finalized {
+					election.deposit = None;
+				}
+				if election.status != ElectionStatus::Created {
+					election.status = ElectionStatus::Ended;
+					Self::unschedule(election_id);
+				}
+				election.is_failed = false;
+				Ok(())
+			})?;
+			AbstainCount::<T>::remove(election_id);
+			TotalVotes::<T>::remove(election_id);
+			RankedBallotCount::<T>::remove(election_id);
+			BallotCount::<T>::remove(election_id);
+			FinalResults::<T>::remove(election_id);
+			Ok(())
+		}
+
+		/// Remove up to [`Config::ResetBatchSize`] entries of the maps holding the votes of an
+		/// election that is being reset, or whose votes are, starting from `progress`. Only the
+		/// first `stages` maps are cleared, so that resetting the votes keeps the endorsements.
+		///
+		/// Returns where to continue from, or `None` once every map has been cleared.
+		fn clear_votes(
+			election_id: ElectionId,
+			mut progress: ResetProgress,
+			stages: u8,
+		) -> Option<ResetProgress> {
+			let mut budget = T::ResetBatchSize::get();
+			while progress.stage < stages {
+				if budget == 0 {
+					return Some(progress)
+				}
+				let cursor = progress.cursor.take();
+				let cursor = cursor.as_ref().map(|cursor| &cursor[..]);
+				let result = match progress.stage {
+					0 => HasVoted::<T>::clear_prefix(election_id, budget, cursor),
+					1 => Commitments::<T>::clear_prefix(election_id, budget, cursor),
+					2 => Rankings::<T>::clear_prefix(election_id, budget, cursor),
+					3 => DelegatedVotes::<T>::clear_prefix(election_id, budget, cursor),
+					4 => AppliedDelegations::<T>::clear_prefix(election_id, budget, cursor),
+					5 => VoteCount::<T>::clear_prefix(election_id, budget, cursor),
+					6 => VoteLog::<T>::clear_prefix(election_id, budget, cursor),
+					7 => Approvals::<T>::clear_prefix(election_id, budget, cursor),
+					8 => SpentCredits::<T>::clear_prefix(election_id, budget, cursor),
+					9 => Nullifiers::<T>::clear_prefix(election_id, budget, cursor),
+					10 => Receipts::<T>::clear_prefix(election_id, budget, cursor),
+					11 => Endorsements::<T>::clear_prefix(election_id, budget, cursor),
+					_ => EndorsementCount::<T>::clear_prefix(election_id, budget, cursor),
+				};
+				budget = budget.saturating_sub(result.unique);
+				match result.maybe_cursor {
+					// A cursor that does not fit is dropped, which only makes the next call scan
+					// the map from its first key again.
+					Some(cursor) => {
+						progress.cursor = BoundedVec::try_from(cursor).ok();
+						return Some(progress)
+					},
+					None => progress.stage = progress.stage.saturating_add(1),
+				}
+			}
+			None
+		}
+
+		/// Freeze the results of an election, declare its winners and archive its summary.
+		///
+		/// Unless `force` is set, the election must have ended. A forced election that is still
+		/// active is ended first.
+		fn do_finalize(election_id: ElectionId, voters: u32, force: bool) -> DispatchResult {
+			let total_votes = TotalVotes::<T>::get(election_id);
+			let required = T::MinQuorum::get();
+			let quorum_met = total_votes >= required;
+
+			let (was_active, kind, seats, title, win_threshold, deposit) =
+				Election::<T>::try_mutate(
+					election_id,
+					|maybe_election| -> Result<_, DispatchError> {
+						let election =
+							maybe_election.as_mut().ok_or(Error::<T>::NoElectionExists)?;
+						let was_active = election.status.is_active();
+						ensure!(force || !was_active, Error::<T>::ElectionIsActive);
+						Self::ensure_not_closed(election.status)?;
+						ensure!(
+							force || election.status == ElectionStatus::Ended,
+							Error::<T>::ElectionNotStarted
+						);
+						if let Some(reveal_end_block) = election.reveal_end_block {
+							ensure!(
+								frame_system::Pallet::<T>::block_number() >= reveal_end_block,
+								Error::<T>::RevealPeriodOpen
+							);
+						}
+						if let (false, Some(dispute_end)) = (force, Self::dispute_end(election)) {
+							ensure!(
+								frame_system::Pallet::<T>::block_number() >= dispute_end,
+								Error::<T>::DisputeWindowOpen
+							);
+						}
+						ensure!(
+							!Disputes::<T>::contains_key(election_id),
+							Error::<T>::ElectionDisputed
+						);
+						election.status = ElectionStatus::Finalized;
+						election.is_failed = !quorum_met;
+						Ok((
+							was_active,
+							election.kind,
+							election.seats,
+							election.title.clone(),
+							election.win_threshold,
+							election.deposit.clone(),
+						))
+					},
+				)?;
+			if let Some((who, amount)) = deposit {
+				T::Currency::unreserve(&who, amount);
+			}
+
+			Self::unschedule(election_id);
+			if was_active {
+				Self::deposit_event(Event::ElectionEnded {
+					election_id,
+					block: frame_system::Pallet::<T>::block_number(),
+				});
+			}
+			Self::deposit_event(Event::ElectionFinalized { election_id, total_votes });
+			let winners = if !quorum_met {
+				Self::deposit_event(Event::ElectionFailedQuorum {
+					election_id,
+					total_votes,
+					required,
+				});
+				Vec::new()
+			} else if seats > 1 {
+				let candidate_ids: Vec<u32> =
+					Self::get_top_winners(election_id).into_iter().map(|(id, _)| id).collect();
+				Self::deposit_event(Event::WinnersDeclared {
+					election_id,
+					candidate_ids: candidate_ids.clone(),
+				});
+				candidate_ids
+			} else {
+				let outcome = match kind {
+					ElectionKind::Plurality | ElectionKind::Approval | ElectionKind::Quadratic =>
+						Self::plurality_winner(election_id),
+					ElectionKind::RankedChoice => {
+						let ballots = Self::ranked_ballots(election_id, voters.saturating_add(1));
+						ensure!(ballots.len() <= voters as usize, Error::<T>::WitnessTooLow);
+						let (outcome, rounds) = Self::tally_instant_runoff(election_id, ballots);
+						Self::deposit_event(Event::RankedResult { election_id, rounds });
+						outcome
+					},
+				};
+				match outcome {
+					WinnerResult::Winner(candidate_id, votes)
+						if win_threshold
+							.is_some_and(|threshold| votes < threshold.mul_ceil(total_votes)) =>
+					{
+						let share = Permill::from_rational_with_rounding(
+							votes,
+							total_votes,
+							Rounding::Down,
+						)
+						.unwrap_or_else(|_| Permill::one());
+						Self::deposit_event(Event::ThresholdNotMet {
+							election_id,
+							top_candidate: candidate_id,
+							share,
+						});
+						Vec::new()
+					},
+					WinnerResult::Winner(candidate_id, votes) => {
+						Self::deposit_event(Event::WinnerDeclared {
+							election_id,
+							candidate_id,
+							votes,
+						});
+						vec![candidate_id]
+					},
+					WinnerResult::Tie(tied) if T::RandomTieBreaking::get() => {
+						let winner = Self::break_tie(election_id, &tied);
+						Self::deposit_event(Event::TieBrokenRandomly { election_id, winner, tied });
+						vec![winner]
+					},
+					WinnerResult::Tie(candidate_ids) => {
+						Self::deposit_event(Event::ElectionTied { election_id, candidate_ids });
+						Vec::new()
+					},
+					WinnerResult::NoVotes => Vec::new(),
+				}
+			};
+
+			let winner = winners.first().copied();
+			let vote_counts = Self::archive(election_id, title.clone(), winners, total_votes, None);
+			Self::deposit_event(Event::ElectionFinalizedDetailed {
+				election_id,
+				title: title.into_inner(),
+				results: vote_counts.to_vec(),
+				total_votes,
+				winner,
+			});
+			FinalResults::<T>::insert(election_id, vote_counts);
+			Ok(())
+		}
+
+		/// Validate and store a new election, assigning it the next free [`ElectionId`].
+		fn insert_election(
+			title: Vec<u8>,
+			window: VotingWindowOf<T>,
+			kind: ElectionKind,
+			weighted: bool,
+			reveal_end_block: Option<BlockNumberFor<T>>,
+			seats: u32,
+			max_candidates: u32,
+			unlisted: bool,
+			allow_write_ins: bool,
+			metadata_uri: Option<Vec<u8>>,
+			deposit: Option<(T::AccountId, BalanceOf<T>)>,
+		) -> Result<ElectionId, DispatchError> {
+			ensure!(window.is_valid(), Error::<T>::InvalidTimeRange);
+			if let VotingWindow::Blocks { start, end } = &window {
+				Self::ensure_duration_bounded(*start, *end)?;
+			}
+			ensure!(seats >= 1, Error::<T>::InvalidSeatCount);
+			ensure!(
+				(1..=T::MaxCandidates::get()).contains(&max_candidates),
+				Error::<T>::InvalidMaxCandidates
+			);
+			ensure!(
+				seats == 1 || kind != ElectionKind::RankedChoice,
+				Error::<T>::WrongElectionKind
+			);
+			ensure!(!weighted || kind != ElectionKind::Quadratic, Error::<T>::WrongElectionKind);
+			ensure!(
+				!unlisted || (kind == ElectionKind::Plurality && reveal_end_block.is_none()),
+				Error::<T>::WrongElectionKind
+			);
+			ensure!(
+				!allow_write_ins
+					|| (kind == ElectionKind::Plurality && reveal_end_block.is_none() && !unlisted),
+				Error::<T>::WrongElectionKind
+			);
+			if let Some(reveal_end_block) = reveal_end_block {
+				ensure!(kind == ElectionKind::Plurality, Error::<T>::WrongElectionKind);
+				let VotingWindow::Blocks { end, .. } = window else {
+					return Err(Error::<T>::BlockWindowRequired.into())
+				};
+				ensure!(reveal_end_block > end, Error::<T>::InvalidTimeRange);
+			}
+			Self::ensure_utf8(&title)?;
+			let bounded_title: BoundedVec<u8, T::MaxTitleLength> =
+				title.try_into().map_err(|_| Error::<T>::TitleTooLong)?;
+			let metadata_uri = metadata_uri.map(Self::bound_metadata_uri).transpose()?;
+
+			let election_id = NextElectionId::<T>::get();
+			let next_id = election_id.checked_add(1).ok_or(Error::<T>::ElectionIdOverflow)?;
+			Self::schedule(election_id)?;
+
+			Election::<T>::insert(
+				election_id,
+				ElectionInfo {
+					title: bounded_title,
+					window,
+					kind,
+					weighted,
+					reveal_end_block,
+					seats,
+					max_candidates,
+					status: ElectionStatus::Created,
+					is_failed: false,
+					unlisted,
+					allow_write_ins,
+					reopen_count: 0,
+					results_hidden: false,
+					metadata_uri,
+					win_threshold: None,
+					dispute_window: Zero::zero(),
+					deposit,
+				},
+			);
+			NextElectionId::<T>::put(next_id);
+			Ok(election_id)
+		}
+
+		/// Add an election to [`ScheduledElections`], unless it is already there.
+		fn schedule(election_id: ElectionId) -> DispatchResult {
+			ScheduledElections::<T>::try_mutate(|scheduled| {
+				if !scheduled.contains(&election_id) {
+					scheduled
+						.try_push(election_id)
+						.map_err(|_| Error::<T>::TooManyScheduledElections)?;
+				}
+				Ok(())
+			})
+		}
+
+		/// Remove an election from [`ScheduledElections`].
+		fn unschedule(election_id: ElectionId) {
+			ScheduledElections::<T>::mutate(|scheduled| {
+				scheduled.retain(|scheduled_id| *scheduled_id != election_id)
+			});
+		}
+
+		/// Add a candidate to an election, assigning it the next free identifier of the election.
+		fn insert_candidate(
+			election_id: ElectionId,
+			name: Vec<u8>,
+			description: Vec<u8>,
+			owner: Option<T::AccountId>,
+		) -> Result<u32, DispatchError> {
+			let candidate_id = NextCandidateId::<T>::get(election_id);
+			Self::insert_candidate_with_id(election_id, candidate_id, name, description, owner)?;
+			Ok(candidate_id)
+		}
+
+		/// Add a candidate with the given identifier to an election, rejecting identifiers of
+		/// candidates that are already standing.
+		fn insert_candidate_with_id(
+			election_id: ElectionId,
+			candidate_id: u32,
+			name: Vec<u8>,
+			description: Vec<u8>,
+			owner: Option<T::AccountId>,
+		) -> DispatchResult {
+			ensure!(candidate_id != ABSTAIN, Error::<T>::ReservedCandidateId);
+			ensure!(!name.is_empty(), Error::<T>::NameEmpty);
+			Self::ensure_utf8(&name)?;
+			let bounded_name: BoundedVec<u8, T::MaxNameLength> =
+				name.try_into().map_err(|_| Error::<T>::NameTooLong)?;
+			let bounded_description: BoundedVec<u8, T::MaxDescriptionLength> =
+				description.try_into().map_err(|_| Error::<T>::DescriptionTooLong)?;
+
+			let next_id = candidate_id.checked_add(1).ok_or(Error::<T>::CandidateIdOverflow)?;
+			let max_candidates = Election::<T>::get(election_id)
+				.map_or_else(T::MaxCandidates::get, |election| election.max_candidates);
+
+			Candidates::<T>::try_mutate(election_id, |candidates| -> DispatchResult {
+				ensure!(
+					!candidates.iter().any(|c| c.id == candidate_id),
+					Error::<T>::DuplicateCandidate
+				);
+				ensure!((candidates.len() as u32) < max_candidates, Error::<T>::TooManyCandidates);
+				candidates
+					.try_push(Candidate {
+						id: candidate_id,
+						name: bounded_name,
+						description: bounded_description,
+						owner,
+					})
+					.map_err(|_| Error::<T>::TooManyCandidates)?;
+				Ok(())
+			})?;
+			CandidateExists::<T>::insert(election_id, candidate_id, ());
+			VoteCount::<T>::insert(election_id, candidate_id, 0);
+			NextCandidateId::<T>::mutate(election_id, |next| *next = (*next).max(next_id));
+			Ok(())
+		}
+
+		/// Emit [`Event::CandidateSetComplete`] if adding candidates to an election that had
+		/// `previous` candidates brought it to [`Config::ExpectedCandidates`].
+		fn note_candidates_added(election_id: ElectionId, previous: u32) {
+			let expected = T::ExpectedCandidates::get();
+			let total_candidates = Self::candidate_count(election_id);
+			if expected != 0 && previous < expected && total_candidates >= expected {
+				Self::deposit_event(Event::CandidateSetComplete { election_id, total_candidates });
+			}
+		}
+
+		/// Forget one election in which `who` stands as a self-registered candidate.
+		fn release_candidacy(who: &T::AccountId) {
+			Candidacies::<T>::mutate_exists(who, |count| {
+				*count = count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0);
+			});
+		}
+
+		/// Remove a candidate from an election together with its vote count.
+		fn take_candidate(election_id: ElectionId, candidate_id: u32) -> DispatchResult {
+			Candidates::<T>::try_mutate(election_id, |candidates| -> DispatchResult {
+				let index = candidates
+					.iter()
+					.position(|c| c.id == candidate_id)
+					.ok_or(Error::<T>::InvalidCandidate)?;
+				candidates.remove(index);
+				Ok(())
+			})?;
+			CandidateExists::<T>::remove(election_id, candidate_id);
+			VoteCount::<T>::remove(election_id, candidate_id);
+			VoteLog::<T>::remove(election_id, candidate_id);
+			Ok(())
+		}
+
+		/// Ensure that `origin` is [`Config::AdminOrigin`] or a signed account listed in
+		/// [`Admins`].
+		///
+		/// Returns the signing admin, or `None` if the call was made by the admin origin.
+		fn ensure_admin(origin: OriginFor<T>) -> Result<Option<T::AccountId>, DispatchError> {
+			match T::AdminOrigin::try_origin(origin) {
+				Ok(_) => Ok(None),
+				Err(origin) => {
+					let who = ensure_signed(origin)?;
+					ensure!(Admins::<T>::get().contains(&who), Error::<T>::NotAdmin);
+					Ok(Some(who))
+				},
+			}
+		}
+
+		/// Ensure that an election exists, has not been cancelled, is active and not paused, and
+		/// that the current block or time is inside its voting window.
+		fn ensure_voting_open(election_id: ElectionId) -> Result<ElectionInfo<T>, Error<T>> {
+			let election = Election::<T>::get(election_id).ok_or(Error::<T>::NoElectionExists)?;
+			match election.status {
+				ElectionStatus::Active => {},
+				ElectionStatus::Paused => return Err(Error::<T>::ElectionPaused),
+				ElectionStatus::Cancelled => return Err(Error::<T>::ElectionCancelled),
+				_ => return Err(Error::<T>::ElectionNotActive),
+			}
+
+			let current_block = frame_system::Pallet::<T>::block_number();
+			// Only time-based windows need the clock, saving a read for block-based ones.
+			let moment = match election.window {
+				VotingWindow::Blocks { .. } => Default::default(),
+				VotingWindow::Time { .. } => T::Time::now(),
+			};
+			ensure!(
+				election.window.has_opened(&current_block, &moment),
+				Error::<T>::VotingNotStarted
+			);
+			ensure!(!election.window.has_closed(&current_block, &moment), Error::<T>::VotingEnded);
+			Ok(election)
+		}
+
+		/// Ensure that `who` can vote for `candidate_id` in an election with [`Pallet::cast_vote`],
+		/// and return the election.
+		fn ensure_can_vote(
+			who: &T::AccountId,
+			election_id: ElectionId,
+			candidate_id: u32,
+		) -> Result<ElectionInfo<T>, Error<T>> {
+			let election = Self::ensure_voting_open(election_id)?;
+			Self::ensure_eligible(who)?;
+			ensure!(election.kind == ElectionKind::Plurality, Error::<T>::WrongElectionKind);
+			ensure!(election.reveal_end_block.is_none(), Error::<T>::WrongElectionKind);
+			ensure!(!election.unlisted, Error::<T>::WrongElectionKind);
+			ensure!(!HasVoted::<T>::contains_key(election_id, who), Error::<T>::AlreadyVoted);
+			Self::ensure_below_max_voters(election_id)?;
+			Self::ensure_choice_exists(election_id, candidate_id)?;
+			Ok(election)
+		}
+
+		/// Ensure that the candidates of an election can still be changed, because it has not been
+		/// started and its candidates have not been locked.
+		fn ensure_candidates_unlocked(
+			election_id: ElectionId,
+			election: &ElectionInfo<T>,
+		) -> Result<(), Error<T>> {
+			Self::ensure_created(election.status)?;
+			ensure!(!CandidatesLocked::<T>::get(election_id), Error::<T>::CandidatesLocked);
+			Ok(())
+		}
+
+		/// Ensure that an election is neither finalized nor cancelled.
+		fn ensure_not_closed(status: ElectionStatus) -> Result<(), Error<T>> {
+			match status {
+				ElectionStatus::Finalized => Err(Error::<T>::AlreadyFinalized),
+				ElectionStatus::Cancelled => Err(Error::<T>::ElectionCancelled),
+				_ => Ok(()),
+			}
+		}
+
+		/// Ensure that an election has been created and never started.
+		fn ensure_created(status: ElectionStatus) -> Result<(), Error<T>> {
+			Self::ensure_not_closed(status)?;
+			ensure!(status == ElectionStatus::Created, Error::<T>::ElectionIsActive);
+			Ok(())
+		}
+
+		/// Ensure that an election has been started and ended, but not finalized.
+		fn ensure_ended(status: ElectionStatus) -> Result<(), Error<T>> {
+			Self::ensure_not_closed(status)?;
+			ensure!(!status.is_active(), Error::<T>::ElectionIsActive);
+			ensure!(status == ElectionStatus::Ended, Error::<T>::VotingNotStarted);
+			Ok(())
+		}
+
+		/// The block at which the dispute window of an election is over, or `None` if it has none.
+		fn dispute_end(election: &ElectionInfo<T>) -> Option<BlockNumberFor<T>> {
+			match election.window {
+				VotingWindow::Blocks { end, .. } if !election.dispute_window.is_zero() =>
+					Some(end.saturating_add(election.dispute_window)),
+				_ => None,
+			}
+		}
+
+		/// Ensure that `bytes` are valid UTF-8, so that clients can decode them as strings.
+		fn ensure_utf8(bytes: &[u8]) -> Result<(), Error<T>> {
+			core::str::from_utf8(bytes).map_err(|_| Error::<T>::InvalidUtf8)?;
+			Ok(())
+		}
+
+		/// Bound a metadata URI to [`MAX_METADATA_URI_LENGTH`].
+		fn bound_metadata_uri(
+			uri: Vec<u8>,
+		) -> Result<BoundedVec<u8, ConstU32<MAX_METADATA_URI_LENGTH>>, Error<T>> {
+			uri.try_into().map_err(|_| Error::<T>::MetadataUriTooLong)
+		}
+
+		/// Ensure that a candidate description is at least [`Config::MinDescriptionLength`] long.
+		fn ensure_description_long_enough(description: &[u8]) -> Result<(), Error<T>> {
+			ensure!(
+				description.len() >= T::MinDescriptionLength::get() as usize,
+				Error::<T>::DescriptionTooShort
+			);
+			Ok(())
+		}
+
+		/// Ensure that fewer than [`Config::MaxVoters`] votes were cast in an election.
+		fn ensure_below_max_voters(election_id: ElectionId) -> Result<(), Error<T>> {
+			ensure!(
+				TotalVotes::<T>::get(election_id) < T::MaxVoters::get(),
+				Error::<T>::MaxVotersReached
+			);
+			Ok(())
+		}
+
+		/// Ensure that an election has candidates, and at least [`Config::MinCandidates`].
+		fn ensure_enough_candidates(election_id: ElectionId) -> Result<(), Error<T>> {
+			let count = Self::candidate_count(election_id);
+			ensure!(count > 0, Error::<T>::NoCandidates);
+			ensure!(count >= T::MinCandidates::get(), Error::<T>::TooFewCandidates);
+			Ok(())
+		}
+
+		/// Ensure that every candidate of an election has at least [`Config::MinEndorsements`]
+		/// endorsements.
+		fn ensure_endorsed(election_id: ElectionId) -> Result<(), Error<T>> {
+			let required = T::MinEndorsements::get();
+			if required.is_zero() {
+				return Ok(())
+			}
+			ensure!(
+				Candidates::<T>::get(election_id)
+					.iter()
+					.all(|candidate| EndorsementCount::<T>::get(election_id, candidate.id)
+						>= required),
+				Error::<T>::InsufficientEndorsements
+			);
+			Ok(())
+		}
+
+		/// Ensure that `who` may vote, by being registered if [`Config::RequireRegistration`] is
+		/// set and having a verified identity if [`Config::RequireVerifiedIdentity`] is set.
+		///
+		/// Registered accounts also have to wait [`Config::VotingEligibilityDelay`] blocks after
+		/// their registration.
+		fn ensure_eligible(who: &T::AccountId) -> Result<(), Error<T>> {
+			ensure!(
+				!T::RequireRegistration::get() || RegisteredVoters::<T>::contains_key(who),
+				Error::<T>::NotRegistered
+			);
+			let delay = T::VotingEligibilityDelay::get();
+			if !delay.is_zero() {
+				if let Some(registered_at) = VoterRegisteredAt::<T>::get(who) {
+					let now = frame_system::Pallet::<T>::block_number();
+					ensure!(
+						now.saturating_sub(registered_at) >= delay,
+						Error::<T>::EligibilityPending
+					);
+				}
+			}
+			ensure!(
+				!T::RequireVerifiedIdentity::get()
+					|| T::IdentityProvider::has_verified_identity(who),
+				Error::<T>::IdentityNotVerified
+			);
+			Ok(())
+		}
+
+		/// Ensure that `candidate_id` stands in an election or is [`ABSTAIN`].
+		fn ensure_choice_exists(
+			election_id: ElectionId,
+			candidate_id: u32,
+		) -> Result<(), Error<T>> {
+			if candidate_id == ABSTAIN {
+				return Ok(())
+			}
+			Self::ensure_candidate_exists(election_id, candidate_id)
+		}
+
+		/// Record the vote of `voter` for `candidate_id` and add it to the tally, together with the
+		/// weight of the accounts that delegated to `voter`.
+		///
+		/// Returns the weight of the vote, the new number of votes of the candidate and the new
+		/// total of the election.
+		fn count_vote(
+			election_id: ElectionId,
+			election: &ElectionInfo<T>,
+			voter: &T::AccountId,
+			candidate_id: u32,
+		) -> Result<(u32, u32, u32), DispatchError> {
+			Self::unwind_delegation(election_id, voter);
+			let weight = Self::vote_weight(election_id, election, voter)?
+				.saturating_add(Self::apply_delegations(election_id, election, voter));
+			let block = frame_system::Pallet::<T>::block_number();
+			HasVoted::<T>::insert(election_id, voter, VoteRecord { candidate_id, weight, block });
+			let new_count = Self::add_to_tally(election_id, candidate_id, weight);
+			BallotCount::<T>::mutate(election_id, |count| *count = count.saturating_add(1));
+			let total_votes = TotalVotes::<T>::mutate(election_id, |total| {
+				*total = total.saturating_add(weight);
+				*total
+			});
+			Ok((weight, new_count, total_votes))
+		}
+
+		/// Add `weight` to the votes of `candidate_id`, or to the abstentions, and log the vote in
+		/// [`VoteLog`] if it is enabled.
+		///
+		/// Returns the new number of votes of the candidate, or of abstentions.
+		fn add_to_tally(election_id: ElectionId, candidate_id: u32, weight: u32) -> u32 {
+			if candidate_id == ABSTAIN {
+				AbstainCount::<T>::mutate(election_id, |count| {
+					*count = count.saturating_add(weight);
+					*count
+				})
+			} else {
+				let new_count = VoteCount::<T>::mutate(election_id, candidate_id, |count| {
+					*count = count.saturating_add(weight);
+					*count
+				});
+				if T::EnableVoteLog::get() {
+					let block = frame_system::Pallet::<T>::block_number();
+					// A full log is left as it is.
+					VoteLog::<T>::mutate(election_id, candidate_id, |log| {
+						let _ = log.try_push(block);
+					});
+				}
+				new_count
+			}
+		}
+
+		/// Remove `weight` from the votes of `candidate_id`, or from the abstentions.
+		fn remove_from_tally(election_id: ElectionId, candidate_id: u32, weight: u32) {
+			if candidate_id == ABSTAIN {
+				AbstainCount::<T>::mutate(election_id, |count| {
+					*count = count.saturating_sub(weight)
+				});
+			} else {
+				VoteCount::<T>::mutate(election_id, candidate_id, |count| {
+					*count = count.saturating_sub(weight)
+				});
+			}
+		}
+
+		/// Remove `weight` from the tally of the vote of `voter` for `candidate_id`, or from every
+		/// candidate on its approval ballot.
+		fn remove_vote_from_tally(
+			election_id: ElectionId,
+			voter: &T::AccountId,
+			candidate_id: u32,
+			weight: u32,
+		) {
+			match Approvals::<T>::get(election_id, voter) {
+				Some(approvals) =>
+					for approved in approvals {
+						Self::remove_from_tally(election_id, approved, weight);
+					},
+				None => Self::remove_from_tally(election_id, candidate_id, weight),
+			}
+		}
+
+		/// Ensure that a block-based voting window from `start` to `end` spans at most
+		/// [`Config::MaxElectionDuration`] blocks.
+		fn ensure_duration_bounded(
+			start: BlockNumberFor<T>,
+			end: BlockNumberFor<T>,
+		) -> Result<(), Error<T>> {
+			ensure!(
+				end.saturating_sub(start) <= T::MaxElectionDuration::get(),
+				Error::<T>::DurationTooLong
+			);
+			Ok(())
+		}
+
+		/// Ensure that `candidate_id` stands in an election.
+		fn ensure_candidate_exists(
+			election_id: ElectionId,
+			candidate_id: u32,
+		) -> Result<(), Error<T>> {
+			ensure!(
+				CandidateExists::<T>::contains_key(election_id, candidate_id),
+				Error::<T>::InvalidCandidate
+			);
+			Ok(())
+		}
+
+		/// Deposit a [`Event::VoteCast`] for a vote of `weight` in `election`, or add the vote to
+		/// [`VotesInBlock`] if [`Config::EmitPerVoteEvents`] is not set.
+		///
+		/// While the results of the election are hidden, the candidate and the counts are left out
+		/// of the event, and it is deposited without the topic of the candidate.
+		fn deposit_vote_cast(election: &ElectionInfo<T>, weight: u32, mut event: Event<T>) {
+			if !T::EmitPerVoteEvents::get() {
+				VotesInBlock::<T>::mutate(|(count, total_votes)| {
+					*count = count.saturating_add(1);
+					*total_votes = total_votes.saturating_add(weight);
+				});
+				return
+			}
+			let topic = match &mut event {
+				Event::VoteCast { candidate_id, new_count, total_votes, .. }
+					if election.results_hidden =>
+				{
+					*candidate_id = None;
+					*new_count = None;
+					*total_votes = None;
+					None
+				},
+				Event::VoteCast { candidate_id, .. } => *candidate_id,
+				_ => None,
+			};
+			match topic {
+				Some(candidate_id) => Self::deposit_candidate_event(candidate_id, event),
+				None => Self::deposit_event(event),
+			}
+		}
+
+		/// Deposit an event with the hash of `candidate_id` as its topic.
+		fn deposit_candidate_event(candidate_id: u32, event: Event<T>) {
+			let event = <T as Config>::RuntimeEvent::from(event);
+			frame_system::Pallet::<T>::deposit_event_indexed(
+				&[T::Hashing::hash_of(&candidate_id)],
+				event.into(),
+			);
+		}
+
+		/// The name under which the finalization of an election is scheduled.
+		#[cfg(feature = "scheduler")]
+		fn finalization_task(election_id: ElectionId) -> TaskName {
+			BlakeTwo256::hash_of(&(b"voting/finalize", election_id)).into()
+		}
+
+		/// Schedule the finalization of an election [`Config::FinalizationDelay`] blocks after
+		/// the block in which its voting window, or its reveal period, closes.
+		///
+		/// Until the voting of the election ends, the number of its ranked ballots is unknown, so
+		/// the finalization is bounded by [`Config::MaxVoters`] ranked ballots. It is replaced
+		/// by one bounded by the ballots actually cast through [`Pallet::bound_finalization`].
+		#[cfg(feature = "scheduler")]
+		fn schedule_finalization(
+			election_id: ElectionId,
+			closes: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let Some(delay) = T::FinalizationDelay::get() else { return Ok(()) };
+			let at = closes.saturating_add(delay);
+			Self::schedule_finalization_at(election_id, at, T::MaxVoters::get())?;
+			Self::deposit_event(Event::FinalizationScheduled { election_id, at });
+			Ok(())
+		}
+
+		/// Replace the scheduled finalization of an election whose voting has ended with one
+		/// bounded by the [`RankedBallotCount`] of the election, so that it is not charged as
+		/// if [`Config::MaxVoters`] ranked ballots had been cast.
+		///
+		/// Nothing is replaced for elections whose finalization was not scheduled, or is due in
+		/// the current block already.
+		#[cfg(feature = "scheduler")]
+		fn bound_finalization(election_id: ElectionId) -> DispatchResult {
+			let task = Self::finalization_task(election_id);
+			let Ok(at) = T::Scheduler::next_dispatch_time(task) else { return Ok(()) };
+			if at <= frame_system::Pallet::<T>::block_number() {
+				return Ok(())
+			}
+			T::Scheduler::cancel_named(task)?;
+			Self::schedule_finalization_at(
+				election_id,
+				at,
+				RankedBallotCount::<T>::get(election_id),
+			)
+		}
+
+		/// Schedule the finalization of an election at block `at`, with `voters` as its witness.
+		///
+		/// The call is small enough to be stored inline with the scheduled task.
+		#[cfg(feature = "scheduler")]
+		fn schedule_finalization_at(
+			election_id: ElectionId,
+			at: BlockNumberFor<T>,
+			voters: u32,
+		) -> DispatchResult {
+			let call: <T as Config>::RuntimeCall =
+				Call::<T>::finalize_election { election_id, voters }.into();
+			T::Scheduler::schedule_named(
+				Self::finalization_task(election_id),
+				DispatchTime::At(at),
+				None,
+				LOWEST_PRIORITY,
+				frame_system::RawOrigin::Root.into(),
+				Bounded::Inline(BoundedInline::truncate_from(call.encode())),
+			)?;
+			Ok(())
+		}
+
+		/// Move the scheduled finalization of an election whose voting window was extended to
+		/// `new_end_block`.
+		///
+		/// Nothing is rescheduled for elections whose finalization was not scheduled, or that are
+		/// only finalized after their reveal period.
+		#[cfg(feature = "scheduler")]
+		fn reschedule_finalization(election_id: ElectionId, new_end_block: BlockNumberFor<T>) {
+			let Some(delay) = T::FinalizationDelay::get() else { return };
+			let scheduled = Election::<T>::get(election_id)
+				.is_some_and(|election| election.reveal_end_block.is_none());
+			let at = new_end_block.saturating_add(delay);
+			if scheduled
+				&& T::Scheduler::reschedule_named(
+					Self::finalization_task(election_id),
+					DispatchTime::At(at),
+				)
+				.is_ok()
+			{
+				Self::deposit_event(Event::FinalizationScheduled { election_id, at });
+			}
+		}
+
+		/// Check the invariants of the tallies of every election.
+		///
+		/// The votes of all candidates and the abstentions add up to [`TotalVotes`], no candidate
+		/// has more votes than that, and every vote recorded in [`HasVoted`] is for a candidate
+		/// standing in the election or for [`ABSTAIN`]. Approval ballots count for every approved
+		/// candidate, so their votes are not expected to add up. The ranked ballots add up to
+		/// [`RankedBallotCount`]. Elections whose votes are being
+		/// reset are skipped, as their tallies are only consistent again once the reset is done.
+		#[cfg(any(feature = "try-runtime", test))]
+		pub(crate) fn do_try_state() -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+			for (election_id, election) in Election::<T>::iter() {
+				if ResetCursor::<T>::contains_key(election_id)
+					|| VotesResetCursor::<T>::contains_key(election_id)
+				{
+					continue
+				}
+
+				let total_votes = TotalVotes::<T>::get(election_id);
+				let mut counted = AbstainCount::<T>::get(election_id);
+				for (_, votes) in VoteCount::<T>::iter_prefix(election_id) {
+					ensure!(votes <= total_votes, "A candidate has more votes than were cast");
+					counted = counted.saturating_add(votes);
+				}
+				ensure!(
+					election.kind == ElectionKind::Approval || counted == total_votes,
+					"The votes of the candidates do not add up to the total votes"
+				);
+
+				let candidates = Candidates::<T>::get(election_id);
+				for (_, vote) in HasVoted::<T>::iter_prefix(election_id) {
+					ensure!(
+						vote.candidate_id == ABSTAIN
+							|| candidates.iter().any(|c| c.id == vote.candidate_id),
+						"A vote was cast for a candidate that does not stand in the election"
+					);
+				}
+				ensure!(
+					Rankings::<T>::iter_key_prefix(election_id).count() as u32
+						== RankedBallotCount::<T>::get(election_id),
+					"The ranked ballots do not add up to their count"
+				);
+			}
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,1911 @@
+//! Storage migrations of the voting pallet.
+
+/// Migrations to storage version 1.
+pub mod v1 {
+	use crate::{Config, Pallet};
+	#[cfg(feature = "try-runtime")]
+	use alloc::vec::Vec;
+	use core::marker::PhantomData;
+	use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
+
+	/// Record storage version 1 on chains that have run the pallet before it tracked its
+	/// storage version.
+	///
+	/// Version 1 is the layout the pallet had when versioning was introduced, so there is no
+	/// data to translate. Chains already at version 1 or later are left untouched.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() >= 1 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(1, 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+			Ok(Pallet::<T>::on_chain_storage_version().encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+			let previous = StorageVersion::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the storage version recorded before the upgrade")?;
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+			ensure!(on_chain_version >= previous, "The storage version went backwards");
+			ensure!(on_chain_version >= 1, "The storage version was not updated");
+			Ok(())
+		}
+	}
+}
+
+/// Migrations to storage version 2, which added [`Candidate::owner`](crate::Candidate::owner).
+pub mod v2 {
+	use crate::{Candidate, Candidates, Config, Pallet};
+	#[cfg(feature = "try-runtime")]
+	use alloc::vec::Vec;
+	use core::marker::PhantomData;
+	use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
+
+	/// A candidate as stored before storage version 2.
+	#[derive(Encode, Decode)]
+	pub struct OldCandidate<T: Config> {
+		/// Identifier of the candidate, unique within its election.
+		pub id: u32,
+		/// Name of the candidate.
+		pub name: BoundedVec<u8, T::MaxNameLength>,
+		/// Short description or manifesto of the candidate.
+		pub description: BoundedVec<u8, T::MaxDescriptionLength>,
+	}
+
+	/// Give every stored candidate an `owner` of `None`.
+	///
+	/// Candidates registered before version 2 keep their owner in
+	/// [`CandidateOwners`](crate::CandidateOwners) only. Only runs on chains at version 1.
+	pub struct MigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 1 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated = 0u64;
+			Candidates::<T>::translate::<BoundedVec<OldCandidate<T>, T::MaxCandidates>, _>(
+				|_, old| {
+					translated = translated.saturating_add(1);
+					let candidates = old.into_iter().map(|c| Candidate {
+						id: c.id,
+						name: c.name,
+						description: c.description,
+						owner: None,
+					});
+					// The candidates are the same as before, so they still fit.
+					Some(BoundedVec::truncate_from(candidates.collect()))
+				},
+			);
+
+			StorageVersion::new(2).put::<Pallet<T>>();
+			T::DbWeight::get()
+				.reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+			Ok((Candidates::<T>::iter_keys().count() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+			let elections = u32::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the number of elections with candidates")?;
+			ensure!(
+				Candidates::<T>::iter().count() as u32 == elections,
+				"Candidates were lost or failed to decode"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 2,
+				"The storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Migrations to storage version 3, which replaced the block range of an election with a
+/// [`VotingWindow`](crate::VotingWindow).
+pub mod v3 {
+	use super::v5;
+	use crate::{BalanceOf, Config, ElectionId, ElectionKind, Pallet, VotingWindow};
+	#[cfg(feature = "try-runtime")]
+	use alloc::vec::Vec;
+	use core::marker::PhantomData;
+	use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
+	use frame_system::pallet_prelude::BlockNumberFor;
+
+	/// The elections with the layout they had from storage version 3 until version 5.
+	#[frame_support::storage_alias]
+	pub type Election<T: Config> =
+		StorageMap<Pallet<T>, Twox64Concat, ElectionId, v5::OldElectionInfo<T>>;
+
+	/// An election as stored before storage version 3.
+	#[derive(Encode, Decode)]
+	pub struct OldElectionInfo<T: Config> {
+		/// Human readable title of the election.
+		pub title: BoundedVec<u8, T::MaxTitleLength>,
+		/// First block at which votes are accepted.
+		pub start_block: BlockNumberFor<T>,
+		/// Block from which votes are no longer accepted.
+		pub end_block: BlockNumberFor<T>,
+		/// How ballots are cast and tallied.
+		pub kind: ElectionKind,
+		/// Whether votes are weighted by the free balance of the voter.
+		pub weighted: bool,
+		/// Block from which committed votes can no longer be revealed.
+		pub reveal_end_block: Option<BlockNumberFor<T>>,
+		/// The number of candidates the election fills.
+		pub seats: u32,
+		/// Whether the election has been started and not yet ended.
+		pub is_active: bool,
+		/// Whether voting in the active election has been halted.
+		pub is_paused: bool,
+		/// Whether the election has ever been started.
+		pub has_started: bool,
+		/// Whether the results of the election have been frozen.
+		pub is_finalized: bool,
+		/// Whether the election was finalized without reaching the quorum.
+		pub is_failed: bool,
+		/// The account that reserved a deposit for creating the election, and its amount.
+		pub deposit: Option<(T::AccountId, BalanceOf<T>)>,
+	}
+
+	/// Turn the block range of every stored election into a [`VotingWindow::Blocks`].
+	///
+	/// Only runs on chains at version 2.
+	pub struct MigrateToV3<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 2 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated = 0u64;
+			Election::<T>::translate::<OldElectionInfo<T>, _>(|_, old| {
+				translated = translated.saturating_add(1);
+				Some(v5::OldElectionInfo {
+					title: old.title,
+					window: VotingWindow::Blocks { start: old.start_block, end: old.end_block },
+					kind: old.kind,
+					weighted: old.weighted,
+					reveal_end_block: old.reveal_end_block,
+					seats: old.seats,
+					is_active: old.is_active,
+					is_paused: old.is_paused,
+					has_started: old.has_started,
+					is_finalized: old.is_finalized,
+					is_failed: old.is_failed,
+					deposit: old.deposit,
+				})
+			});
+
+			StorageVersion::new(3).put::<Pallet<T>>();
+			T::DbWeight::get()
+				.reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+			Ok((Election::<T>::iter_keys().count() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+			let elections = u32::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the number of elections")?;
+			ensure!(
+				Election::<T>::iter().count() as u32 == elections,
+				"Elections were lost or failed to decode"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 3,
+				"The storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Migrations to storage version 4, which started counting the
+/// [`RegisteredVoters`](crate::RegisteredVoters) in
+/// [`RegisteredVoterCount`](crate::RegisteredVoterCount).
+pub mod v4 {
+	use crate::{Config, Pallet, RegisteredVoterCount, RegisteredVoters};
+	#[cfg(feature = "try-runtime")]
+	use alloc::vec::Vec;
+	use core::marker::PhantomData;
+	use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
+
+	/// Initialize [`RegisteredVoterCount`] from the accounts registered so far.
+	///
+	/// Only runs on chains at version 3.
+	pub struct MigrateToV4<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV4<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 3 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let registered = RegisteredVoters::<T>::iter_keys().count() as u32;
+			RegisteredVoterCount::<T>::put(registered);
+
+			StorageVersion::new(4).put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(u64::from(registered).saturating_add(1), 2)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+			Ok((RegisteredVoters::<T>::iter_keys().count() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+			let registered = u32::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the number of registered voters")?;
+			ensure!(
+				RegisteredVoterCount::<T>::get() == registered,
+				"The counter does not match the registered voters"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 4,
+				"The storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Migrations to storage version 5, which added the cancellation of elections.
+pub mod v5 {
+	use super::v7;
+	use crate::{
+		BalanceOf, Config, ElectionId, ElectionKind, ElectionSummary, Pallet, PastElections,
+		VotingWindowOf,
+	};
+	#[cfg(feature = "try-runtime")]
+	use alloc::vec::Vec;
+	use core::marker::PhantomData;
+	use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
+	use frame_system::pallet_prelude::BlockNumberFor;
+
+	/// An election as stored before storage version 5.
+	#[derive(Encode, Decode)]
+	pub struct OldElectionInfo<T: Config> {
+		/// Human readable title of the election.
+		pub title: BoundedVec<u8, T::MaxTitleLength>,
+		/// The period in which votes are accepted.
+		pub window: VotingWindowOf<T>,
+		/// How ballots are cast and tallied.
+		pub kind: ElectionKind,
+		/// Whether votes are weighted by the free balance of the voter.
+		pub weighted: bool,
+		/// Block from which committed votes can no longer be revealed.
+		pub reveal_end_block: Option<BlockNumberFor<T>>,
+		/// The number of candidates the election fills.
+		pub seats: u32,
+		/// Whether the election has been started and not yet ended.
+		pub is_active: bool,
+		/// Whether voting in the active election has been halted.
+		pub is_paused: bool,
+		/// Whether the election has ever been started.
+		pub has_started: bool,
+		/// Whether the results of the election have been frozen.
+		pub is_finalized: bool,
+		/// Whether the election was finalized without reaching the quorum.
+		pub is_failed: bool,
+		/// The account that reserved a deposit for creating the election, and its amount.
+		pub deposit: Option<(T::AccountId, BalanceOf<T>)>,
+	}
+
+	/// The summary of a finalized election as stored before storage version 5.
+	#[derive(Encode, Decode)]
+	pub struct OldElectionSummary<T: Config> {
+		/// The identifier the election had while it was live.
+		pub election_id: ElectionId,
+		/// Human readable title of the election.
+		pub title: BoundedVec<u8, T::MaxTitleLength>,
+		/// The candidates that won the election.
+		pub winners: BoundedVec<u32, T::MaxCandidates>,
+		/// The total number of votes cast in the election.
+		pub total_votes: u32,
+		/// The number of votes received by each candidate.
+		pub vote_counts: BoundedVec<(u32, u32), T::MaxCandidates>,
+		/// The number of abstentions.
+		pub abstentions: u32,
+	}
+
+	/// The elections with the layout they had from storage version 5 until version 7.
+	#[frame_support::storage_alias]
+	pub type Election<T: Config> =
+		StorageMap<Pallet<T>, Twox64Concat, ElectionId, v7::OldElectionInfo<T>>;
+
+	/// Mark every stored election as not cancelled, and every archived summary as finalized.
+	///
+	/// Only runs on chains at version 4.
+	pub struct MigrateToV5<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV5<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 4 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated = 0u64;
+			Election::<T>::translate::<OldElectionInfo<T>, _>(|_, old| {
+				translated = translated.saturating_add(1);
+				Some(v7::OldElectionInfo {
+					title: old.title,
+					window: old.window,
+					kind: old.kind,
+					weighted: old.weighted,
+					reveal_end_block: old.reveal_end_block,
+					seats: old.seats,
+					is_active: old.is_active,
+					is_paused: old.is_paused,
+					has_started: old.has_started,
+					is_finalized: old.is_finalized,
+					is_failed: old.is_failed,
+					is_cancelled: false,
+					deposit: old.deposit,
+				})
+			});
+			PastElections::<T>::translate::<OldElectionSummary<T>, _>(|_, old| {
+				translated = translated.saturating_add(1);
+				Some(ElectionSummary {
+					election_id: old.election_id,
+					title: old.title,
+					winners: old.winners,
+					total_votes: old.total_votes,
+					vote_counts: old.vote_counts,
+					abstentions: old.abstentions,
+					cancellation_reason: None,
+				})
+			});
+
+			StorageVersion::new(5).put::<Pallet<T>>();
+			T::DbWeight::get()
+				.reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+			Ok((
+				Election::<T>::iter_keys().count() as u32,
+				PastElections::<T>::iter_keys().count() as u32,
+			)
+				.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+			let (elections, summaries) = <(u32, u32)>::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the number of elections and summaries")?;
+			ensure!(
+				Election::<T>::iter().count() as u32 == elections,
+				"Elections were lost or failed to decode"
+			);
+			ensure!(
+				PastElections::<T>::iter().count() as u32 == summaries,
+				"Election summaries were lost or failed to decode"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 5,
+				"The storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Migrations to storage version 6, which started recording the block of every vote.
+pub mod v6 {
+	use crate::{Config, HasVoted, Pallet, VoteRecord};
+	#[cfg(feature = "try-runtime")]
+	use alloc::vec::Vec;
+	use core::marker::PhantomData;
+	use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
+
+	/// A vote as stored before storage version 6.
+	#[derive(Encode, Decode)]
+	pub struct OldVoteRecord {
+		/// The candidate voted for; the first preference in ranked-choice elections.
+		pub candidate_id: u32,
+		/// The weight that was added to the tally for this vote.
+		pub weight: u32,
+	}
+
+	/// Record the block of the upgrade as the block of every vote cast so far, as the block
+	/// they were actually cast in is not known.
+	///
+	/// Only runs on chains at version 5.
+	pub struct MigrateToV6<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV6<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 5 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let block = frame_system::Pallet::<T>::block_number();
+			let mut translated = 0u64;
+			HasVoted::<T>::translate::<OldVoteRecord, _>(|_, _, old| {
+				translated = translated.saturating_add(1);
+				Some(VoteRecord { candidate_id: old.candidate_id, weight: old.weight, block })
+			});
+
+			StorageVersion::new(6).put::<Pallet<T>>();
+			T::DbWeight::get()
+				.reads_writes(translated.saturating_add(2), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+			Ok((HasVoted::<T>::iter_keys().count() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+			let votes =
+				u32::decode(&mut &state[..]).map_err(|_| "Failed to decode the number of votes")?;
+			ensure!(
+				HasVoted::<T>::iter().count() as u32 == votes,
+				"Votes were lost or failed to decode"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 6,
+				"The storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Migrations to storage version 7, which added anonymous elections.
+pub mod v7 {
+	use super::v8;
+	use crate::{BalanceOf, Config, ElectionId, ElectionKind, Pallet, VotingWindowOf};
+	#[cfg(feature = "try-runtime")]
+	use alloc::vec::Vec;
+	use core::marker::PhantomData;
+	use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
+	use frame_system::pallet_prelude::BlockNumberFor;
+
+	/// An election as stored before storage version 7.
+	#[derive(Encode, Decode)]
+	pub struct OldElectionInfo<T: Config> {
+		/// Human readable title of the election.
+		pub title: BoundedVec<u8, T::MaxTitleLength>,
+		/// The period in which votes are accepted.
+		pub window: VotingWindowOf<T>,
+		/// How ballots are cast and tallied.
+		pub kind: ElectionKind,
+		/// Whether votes are weighted by the free balance of the voter.
+		pub weighted: bool,
+		/// Block from which committed votes can no longer be revealed.
+		pub reveal_end_block: Option<BlockNumberFor<T>>,
+		/// The number of candidates the election fills.
+		pub seats: u32,
+		/// Whether the election has been started and not yet ended.
+		pub is_active: bool,
+		/// Whether voting in the active election has been halted.
+		pub is_paused: bool,
+		/// Whether the election has ever been started.
+		pub has_started: bool,
+		/// Whether the results of the election have been frozen.
+		pub is_finalized: bool,
+		/// Whether the election was finalized without reaching the quorum.
+		pub is_failed: bool,
+		/// Whether the election was cancelled before it was finalized.
+		pub is_cancelled: bool,
+		/// The account that reserved a deposit for creating the election, and its amount.
+		pub deposit: Option<(T::AccountId, BalanceOf<T>)>,
+	}
+
+	/// The elections with the layout they had at storage version 7.
+	#[frame_support::storage_alias]
+	pub type Election<T: Config> =
+		StorageMap<Pallet<T>, Twox64Concat, ElectionId, v8::OldElectionInfo<T>>;
+
+	/// Mark every stored election as not anonymous.
+	///
+	/// Only runs on chains at version 6.
+	pub struct MigrateToV7<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV7<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 6 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated = 0u64;
+			Election::<T>::translate::<OldElectionInfo<T>, _>(|_, old| {
+				translated = translated.saturating_add(1);
+				Some(v8::OldElectionInfo {
+					title: old.title,
+					window: old.window,
+					kind: old.kind,
+					weighted: old.weighted,
+					reveal_end_block: old.reveal_end_block,
+					seats: old.seats,
+					is_active: old.is_active,
+					is_paused: old.is_paused,
+					has_started: old.has_started,
+					is_finalized: old.is_finalized,
+					is_failed: old.is_failed,
+					is_cancelled: old.is_cancelled,
+					anonymous: false,
+					deposit: old.deposit,
+				})
+			});
+
+			StorageVersion::new(7).put::<Pallet<T>>();
+			T::DbWeight::get()
+				.reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+			Ok((Election::<T>::iter_keys().count() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+			let elections = u32::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the number of elections")?;
+			ensure!(
+				Election::<T>::iter().count() as u32 == elections,
+				"Elections were lost or failed to decode"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 7,
+				"The storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Migrations to storage version 8, which added write-in candidates.
+pub mod v8 {
+	use super::v10;
+	use crate::{BalanceOf, Config, ElectionId, ElectionKind, Pallet, VotingWindowOf};
+	#[cfg(feature = "try-runtime")]
+	use alloc::vec::Vec;
+	use core::marker::PhantomData;
+	use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
+	use frame_system::pallet_prelude::BlockNumberFor;
+
+	/// An election as stored before storage version 8.
+	#[derive(Encode, Decode)]
+	pub struct OldElectionInfo<T: Config> {
+		/// Human readable title of the election.
+		pub title: BoundedVec<u8, T::MaxTitleLength>,
+		/// The period in which votes are accepted.
+		pub window: VotingWindowOf<T>,
+		/// How ballots are cast and tallied.
+		pub kind: ElectionKind,
+		/// Whether votes are weighted by the free balance of the voter.
+		pub weighted: bool,
+		/// Block from which committed votes can no longer be revealed.
+		pub reveal_end_block: Option<BlockNumberFor<T>>,
+		/// The number of candidates the election fills.
+		pub seats: u32,
+		/// Whether the election has been started and not yet ended.
+		pub is_active: bool,
+		/// Whether voting in the active election has been halted.
+		pub is_paused: bool,
+		/// Whether the election has ever been started.
+		pub has_started: bool,
+		/// Whether the results of the election have been frozen.
+		pub is_finalized: bool,
+		/// Whether the election was finalized without reaching the quorum.
+		pub is_failed: bool,
+		/// Whether the election was cancelled before it was finalized.
+		pub is_cancelled: bool,
+		/// Whether votes are cast anonymously.
+		pub anonymous: bool,
+		/// The account that reserved a deposit for creating the election, and its amount.
+		pub deposit: Option<(T::AccountId, BalanceOf<T>)>,
+	}
+
+	/// The elections with the layout they had at storage version 8.
+	#[frame_support::storage_alias]
+	pub type Election<T: Config> =
+		StorageMap<Pallet<T>, Twox64Concat, ElectionId, v10::OldElectionInfo<T>>;
+
+	/// Mark every stored election as not accepting write-in candidates.
+	///
+	/// Only runs on chains at version 7.
+	pub struct MigrateToV8<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV8<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 7 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated = 0u64;
+			Election::<T>::translate::<OldElectionInfo<T>, _>(|_, old| {
+				translated = translated.saturating_add(1);
+				Some(v10::OldElectionInfo {
+					title: old.title,
+					window: old.window,
+					kind: old.kind,
+					weighted: old.weighted,
+					reveal_end_block: old.reveal_end_block,
+					seats: old.seats,
+					is_active: old.is_active,
+					is_paused: old.is_paused,
+					has_started: old.has_started,
+					is_finalized: old.is_finalized,
+					is_failed: old.is_failed,
+					is_cancelled: old.is_cancelled,
+					anonymous: old.anonymous,
+					allow_write_ins: false,
+					deposit: old.deposit,
+				})
+			});
+
+			StorageVersion::new(8).put::<Pallet<T>>();
+			T::DbWeight::get()
+				.reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+			Ok((Election::<T>::iter_keys().count() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+			let elections = u32::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the number of elections")?;
+			ensure!(
+				Election::<T>::iter().count() as u32 == elections,
+				"Elections were lost or failed to decode"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 8,
+				"The storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Migrations to storage version 9, which started counting the candidacies of every account.
+pub mod v9 {
+	use crate::{Candidacies, CandidateOwners, Config, Pallet};
+	#[cfg(feature = "try-runtime")]
+	use alloc::vec::Vec;
+	use core::marker::PhantomData;
+	use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
+
+	/// Initialize [`Candidacies`] from the candidates registered so far in [`CandidateOwners`].
+	///
+	/// Only runs on chains at version 8.
+	pub struct MigrateToV9<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV9<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 8 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut counted = 0u64;
+			for (_, who, _) in CandidateOwners::<T>::iter() {
+				Candidacies::<T>::mutate(&who, |count| *count = count.saturating_add(1));
+				counted = counted.saturating_add(1);
+			}
+
+			StorageVersion::new(9).put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(
+				counted.saturating_mul(2).saturating_add(1),
+				counted.saturating_add(1),
+			)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+			Ok((CandidateOwners::<T>::iter_keys().count() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+			let candidacies = u32::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the number of candidacies")?;
+			ensure!(
+				Candidacies::<T>::iter_values().sum::<u32>() == candidacies,
+				"The counters do not match the registered candidates"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 9,
+				"The storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Migrations to storage version 10, which started counting how often an election was reopened.
+pub mod v10 {
+	use super::v11;
+	use crate::{BalanceOf, Config, ElectionId, ElectionKind, Pallet, VotingWindowOf};
+	#[cfg(feature = "try-runtime")]
+	use alloc::vec::Vec;
+	use core::marker::PhantomData;
+	use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
+	use frame_system::pallet_prelude::BlockNumberFor;
+
+	/// An election as stored before storage version 10.
+	#[derive(Encode, Decode)]
+	pub struct OldElectionInfo<T: Config> {
+		/// Human readable title of the election.
+		pub title: BoundedVec<u8, T::MaxTitleLength>,
+		/// The period in which votes are accepted.
+		pub window: VotingWindowOf<T>,
+		/// How ballots are cast and tallied.
+		pub kind: ElectionKind,
+		/// Whether votes are weighted by the free balance of the voter.
+		pub weighted: bool,
+		/// Block from which committed votes can no longer be revealed.
+		pub reveal_end_block: Option<BlockNumberFor<T>>,
+		/// The number of candidates the election fills.
+		pub seats: u32,
+		/// Whether the election has been started and not yet ended.
+		pub is_active: bool,
+		/// Whether voting in the active election has been halted.
+		pub is_paused: bool,
+		/// Whether the election has ever been started.
+		pub has_started: bool,
+		/// Whether the results of the election have been frozen.
+		pub is_finalized: bool,
+		/// Whether the election was finalized without reaching the quorum.
+		pub is_failed: bool,
+		/// Whether the election was cancelled before it was finalized.
+		pub is_cancelled: bool,
+		/// Whether votes are cast anonymously.
+		pub anonymous: bool,
+		/// Whether voters may vote for write-in candidates.
+		pub allow_write_ins: bool,
+		/// The account that reserved a deposit for creating the election, and its amount.
+		pub deposit: Option<(T::AccountId, BalanceOf<T>)>,
+	}
+
+	/// The elections with the layout they had at storage version 10.
+	#[frame_support::storage_alias]
+	pub type Election<T: Config> =
+		StorageMap<Pallet<T>, Twox64Concat, ElectionId, v11::OldElectionInfo<T>>;
+
+	/// Mark every stored election as never reopened.
+	///
+	/// Only runs on chains at version 9.
+	pub struct MigrateToV10<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV10<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 9 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated = 0u64;
+			Election::<T>::translate::<OldElectionInfo<T>, _>(|_, old| {
+				translated = translated.saturating_add(1);
+				Some(v11::OldElectionInfo {
+					title: old.title,
+					window: old.window,
+					kind: old.kind,
+					weighted: old.weighted,
+					reveal_end_block: old.reveal_end_block,
+					seats: old.seats,
+					is_active: old.is_active,
+					is_paused: old.is_paused,
+					has_started: old.has_started,
+					is_finalized: old.is_finalized,
+					is_failed: old.is_failed,
+					is_cancelled: old.is_cancelled,
+					anonymous: old.anonymous,
+					allow_write_ins: old.allow_write_ins,
+					reopen_count: 0,
+					deposit: old.deposit,
+				})
+			});
+
+			StorageVersion::new(10).put::<Pallet<T>>();
+			T::DbWeight::get()
+				.reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+			Ok((Election::<T>::iter_keys().count() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+			let elections = u32::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the number of elections")?;
+			ensure!(
+				Election::<T>::iter().count() as u32 == elections,
+				"Elections were lost or failed to decode"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 10,
+				"The storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Migrations to storage version 11, which allowed hiding the results of elections until they
+/// are finalized.
+pub mod v11 {
+	use super::v13;
+	use crate::{BalanceOf, Config, ElectionId, ElectionKind, Pallet, VotingWindowOf};
+	#[cfg(feature = "try-runtime")]
+	use alloc::vec::Vec;
+	use core::marker::PhantomData;
+	use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
+	use frame_system::pallet_prelude::BlockNumberFor;
+
+	/// An election as stored before storage version 11.
+	#[derive(Encode, Decode)]
+	pub struct OldElectionInfo<T: Config> {
+		/// Human readable title of the election.
+		pub title: BoundedVec<u8, T::MaxTitleLength>,
+		/// The period in which votes are accepted.
+		pub window: VotingWindowOf<T>,
+		/// How ballots are cast and tallied.
+		pub kind: ElectionKind,
+		/// Whether votes are weighted by the free balance of the voter.
+		pub weighted: bool,
+		/// Block from which committed votes can no longer be revealed.
+		pub reveal_end_block: Option<BlockNumberFor<T>>,
+		/// The number of candidates the election fills.
+		pub seats: u32,
+		/// Whether the election has been started and not yet ended.
+		pub is_active: bool,
+		/// Whether voting in the active election has been halted.
+		pub is_paused: bool,
+		/// Whether the election has ever been started.
+		pub has_started: bool,
+		/// Whether the results of the election have been frozen.
+		pub is_finalized: bool,
+		/// Whether the election was finalized without reaching the quorum.
+		pub is_failed: bool,
+		/// Whether the election was cancelled before it was finalized.
+		pub is_cancelled: bool,
+		/// Whether votes are cast anonymously.
+		pub anonymous: bool,
+		/// Whether voters may vote for write-in candidates.
+		pub allow_write_ins: bool,
+		/// How many times the election was reopened for a recount.
+		pub reopen_count: u32,
+		/// The account that reserved a deposit for creating the election, and its amount.
+		pub deposit: Option<(T::AccountId, BalanceOf<T>)>,
+	}
+
+	/// The elections with the layout they had at storage version 11.
+	#[frame_support::storage_alias]
+	pub type Election<T: Config> =
+		StorageMap<Pallet<T>, Twox64Concat, ElectionId, v13::OldElectionInfo<T>>;
+
+	/// Mark the results of every stored election as visible.
+	///
+	/// Only runs on chains at version 10.
+	pub struct MigrateToV11<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV11<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 10 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated = 0u64;
+			Election::<T>::translate::<OldElectionInfo<T>, _>(|_, old| {
+				translated = translated.saturating_add(1);
+				Some(v13::OldElectionInfo {
+					title: old.title,
+					window: old.window,
+					kind: old.kind,
+					weighted: old.weighted,
+					reveal_end_block: old.reveal_end_block,
+					seats: old.seats,
+					is_active: old.is_active,
+					is_paused: old.is_paused,
+					has_started: old.has_started,
+					is_finalized: old.is_finalized,
+					is_failed: old.is_failed,
+					is_cancelled: old.is_cancelled,
+					anonymous: old.anonymous,
+					allow_write_ins: old.allow_write_ins,
+					reopen_count: old.reopen_count,
+					results_hidden: false,
+					deposit: old.deposit,
+				})
+			});
+
+			StorageVersion::new(11).put::<Pallet<T>>();
+			T::DbWeight::get()
+				.reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+			Ok((Election::<T>::iter_keys().count() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+			let elections = u32::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the number of elections")?;
+			ensure!(
+				Election::<T>::iter().count() as u32 == elections,
+				"Elections were lost or failed to decode"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 11,
+				"The storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Migrations to storage version 12, which indexed the candidates of every election.
+pub mod v12 {
+	use crate::{CandidateExists, Candidates, Config, Pallet};
+	#[cfg(feature = "try-runtime")]
+	use alloc::vec::Vec;
+	use core::marker::PhantomData;
+	use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
+
+	/// Initialize [`CandidateExists`] from the candidates stored in [`Candidates`].
+	///
+	/// Only runs on chains at version 11.
+	pub struct MigrateToV12<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV12<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 11 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut elections = 0u64;
+			let mut indexed = 0u64;
+			for (election_id, candidates) in Candidates::<T>::iter() {
+				elections = elections.saturating_add(1);
+				for candidate in candidates {
+					CandidateExists::<T>::insert(election_id, candidate.id, ());
+					indexed = indexed.saturating_add(1);
+				}
+			}
+
+			StorageVersion::new(12).put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(elections.saturating_add(1), indexed.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+			let candidates: u32 = Candidates::<T>::iter_values().map(|c| c.len() as u32).sum();
+			Ok(candidates.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+			let candidates = u32::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the number of candidates")?;
+			ensure!(
+				CandidateExists::<T>::iter_keys().count() as u32 == candidates,
+				"The index does not match the stored candidates"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 12,
+				"The storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Migrations to storage version 13, which added a metadata URI to elections.
+pub mod v13 {
+	use super::v14;
+	use crate::{BalanceOf, Config, ElectionId, ElectionKind, Pallet, VotingWindowOf};
+	#[cfg(feature = "try-runtime")]
+	use alloc::vec::Vec;
+	use core::marker::PhantomData;
+	use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
+	use frame_system::pallet_prelude::BlockNumberFor;
+
+	/// An election as stored before storage version 13.
+	#[derive(Encode, Decode)]
+	pub struct OldElectionInfo<T: Config> {
+		/// Human readable title of the election.
+		pub title: BoundedVec<u8, T::MaxTitleLength>,
+		/// The period in which votes are accepted.
+		pub window: VotingWindowOf<T>,
+		/// How ballots are cast and tallied.
+		pub kind: ElectionKind,
+		/// Whether votes are weighted by the free balance of the voter.
+		pub weighted: bool,
+		/// Block from which committed votes can no longer be revealed.
+		pub reveal_end_block: Option<BlockNumberFor<T>>,
+		/// The number of candidates the election fills.
+		pub seats: u32,
+		/// Whether the election has been started and not yet ended.
+		pub is_active: bool,
+		/// Whether voting in the active election has been halted.
+		pub is_paused: bool,
+		/// Whether the election has ever been started.
+		pub has_started: bool,
+		/// Whether the results of the election have been frozen.
+		pub is_finalized: bool,
+		/// Whether the election was finalized without reaching the quorum.
+		pub is_failed: bool,
+		/// Whether the election was cancelled before it was finalized.
+		pub is_cancelled: bool,
+		/// Whether votes are cast anonymously.
+		pub anonymous: bool,
+		/// Whether voters may vote for write-in candidates.
+		pub allow_write_ins: bool,
+		/// How many times the election was reopened for a recount.
+		pub reopen_count: u32,
+		/// Whether the results are withheld until the election is finalized.
+		pub results_hidden: bool,
+		/// The account that reserved a deposit for creating the election, and its amount.
+		pub deposit: Option<(T::AccountId, BalanceOf<T>)>,
+	}
+
+	/// The elections with the layout they had at storage version 13.
+	#[frame_support::storage_alias]
+	pub type Election<T: Config> =
+		StorageMap<Pallet<T>, Twox64Concat, ElectionId, v14::OldElectionInfo<T>>;
+
+	/// Store every election without a metadata URI.
+	///
+	/// Only runs on chains at version 12.
+	pub struct MigrateToV13<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV13<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 12 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated = 0u64;
+			Election::<T>::translate::<OldElectionInfo<T>, _>(|_, old| {
+				translated = translated.saturating_add(1);
+				Some(v14::OldElectionInfo {
+					title: old.title,
+					window: old.window,
+					kind: old.kind,
+					weighted: old.weighted,
+					reveal_end_block: old.reveal_end_block,
+					seats: old.seats,
+					is_active: old.is_active,
+					is_paused: old.is_paused,
+					has_started: old.has_started,
+					is_finalized: old.is_finalized,
+					is_failed: old.is_failed,
+					is_cancelled: old.is_cancelled,
+					anonymous: old.anonymous,
+					allow_write_ins: old.allow_write_ins,
+					reopen_count: old.reopen_count,
+					results_hidden: old.results_hidden,
+					metadata_uri: None,
+					deposit: old.deposit,
+				})
+			});
+
+			StorageVersion::new(13).put::<Pallet<T>>();
+			T::DbWeight::get()
+				.reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+			Ok((Election::<T>::iter_keys().count() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+			let elections = u32::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the number of elections")?;
+			ensure!(
+				Election::<T>::iter().count() as u32 == elections,
+				"Elections were lost or failed to decode"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 13,
+				"The storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Migrations to storage version 14, which added a win threshold to elections.
+pub mod v14 {
+	use super::v15;
+	use crate::{
+		BalanceOf, Config, ElectionId, ElectionKind, Pallet, VotingWindowOf,
+		MAX_METADATA_URI_LENGTH,
+	};
+	#[cfg(feature = "try-runtime")]
+	use alloc::vec::Vec;
+	use core::marker::PhantomData;
+	use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
+	use frame_system::pallet_prelude::BlockNumberFor;
+
+	/// An election as stored before storage version 14.
+	#[derive(Encode, Decode)]
+	pub struct OldElectionInfo<T: Config> {
+		/// Human readable title of the election.
+		pub title: BoundedVec<u8, T::MaxTitleLength>,
+		/// The period in which votes are accepted.
+		pub window: VotingWindowOf<T>,
+		/// How ballots are cast and tallied.
+		pub kind: ElectionKind,
+		/// Whether votes are weighted by the free balance of the voter.
+		pub weighted: bool,
+		/// Block from which committed votes can no longer be revealed.
+		pub reveal_end_block: Option<BlockNumberFor<T>>,
+		/// The number of candidates the election fills.
+		pub seats: u32,
+		/// Whether the election has been started and not yet ended.
+		pub is_active: bool,
+		/// Whether voting in the active election has been halted.
+		pub is_paused: bool,
+		/// Whether the election has ever been started.
+		pub has_started: bool,
+		/// Whether the results of the election have been frozen.
+		pub is_finalized: bool,
+		/// Whether the election was finalized without reaching the quorum.
+		pub is_failed: bool,
+		/// Whether the election was cancelled before it was finalized.
+		pub is_cancelled: bool,
+		/// Whether votes are cast anonymously.
+		pub anonymous: bool,
+		/// Whether voters may vote for write-in candidates.
+		pub allow_write_ins: bool,
+		/// How many times the election was reopened for a recount.
+		pub reopen_count: u32,
+		/// Whether the results are withheld until the election is finalized.
+		pub results_hidden: bool,
+		/// A pointer to off-chain documentation of the election.
+		pub metadata_uri: Option<BoundedVec<u8, ConstU32<MAX_METADATA_URI_LENGTH>>>,
+		/// The account that reserved a deposit for creating the election, and its amount.
+		pub deposit: Option<(T::AccountId, BalanceOf<T>)>,
+	}
+
+	/// The elections with the layout they had at storage version 14.
+	#[frame_support::storage_alias]
+	pub type Election<T: Config> =
+		StorageMap<Pallet<T>, Twox64Concat, ElectionId, v15::OldElectionInfo<T>>;
+
+	/// Store every election without a win threshold.
+	///
+	/// Only runs on chains at version 13.
+	pub struct MigrateToV14<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV14<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 13 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated = 0u64;
+			Election::<T>::translate::<OldElectionInfo<T>, _>(|_, old| {
+				translated = translated.saturating_add(1);
+				Some(v15::OldElectionInfo {
+					title: old.title,
+					window: old.window,
+					kind: old.kind,
+					weighted: old.weighted,
+					reveal_end_block: old.reveal_end_block,
+					seats: old.seats,
+					is_active: old.is_active,
+					is_paused: old.is_paused,
+					has_started: old.has_started,
+					is_finalized: old.is_finalized,
+					is_failed: old.is_failed,
+					is_cancelled: old.is_cancelled,
+					anonymous: old.anonymous,
+					allow_write_ins: old.allow_write_ins,
+					reopen_count: old.reopen_count,
+					results_hidden: old.results_hidden,
+					metadata_uri: old.metadata_uri,
+					win_threshold: None,
+					deposit: old.deposit,
+				})
+			});
+
+			StorageVersion::new(14).put::<Pallet<T>>();
+			T::DbWeight::get()
+				.reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+			Ok((Election::<T>::iter_keys().count() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+			let elections = u32::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the number of elections")?;
+			ensure!(
+				Election::<T>::iter().count() as u32 == elections,
+				"Elections were lost or failed to decode"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 14,
+				"The storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Migrations to storage version 15, which replaced the lifecycle flags of elections with an
+/// [`ElectionStatus`](crate::ElectionStatus).
+pub mod v15 {
+	use super::v16;
+	use crate::{
+		BalanceOf, Config, ElectionId, ElectionKind, ElectionStatus, Pallet, VotingWindowOf,
+		MAX_METADATA_URI_LENGTH,
+	};
+	#[cfg(feature = "try-runtime")]
+	use alloc::vec::Vec;
+	use core::marker::PhantomData;
+	use frame_support::{pallet_prelude::*, sp_runtime::Permill, traits::OnRuntimeUpgrade};
+	use frame_system::pallet_prelude::BlockNumberFor;
+
+	/// An election as stored before storage version 15.
+	#[derive(Encode, Decode)]
+	pub struct OldElectionInfo<T: Config> {
+		/// Human readable title of the election.
+		pub title: BoundedVec<u8, T::MaxTitleLength>,
+		/// The period in which votes are accepted.
+		pub window: VotingWindowOf<T>,
+		/// How ballots are cast and tallied.
+		pub kind: ElectionKind,
+		/// Whether votes are weighted by the free balance of the voter.
+		pub weighted: bool,
+		/// Block from which committed votes can no longer be revealed.
+		pub reveal_end_block: Option<BlockNumberFor<T>>,
+		/// The number of candidates the election fills.
+		pub seats: u32,
+		/// Whether the election has been started and not yet ended.
+		pub is_active: bool,
+		/// Whether voting in the active election has been halted.
+		pub is_paused: bool,
+		/// Whether the election has ever been started.
+		pub has_started: bool,
+		/// Whether the results of the election have been frozen.
+		pub is_finalized: bool,
+		/// Whether the election was finalized without reaching the quorum.
+		pub is_failed: bool,
+		/// Whether the election was cancelled before it was finalized.
+		pub is_cancelled: bool,
+		/// Whether votes are cast anonymously.
+		pub anonymous: bool,
+		/// Whether voters may vote for write-in candidates.
+		pub allow_write_ins: bool,
+		/// How many times the election was reopened for a recount.
+		pub reopen_count: u32,
+		/// Whether the results are withheld until the election is finalized.
+		pub results_hidden: bool,
+		/// A pointer to off-chain documentation of the election.
+		pub metadata_uri: Option<BoundedVec<u8, ConstU32<MAX_METADATA_URI_LENGTH>>>,
+		/// The share of the votes the winner of a single-seat election has to reach.
+		pub win_threshold: Option<Permill>,
+		/// The account that reserved a deposit for creating the election, and its amount.
+		pub deposit: Option<(T::AccountId, BalanceOf<T>)>,
+	}
+
+	/// The elections with the layout they had at storage version 15.
+	#[frame_support::storage_alias]
+	pub type Election<T: Config> =
+		StorageMap<Pallet<T>, Twox64Concat, ElectionId, v16::OldElectionInfo<T>>;
+
+	/// Map the lifecycle flags of every election to its [`ElectionStatus`].
+	///
+	/// Cancellation takes precedence over finalization, and elections that were started but are
+	/// no longer active are ended.
+	///
+	/// Only runs on chains at version 14.
+	pub struct MigrateToV15<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV15<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 14 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated = 0u64;
+			Election::<T>::translate::<OldElectionInfo<T>, _>(|_, old| {
+				translated = translated.saturating_add(1);
+				let status = if old.is_cancelled {
+					ElectionStatus::Cancelled
+				} else if old.is_finalized {
+					ElectionStatus::Finalized
+				} else if old.is_active && old.is_paused {
+					ElectionStatus::Paused
+				} else if old.is_active {
+					ElectionStatus::Active
+				} else if old.has_started {
+					ElectionStatus::Ended
+				} else {
+					ElectionStatus::Created
+				};
+				Some(v16::OldElectionInfo {
+					title: old.title,
+					window: old.window,
+					kind: old.kind,
+					weighted: old.weighted,
+					reveal_end_block: old.reveal_end_block,
+					seats: old.seats,
+					status,
+					is_failed: old.is_failed,
+					anonymous: old.anonymous,
+					allow_write_ins: old.allow_write_ins,
+					reopen_count: old.reopen_count,
+					results_hidden: old.results_hidden,
+					metadata_uri: old.metadata_uri,
+					win_threshold: old.win_threshold,
+					deposit: old.deposit,
+				})
+			});
+
+			StorageVersion::new(15).put::<Pallet<T>>();
+			T::DbWeight::get()
+				.reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+			Ok((Election::<T>::iter_keys().count() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+			let elections = u32::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the number of elections")?;
+			ensure!(
+				Election::<T>::iter().count() as u32 == elections,
+				"Elections were lost or failed to decode"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 15,
+				"The storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Migrations to storage version 16, which added a per-election limit on the number of
+/// candidates.
+pub mod v16 {
+	use super::v17;
+	use crate::{
+		BalanceOf, Config, ElectionId, ElectionKind, ElectionStatus, Pallet, VotingWindowOf,
+		MAX_METADATA_URI_LENGTH,
+	};
+	#[cfg(feature = "try-runtime")]
+	use alloc::vec::Vec;
+	use core::marker::PhantomData;
+	use frame_support::{pallet_prelude::*, sp_runtime::Permill, traits::OnRuntimeUpgrade};
+	use frame_system::pallet_prelude::BlockNumberFor;
+
+	/// An election as stored before storage version 16.
+	#[derive(Encode, Decode)]
+	pub struct OldElectionInfo<T: Config> {
+		/// Human readable title of the election.
+		pub title: BoundedVec<u8, T::MaxTitleLength>,
+		/// The period in which votes are accepted.
+		pub window: VotingWindowOf<T>,
+		/// How ballots are cast and tallied.
+		pub kind: ElectionKind,
+		/// Whether votes are weighted by the free balance of the voter.
+		pub weighted: bool,
+		/// Block from which committed votes can no longer be revealed.
+		pub reveal_end_block: Option<BlockNumberFor<T>>,
+		/// The number of candidates the election fills.
+		pub seats: u32,
+		/// The stage of its lifecycle the election is in.
+		pub status: ElectionStatus,
+		/// Whether the election was finalized without reaching the quorum.
+		pub is_failed: bool,
+		/// Whether votes are cast anonymously.
+		pub anonymous: bool,
+		/// Whether voters may vote for write-in candidates.
+		pub allow_write_ins: bool,
+		/// How many times the election was reopened for a recount.
+		pub reopen_count: u32,
+		/// Whether the results are withheld until the election is finalized.
+		pub results_hidden: bool,
+		/// A pointer to off-chain documentation of the election.
+		pub metadata_uri: Option<BoundedVec<u8, ConstU32<MAX_METADATA_URI_LENGTH>>>,
+		/// The share of the votes the winner of a single-seat election has to reach.
+		pub win_threshold: Option<Permill>,
+		/// The account that reserved a deposit for creating the election, and its amount.
+		pub deposit: Option<(T::AccountId, BalanceOf<T>)>,
+	}
+
+	/// The elections with the layout they had at storage version 16.
+	#[frame_support::storage_alias]
+	pub type Election<T: Config> =
+		StorageMap<Pallet<T>, Twox64Concat, ElectionId, v17::OldElectionInfo<T>>;
+
+	/// Let as many candidates stand in every election as [`Config::MaxCandidates`] allows.
+	///
+	/// Only runs on chains at version 15.
+	pub struct MigrateToV16<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV16<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 15 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated = 0u64;
+			Election::<T>::translate::<OldElectionInfo<T>, _>(|_, old| {
+				translated = translated.saturating_add(1);
+				Some(v17::OldElectionInfo {
+					title: old.title,
+					window: old.window,
+					kind: old.kind,
+					weighted: old.weighted,
+					reveal_end_block: old.reveal_end_block,
+					seats: old.seats,
+					max_candidates: T::MaxCandidates::get(),
+					status: old.status,
+					is_failed: old.is_failed,
+					anonymous: old.anonymous,
+					allow_write_ins: old.allow_write_ins,
+					reopen_count: old.reopen_count,
+					results_hidden: old.results_hidden,
+					metadata_uri: old.metadata_uri,
+					win_threshold: old.win_threshold,
+					deposit: old.deposit,
+				})
+			});
+
+			StorageVersion::new(16).put::<Pallet<T>>();
+			T::DbWeight::get()
+				.reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+			Ok((Election::<T>::iter_keys().count() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+			let elections = u32::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the number of elections")?;
+			ensure!(
+				Election::<T>::iter().count() as u32 == elections,
+				"Elections were lost or failed to decode"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 16,
+				"The storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Migrations to storage version 17, which added a dispute window to elections.
+pub mod v17 {
+	use crate::{
+		BalanceOf, Config, Election, ElectionInfo, ElectionKind, ElectionStatus, Pallet,
+		VotingWindowOf, MAX_METADATA_URI_LENGTH,
+	};
+	#[cfg(feature = "try-runtime")]
+	use alloc::vec::Vec;
+	use core::marker::PhantomData;
+	use frame_support::{
+		pallet_prelude::*,
+		sp_runtime::{traits::Zero, Permill},
+		traits::OnRuntimeUpgrade,
+	};
+	use frame_system::pallet_prelude::BlockNumberFor;
+
+	/// An election as stored before storage version 17.
+	#[derive(Encode, Decode)]
+	pub struct OldElectionInfo<T: Config> {
+		/// Human readable title of the election.
+		pub title: BoundedVec<u8, T::MaxTitleLength>,
+		/// The period in which votes are accepted.
+		pub window: VotingWindowOf<T>,
+		/// How ballots are cast and tallied.
+		pub kind: ElectionKind,
+		/// Whether votes are weighted by the free balance of the voter.
+		pub weighted: bool,
+		/// Block from which committed votes can no longer be revealed.
+		pub reveal_end_block: Option<BlockNumberFor<T>>,
+		/// The number of candidates the election fills.
+		pub seats: u32,
+		/// The number of candidates that can stand in the election.
+		pub max_candidates: u32,
+		/// The stage of its lifecycle the election is in.
+		pub status: ElectionStatus,
+		/// Whether the election was finalized without reaching the quorum.
+		pub is_failed: bool,
+		/// Whether votes are cast anonymously.
+		pub anonymous: bool,
+		/// Whether voters may vote for write-in candidates.
+		pub allow_write_ins: bool,
+		/// How many times the election was reopened for a recount.
+		pub reopen_count: u32,
+		/// Whether the results are withheld until the election is finalized.
+		pub results_hidden: bool,
+		/// A pointer to off-chain documentation of the election.
+		pub metadata_uri: Option<BoundedVec<u8, ConstU32<MAX_METADATA_URI_LENGTH>>>,
+		/// The share of the votes the winner of a single-seat election has to reach.
+		pub win_threshold: Option<Permill>,
+		/// The account that reserved a deposit for creating the election, and its amount.
+		pub deposit: Option<(T::AccountId, BalanceOf<T>)>,
+	}
+
+	/// Give every election an empty dispute window, so that it can be finalized as before.
+	///
+	/// Only runs on chains at version 16.
+	pub struct MigrateToV17<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV17<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 16 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated = 0u64;
+			Election::<T>::translate::<OldElectionInfo<T>, _>(|_, old| {
+				translated = translated.saturating_add(1);
+				Some(ElectionInfo {
+					title: old.title,
+					window: old.window,
+					kind: old.kind,
+					weighted: old.weighted,
+					reveal_end_block: old.reveal_end_block,
+					seats: old.seats,
+					max_candidates: old.max_candidates,
+					status: old.status,
+					is_failed: old.is_failed,
+					unlisted: old.anonymous,
+					allow_write_ins: old.allow_write_ins,
+					reopen_count: old.reopen_count,
+					results_hidden: old.results_hidden,
+					metadata_uri: old.metadata_uri,
+					win_threshold: old.win_threshold,
+					dispute_window: Zero::zero(),
+					deposit: old.deposit,
+				})
+			});
+
+			StorageVersion::new(17).put::<Pallet<T>>();
+			T::DbWeight::get()
+				.reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+			Ok((Election::<T>::iter_keys().count() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+			let elections = u32::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the number of elections")?;
+			ensure!(
+				Election::<T>::iter().count() as u32 == elections,
+				"Elections were lost or failed to decode"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 17,
+				"The storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Migrations to storage version 18, which added an index of the elections that
+/// [`Hooks::on_initialize`](frame_support::traits::Hooks::on_initialize) starts and ends.
+pub mod v18 {
+	use crate::{Config, Election, ElectionStatus, Pallet, ScheduledElections};
+	#[cfg(feature = "try-runtime")]
+	use alloc::vec::Vec;
+	use core::marker::PhantomData;
+	use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
+
+	/// Initialize [`ScheduledElections`] with the elections that have not been started yet or
+	/// are active.
+	///
+	/// Elections beyond [`Config::MaxScheduledElections`] are left out, and have to be started
+	/// and ended by an admin. Only runs on chains at version 17.
+	pub struct MigrateToV18<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV18<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 17 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut elections = 0u64;
+			let mut scheduled = BoundedVec::<_, T::MaxScheduledElections>::new();
+			for (election_id, election) in Election::<T>::iter() {
+				elections = elections.saturating_add(1);
+				if election.status == ElectionStatus::Created || election.status.is_active() {
+					let _ = scheduled.try_push(election_id);
+				}
+			}
+			ScheduledElections::<T>::put(scheduled);
+
+			StorageVersion::new(18).put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(elections.saturating_add(1), 2)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+			Ok(Vec::new())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+			for election_id in ScheduledElections::<T>::get() {
+				let election =
+					Election::<T>::get(election_id).ok_or("A scheduled election does not exist")?;
+				ensure!(
+					election.status == ElectionStatus::Created || election.status.is_active(),
+					"A closed election was scheduled"
+				);
+			}
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 18,
+				"The storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Migrations to storage version 19, which kept the nullifiers of anonymous votes per
+/// election.
+pub mod v19 {
+	use crate::{Config, Pallet};
+	#[cfg(feature = "try-runtime")]
+	use alloc::vec::Vec;
+	use core::marker::PhantomData;
+	use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
+
+	/// The nullifiers as stored before storage version 19, which were chosen by the voter.
+	#[frame_support::storage_alias]
+	pub type Nullifiers<T: Config> =
+		StorageMap<Pallet<T>, Identity, <T as frame_system::Config>::Hash, ()>;
+
+	/// Remove the nullifiers chosen by voters, which do not belong to any election.
+	///
+	/// Votes cast with them stay counted, but the voters can vote once more in the elections
+	/// that are still open. Only runs on chains at version 18.
+	pub struct MigrateToV19<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV19<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 18 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let removed = u64::from(Nullifiers::<T>::clear(u32::MAX, None).unique);
+
+			StorageVersion::new(19).put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(removed.saturating_add(1), removed.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+			Ok(Vec::new())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+			ensure!(
+				crate::Nullifiers::<T>::iter_keys().next().is_none(),
+				"Nullifiers were left behind"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 19,
+				"The storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Migrations to storage version 20, which kept the receipts of votes per election.
+pub mod v20 {
+	use crate::{Config, Pallet};
+	#[cfg(feature = "try-runtime")]
+	use alloc::vec::Vec;
+	use core::marker::PhantomData;
+	use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
+
+	/// The receipts as stored before storage version 20, which only kept the latest vote of
+	/// every account.
+	#[frame_support::storage_alias]
+	pub type Receipts<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		<T as frame_system::Config>::Hash,
+	>;
+
+	/// Remove the receipts stored without their election.
+	///
+	/// The receipts did not cover the election either, so they cannot be moved. Voters keep the
+	/// receipts reported in their [`Event::VoteCast`](crate::Event::VoteCast). Only runs on
+	/// chains at version 19.
+	pub struct MigrateToV20<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV20<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 19 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let removed = u64::from(Receipts::<T>::clear(u32::MAX, None).unique);
+
+			StorageVersion::new(20).put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(removed.saturating_add(1), removed.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+			Ok(Vec::new())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+			ensure!(
+				crate::Receipts::<T>::iter_keys().next().is_none(),
+				"Receipts were left behind"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 20,
+				"The storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Migrations to storage version 21, which counted the ranked ballots of every election.
+pub mod v21 {
+	use crate::{Config, Pallet, RankedBallotCount, Rankings};
+	#[cfg(feature = "try-runtime")]
+	use alloc::vec::Vec;
+	use core::marker::PhantomData;
+	use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
+
+	/// Initialize [`RankedBallotCount`] with the number of ranked ballots of every election.
+	///
+	/// Only runs on chains at version 20.
+	pub struct MigrateToV21<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV21<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 20 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut ballots = 0u64;
+			for (election_id, _) in Rankings::<T>::iter_keys() {
+				ballots = ballots.saturating_add(1);
+				RankedBallotCount::<T>::mutate(election_id, |count| {
+					*count = count.saturating_add(1)
+				});
+			}
+
+			StorageVersion::new(21).put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(
+				ballots.saturating_mul(2).saturating_add(1),
+				ballots.saturating_add(1),
+			)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+			Ok(Vec::new())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+			let ballots = Rankings::<T>::iter_keys().count() as u32;
+			let counted =
+				RankedBallotCount::<T>::iter_values().fold(0u32, |a, b| a.saturating_add(b));
+			ensure!(ballots == counted, "Ranked ballots were left uncounted");
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 21,
+				"The storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Migrations to storage version 22, which counted the ballots of every election.
+pub mod v22 {
+	use crate::{BallotCount, Config, HasVoted, Nullifiers, Pallet};
+	#[cfg(feature = "try-runtime")]
+	use alloc::vec::Vec;
+	use core::marker::PhantomData;
+	use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade};
+
+	/// Initialize [`BallotCount`] with the number of ballots of every election, which are the
+	/// votes in [`HasVoted`] and the unlisted votes in [`Nullifiers`].
+	///
+	/// Only runs on chains at version 21.
+	pub struct MigrateToV22<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV22<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 21 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut ballots = 0u64;
+			let election_ids = HasVoted::<T>::iter_keys()
+				.map(|(election_id, _)| election_id)
+				.chain(Nullifiers::<T>::iter_keys().map(|(election_id, _)| election_id));
+			for election_id in election_ids {
+				ballots = ballots.saturating_add(1);
+				BallotCount::<T>::mutate(election_id, |count| *count = count.saturating_add(1));
+			}
+
+			StorageVersion::new(22).put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(
+				ballots.saturating_mul(2).saturating_add(1),
+				ballots.saturating_add(1),
+			)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+			Ok(Vec::new())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+			let ballots = (HasVoted::<T>::iter_keys().count() as u32)
+				.saturating_add(Nullifiers::<T>::iter_keys().count() as u32);
+			let counted = BallotCount::<T>::iter_values().fold(0u32, |a, b| a.saturating_add(b));
+			ensure!(ballots == counted, "Ballots were left uncounted");
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 22,
+				"The storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,248 @@
+use crate as pallet_voting;
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{ConstU32, ConstU64, Randomness, Time},
+};
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_runtime::{traits::Convert, BuildStorage, SaturatedConversion};
+#[cfg(feature = "scheduler")]
+use {
+	codec::Decode,
+	frame_support::traits::{
+		schedule::{
+			v3::{Named, TaskName},
+			DispatchTime, Period, Priority,
+		},
+		Bounded,
+	},
+	sp_runtime::{traits::BlakeTwo256, DispatchError},
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+#[frame_support::runtime]
+mod runtime {
+	// The main runtime
+	#[runtime::runtime]
+	// Runtime Types to be generated
+	#[runtime::derive(
+		RuntimeCall,
+		RuntimeEvent,
+		RuntimeError,
+		RuntimeOrigin,
+		RuntimeFreezeReason,
+		RuntimeHoldReason,
+		RuntimeSlashReason,
+		RuntimeLockId,
+		RuntimeTask,
+		RuntimeViewFunction
+	)]
+	pub struct Test;
+
+	#[runtime::pallet_index(0)]
+	pub type System = frame_system::Pallet<Test>;
+
+	#[runtime::pallet_index(1)]
+	pub type Voting = pallet_voting::Pallet<Test>;
+
+	#[runtime::pallet_index(2)]
+	pub type Balances = pallet_balances::Pallet<Test>;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+}
+
+/// Counts one vote per unit of free balance.
+pub struct BalanceToVoteWeight;
+impl Convert<u64, u32> for BalanceToVoteWeight {
+	fn convert(balance: u64) -> u32 {
+		balance.saturated_into()
+	}
+}
+
+parameter_types! {
+	pub static RequireRegistration: bool = false;
+	pub static MinQuorum: u32 = 0;
+	pub static RandomTieBreaking: bool = false;
+	pub static RandomSeed: u8 = 0;
+	pub static AllowResetDuringVoting: bool = true;
+	pub static Now: u64 = 0;
+	pub static MinCandidates: u32 = 1;
+	pub static RequireVerifiedIdentity: bool = false;
+	pub static VerifiedIdentities: Vec<u64> = vec![];
+	pub static TurnoutReportInterval: u64 = 0;
+	pub static MaxVoters: u32 = u32::MAX;
+	pub static MinCreationLead: u64 = 0;
+	pub static SlashedFundsDestination: Option<u64> = None;
+	pub static AllowMultipleCandidaciesPerAccount: bool = true;
+	pub static ExpectedCandidates: u32 = 0;
+	pub static EnableVoteLog: bool = true;
+	pub static ResetBatchSize: u32 = 1_000;
+	pub static VotingEligibilityDelay: u64 = 0;
+	pub static MinDescriptionLength: u32 = 0;
+	pub static EmitPerVoteEvents: bool = true;
+	pub static MinEndorsements: u32 = 0;
+	pub static VoteCreditBudget: u32 = 100;
+	pub static MaxScheduledElections: u32 = 100;
+}
+
+#[cfg(feature = "scheduler")]
+parameter_types! {
+	pub static FinalizationDelay: Option<u64> = None;
+	pub static ScheduledTasks: Vec<(TaskName, u64, RuntimeCall)> = vec![];
+}
+
+/// A registrar that has verified the identities of the accounts in [`VerifiedIdentities`].
+pub struct TestRegistrar;
+impl pallet_voting::IdentityVerifier<u64> for TestRegistrar {
+	fn has_verified_identity(who: &u64) -> bool {
+		VerifiedIdentities::get().contains(who)
+	}
+}
+
+/// A clock that reports [`Now`].
+pub struct TestTime;
+impl Time for TestTime {
+	type Moment = u64;
+
+	fn now() -> u64 {
+		Now::get()
+	}
+}
+
+/// Returns a hash made of [`RandomSeed`] bytes, whatever the subject.
+pub struct TestRandomness;
+impl Randomness<H256, u64> for TestRandomness {
+	fn random(_subject: &[u8]) -> (H256, u64) {
+		(H256::repeat_byte(RandomSeed::get()), System::block_number())
+	}
+}
+
+/// A scheduler that keeps its tasks in [`ScheduledTasks`] without ever dispatching them.
+#[cfg(feature = "scheduler")]
+pub struct TestScheduler;
+#[cfg(feature = "scheduler")]
+impl Named<u64, RuntimeCall, OriginCaller> for TestScheduler {
+	type Address = TaskName;
+	type Hasher = BlakeTwo256;
+
+	fn schedule_named(
+		id: TaskName,
+		when: DispatchTime<u64>,
+		_maybe_periodic: Option<Period<u64>>,
+		_priority: Priority,
+		_origin: OriginCaller,
+		call: Bounded<RuntimeCall, BlakeTwo256>,
+	) -> Result<TaskName, DispatchError> {
+		let Bounded::Inline(encoded) = call else { return Err(DispatchError::Unavailable) };
+		let call = RuntimeCall::decode(&mut &encoded[..])
+			.map_err(|_| DispatchError::Other("Undecodable call"))?;
+		let mut tasks = ScheduledTasks::get();
+		if tasks.iter().any(|(task, ..)| *task == id) {
+			return Err(DispatchError::Other("Duplicate task"))
+		}
+		tasks.push((id, when.evaluate(System::block_number()), call));
+		ScheduledTasks::set(tasks);
+		Ok(id)
+	}
+
+	fn cancel_named(id: TaskName) -> Result<(), DispatchError> {
+		let mut tasks = ScheduledTasks::get();
+		let position =
+			tasks.iter().position(|(task, ..)| *task == id).ok_or(DispatchError::Unavailable)?;
+		tasks.remove(position);
+		ScheduledTasks::set(tasks);
+		Ok(())
+	}
+
+	fn reschedule_named(id: TaskName, when: DispatchTime<u64>) -> Result<TaskName, DispatchError> {
+		let mut tasks = ScheduledTasks::get();
+		let task =
+			tasks.iter_mut().find(|(task, ..)| *task == id).ok_or(DispatchError::Unavailable)?;
+		task.1 = when.evaluate(System::block_number());
+		ScheduledTasks::set(tasks);
+		Ok(id)
+	}
+
+	fn next_dispatch_time(id: TaskName) -> Result<u64, DispatchError> {
+		ScheduledTasks::get()
+			.into_iter()
+			.find(|(task, ..)| *task == id)
+			.map(|(_, at, _)| at)
+			.ok_or(DispatchError::Unavailable)
+	}
+}
+
+impl pallet_voting::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type MaxCandidates = ConstU32<3>;
+	type MaxNameLength = ConstU32<16>;
+	type MaxTitleLength = ConstU32<32>;
+	type Currency = Balances;
+	type BalanceToVoteWeight = BalanceToVoteWeight;
+	type RequireRegistration = RequireRegistration;
+	type AdminOrigin = EnsureRoot<u64>;
+	type ElectionDeposit = ConstU64<5>;
+	type CandidateBond = ConstU64<3>;
+	type MinQuorum = MinQuorum;
+	type WeightInfo = ();
+	type Randomness = TestRandomness;
+	type RandomTieBreaking = RandomTieBreaking;
+	type MaxDelegators = ConstU32<4>;
+	type AllowResetDuringVoting = AllowResetDuringVoting;
+	type Time = TestTime;
+	type MinCandidates = MinCandidates;
+	type IdentityProvider = TestRegistrar;
+	type RequireVerifiedIdentity = RequireVerifiedIdentity;
+	type TurnoutReportInterval = TurnoutReportInterval;
+	type MaxReasonLength = ConstU32<32>;
+	type MaxVoters = MaxVoters;
+	type MinCreationLead = MinCreationLead;
+	type SlashedFundsDestination = SlashedFundsDestination;
+	type AllowMultipleCandidaciesPerAccount = AllowMultipleCandidaciesPerAccount;
+	type ExpectedCandidates = ExpectedCandidates;
+	type EnableVoteLog = EnableVoteLog;
+	type MaxDescriptionLength = ConstU32<32>;
+	type ResetConfirmationWindow = ConstU64<10>;
+	type ResetBatchSize = ResetBatchSize;
+	type VotingEligibilityDelay = VotingEligibilityDelay;
+	type MinDescriptionLength = MinDescriptionLength;
+	type MaxElectionDuration = ConstU64<1_000>;
+	type EmitPerVoteEvents = EmitPerVoteEvents;
+	type MinEndorsements = MinEndorsements;
+	type VoteCreditBudget = VoteCreditBudget;
+	type MaxScheduledElections = MaxScheduledElections;
+	type MaxVoteLocks = ConstU32<3>;
+	#[cfg(feature = "scheduler")]
+	type RuntimeCall = RuntimeCall;
+	#[cfg(feature = "scheduler")]
+	type PalletsOrigin = OriginCaller;
+	#[cfg(feature = "scheduler")]
+	type Scheduler = TestScheduler;
+	#[cfg(feature = "scheduler")]
+	type FinalizationDelay = FinalizationDelay;
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: (1..=10).map(|who| (who, 10 * who)).collect(),
+		..Default::default()
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+	let mut ext: sp_io::TestExternalities = storage.into();
+	// Go past genesis block so events get deposited.
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,5061 @@
+use crate::{
+	migrations::{
+		v1::MigrateToV1,
+		v10::{self, MigrateToV10},
+		v11::{self, MigrateToV11},
+		v12::MigrateToV12,
+		v13::{self, MigrateToV13},
+		v14::{self, MigrateToV14},
+		v15::{self, MigrateToV15},
+		v16::{self, MigrateToV16},
+		v17::{self, MigrateToV17},
+		v18::MigrateToV18,
+		v19::{self, MigrateToV19},
+		v2::{MigrateToV2, OldCandidate},
+		v20::{self, MigrateToV20},
+		v21::MigrateToV21,
+		v22::MigrateToV22,
+		v3::{self, MigrateToV3, OldElectionInfo},
+		v4::MigrateToV4,
+		v5::{self, MigrateToV5},
+		v6::{MigrateToV6, OldVoteRecord},
+		v7::{self, MigrateToV7},
+		v8::{self, MigrateToV8},
+		v9::MigrateToV9,
+	},
+	mock::*,
+	AbstainCount, Admins, Approvals, BallotCount, Candidacies, CandidateExists, CandidateOwners,
+	Candidates, CandidatesLocked, CheckNotAlreadyVoted, Commitments, DelegatedVotes, Delegations,
+	Delegators, Disputes, Election, ElectionKind, ElectionStatus, EndorsementCount, Endorsements,
+	Error, Event, FinalResults, HasVoted, NextCandidateId, NextElectionId, NextResetNonce,
+	Nullifiers, PastElections, PendingResets, RankedBallotCount, Rankings, Receipts,
+	RegisteredVoterCount, RegisteredVoters, ResetCursor, ResultOrder, ScheduledElections,
+	SpentCredits, TotalVotes, VoteCount, VoteLocks, VoteLog, VoteRecord, VoterRegisteredAt,
+	VotesInBlock, VotingWindow, WinnerResult, ABSTAIN, MAX_METADATA_URI_LENGTH,
+};
+use codec::Encode;
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::DispatchInfo,
+	pallet_prelude::TransactionSource,
+	traits::{GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion},
+};
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, DispatchTransaction, Hash},
+	transaction_validity::{InvalidTransaction, TransactionValidity},
+	BuildStorage, Permill,
+};
+
+fn blocks(start: u64, end: u64) -> VotingWindow<u64, u64> {
+	VotingWindow::Blocks { start, end }
+}
+
+fn create_election(start_block: u64, end_block: u64) -> u32 {
+	create_election_of_kind(start_block, end_block, ElectionKind::Plurality)
+}
+
+fn create_election_of_kind(start_block: u64, end_block: u64, kind: ElectionKind) -> u32 {
+	create_election_with(start_block, end_block, kind, false)
+}
+
+fn create_election_with(
+	start_block: u64,
+	end_block: u64,
+	kind: ElectionKind,
+	weighted: bool,
+) -> u32 {
+	let election_id = NextElectionId::<Test>::get();
+	assert_ok!(Voting::create_election(
+		RuntimeOrigin::root(),
+		b"Council".to_vec(),
+		blocks(start_block, end_block),
+		kind,
+		weighted,
+		None,
+		1,
+		false,
+		false,
+		None,
+		None
+	));
+	election_id
+}
+
+fn start_election(election_id: u32) {
+	assert_ok!(Voting::lock_candidates(RuntimeOrigin::root(), election_id));
+	assert_ok!(Voting::start_election(RuntimeOrigin::root(), election_id));
+}
+
+/// Reset an election in two steps, which also resets elections that have not been finalized.
+fn confirmed_reset(election_id: u32) {
+	let nonce = NextResetNonce::<Test>::get();
+	assert_ok!(Voting::request_reset(RuntimeOrigin::root(), election_id));
+	assert_ok!(Voting::confirm_reset(RuntimeOrigin::root(), election_id, nonce));
+}
+
+fn add_candidate(election_id: u32, name: &[u8]) -> u32 {
+	let candidate_id = NextCandidateId::<Test>::get(election_id);
+	assert_ok!(Voting::add_candidate(
+		RuntimeOrigin::root(),
+		election_id,
+		name.to_vec(),
+		b"Manifesto".to_vec()
+	));
+	candidate_id
+}
+
+#[test]
+fn elections_get_sequential_ids() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(create_election(1, 10), 0);
+		System::assert_last_event(
+			Event::ElectionCreated { election_id: 0, title: b"Council".to_vec() }.into(),
+		);
+		assert_eq!(create_election(1, 10), 1);
+		System::assert_last_event(
+			Event::ElectionCreated { election_id: 1, title: b"Council".to_vec() }.into(),
+		);
+		assert!(Election::<Test>::contains_key(0));
+		assert!(Election::<Test>::contains_key(1));
+		assert_eq!(NextElectionId::<Test>::get(), 2);
+	});
+}
+
+#[test]
+fn voter_queries_list_the_accounts_that_voted() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		let alice = add_candidate(election_id, b"Alice");
+		let bob = add_candidate(election_id, b"Bob");
+		let other = create_election(1, 10);
+		let carol = add_candidate(other, b"Carol");
+		start_election(election_id);
+		start_election(other);
+		for (voter, candidate_id) in [(1, alice), (2, bob), (3, alice)] {
+			assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(voter), election_id, candidate_id));
+		}
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(4), other, carol));
+
+		let mut alice_voters = Voting::voters_for_candidate(election_id, alice);
+		alice_voters.sort();
+		assert_eq!(alice_voters, vec![1, 3]);
+		assert_eq!(Voting::voters_for_candidate(election_id, bob), vec![2]);
+		assert!(Voting::voters_for_candidate(election_id, ABSTAIN).is_empty());
+
+		let mut voters = Voting::all_voters(election_id);
+		voters.sort();
+		assert_eq!(voters, vec![1, 2, 3]);
+		assert_eq!(Voting::all_voters(other), vec![4]);
+	});
+}
+
+#[test]
+fn create_election_rejects_invalid_time_range() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Voting::create_election(
+				RuntimeOrigin::root(),
+				b"Council".to_vec(),
+				blocks(10, 10),
+				ElectionKind::Plurality,
+				false,
+				None,
+				1,
+				false,
+				false,
+				None,
+				None
+			),
+			Error::<Test>::InvalidTimeRange
+		);
+	});
+}
+
+#[test]
+fn voting_windows_cannot_exceed_the_max_election_duration() {
+	new_test_ext().execute_with(|| {
+		let create = |start, end| {
+			Voting::create_election(
+				RuntimeOrigin::root(),
+				b"Council".to_vec(),
+				blocks(start, end),
+				ElectionKind::Plurality,
+				false,
+				None,
+				1,
+				false,
+				false,
+				None,
+				None,
+			)
+		};
+		assert_noop!(create(1, u64::MAX), Error::<Test>::DurationTooLong);
+		assert_noop!(create(1, 1_002), Error::<Test>::DurationTooLong);
+		// Short windows are fine however late they end.
+		assert_ok!(create(u64::MAX - 1_000, u64::MAX));
+
+		let election_id = create_election(1, 1_001);
+		add_candidate(election_id, b"Alice");
+		start_election(election_id);
+		assert_noop!(
+			Voting::extend_election(RuntimeOrigin::root(), election_id, 1_002),
+			Error::<Test>::DurationTooLong
+		);
+	});
+}
+
+#[test]
+fn create_election_rejects_windows_that_already_started() {
+	new_test_ext().execute_with(|| {
+		let create = |window| {
+			Voting::create_election(
+				RuntimeOrigin::root(),
+				b"Council".to_vec(),
+				window,
+				ElectionKind::Plurality,
+				false,
+				None,
+				1,
+				false,
+				false,
+				None,
+				None,
+			)
+		};
+		System::set_block_number(5);
+		assert_noop!(create(blocks(4, 10)), Error::<Test>::StartBlockInPast);
+		assert_ok!(create(blocks(5, 10)));
+
+		MinCreationLead::set(3);
+		assert_noop!(create(blocks(7, 10)), Error::<Test>::StartBlockInPast);
+		assert_ok!(create(blocks(8, 10)));
+
+		Now::set(1_000);
+		assert_noop!(
+			create(VotingWindow::Time { start: 999, end: 2_000 }),
+			Error::<Test>::StartBlockInPast
+		);
+		assert_ok!(create(VotingWindow::Time { start: 1_000, end: 2_000 }));
+	});
+}
+
+#[test]
+fn election_lifecycle_works() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		let alice = add_candidate(election_id, b"Alice");
+		let bob = add_candidate(election_id, b"Bob");
+		System::assert_last_event(
+			Event::CandidateAdded {
+				election_id,
+				candidate_id: bob,
+				name: b"Bob".to_vec(),
+				description: b"Manifesto".to_vec(),
+				total_candidates: 2,
+			}
+			.into(),
+		);
+
+		start_election(election_id);
+		System::assert_last_event(Event::ElectionStarted { election_id, block: 1 }.into());
+
+		for (voter, candidate_id, new_count, total_votes) in
+			[(1, alice, 1, 1), (2, bob, 1, 2), (3, alice, 2, 3)]
+		{
+			assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(voter), election_id, candidate_id));
+			System::assert_last_event(
+				Event::VoteCast {
+					election_id,
+					voter,
+					candidate_id: Some(candidate_id),
+					new_count: Some(new_count),
+					total_votes: Some(total_votes),
+					receipt: receipt(election_id, voter, candidate_id),
+				}
+				.into(),
+			);
+		}
+		assert!(Voting::has_account_voted(election_id, &1));
+		assert_eq!(Voting::get_vote_for_account(election_id, &2), Some(bob));
+		assert_eq!(TotalVotes::<Test>::get(election_id), 3);
+
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), election_id));
+		System::assert_last_event(Event::ElectionEnded { election_id, block: 1 }.into());
+
+		assert_ok!(Voting::finalize_election(RuntimeOrigin::root(), election_id, 0));
+		System::assert_has_event(Event::ElectionFinalized { election_id, total_votes: 3 }.into());
+		System::assert_has_event(
+			Event::WinnerDeclared { election_id, candidate_id: alice, votes: 2 }.into(),
+		);
+		assert_eq!(Election::<Test>::get(election_id).unwrap().status, ElectionStatus::Finalized);
+		assert_eq!(
+			Voting::get_results(election_id),
+			vec![
+				(alice, b"Alice".to_vec(), 2, None),
+				(bob, b"Bob".to_vec(), 1, None),
+				(ABSTAIN, b"Abstain".to_vec(), 0, None)
+			]
+		);
+		assert_noop!(
+			Voting::finalize_election(RuntimeOrigin::root(), election_id, 0),
+			Error::<Test>::AlreadyFinalized
+		);
+	});
+}
+
+#[test]
+fn cast_vote_fails_outside_voting_window() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(3, 10);
+		let alice = add_candidate(election_id, b"Alice");
+		assert_noop!(
+			Voting::cast_vote(RuntimeOrigin::signed(1), election_id, alice),
+			Error::<Test>::ElectionNotActive
+		);
+
+		// Starting the election early does not open the voting window.
+		start_election(election_id);
+		assert_noop!(
+			Voting::cast_vote(RuntimeOrigin::signed(1), election_id, alice),
+			Error::<Test>::VotingNotStarted
+		);
+
+		System::set_block_number(10);
+		assert_noop!(
+			Voting::cast_vote(RuntimeOrigin::signed(1), election_id, alice),
+			Error::<Test>::VotingEnded
+		);
+	});
+}
+
+#[test]
+fn cast_vote_fails_for_double_vote_and_unknown_candidate() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		let alice = add_candidate(election_id, b"Alice");
+		start_election(election_id);
+		assert_noop!(
+			Voting::cast_vote(RuntimeOrigin::signed(1), election_id, alice + 1),
+			Error::<Test>::InvalidCandidate
+		);
+		assert_noop!(
+			Voting::cast_vote(RuntimeOrigin::signed(1), election_id + 1, alice),
+			Error::<Test>::NoElectionExists
+		);
+
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(1), election_id, alice));
+		assert_noop!(
+			Voting::cast_vote(RuntimeOrigin::signed(1), election_id, alice),
+			Error::<Test>::AlreadyVoted
+		);
+		assert_eq!(VoteCount::<Test>::get(election_id, alice), 1);
+	});
+}
+
+#[test]
+fn vote_cast_events_are_indexed_by_candidate() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		let alice = add_candidate(election_id, b"Alice");
+		let bob = add_candidate(election_id, b"Bob");
+		start_election(election_id);
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(1), election_id, alice));
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(2), election_id, bob));
+
+		let topic = BlakeTwo256::hash_of(&bob);
+		let events = System::events();
+		let indexed: Vec<_> = events.iter().filter(|record| record.topics == vec![topic]).collect();
+		assert_eq!(indexed.len(), 1);
+		assert!(matches!(
+			&indexed[0].event,
+			RuntimeEvent::Voting(Event::VoteCast { voter: 2, candidate_id, .. }) if *candidate_id == Some(bob)
+		));
+		assert_eq!(System::event_topics(topic).len(), 1);
+	});
+}
+
+#[test]
+fn votes_are_summarized_per_block_without_per_vote_events() {
+	new_test_ext().execute_with(|| {
+		EmitPerVoteEvents::set(false);
+		let election_id = create_election(1, 10);
+		let alice = add_candidate(election_id, b"Alice");
+		let ranked = create_election_of_kind(1, 10, ElectionKind::RankedChoice);
+		let bob = add_candidate(ranked, b"Bob");
+		start_election(election_id);
+		start_election(ranked);
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(1), election_id, alice));
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(2), election_id, ABSTAIN));
+		assert_ok!(Voting::cast_ranked_vote(RuntimeOrigin::signed(1), ranked, vec![bob]));
+		assert!(!System::events()
+			.iter()
+			.any(|record| matches!(record.event, RuntimeEvent::Voting(Event::VoteCast { .. }))));
+
+		Voting::on_finalize(1);
+		System::assert_last_event(Event::VotesBatchRecorded { count: 3, total_votes: 3 }.into());
+		assert_eq!(VotesInBlock::<Test>::get(), (0, 0));
+
+		// Blocks without votes are not summarized.
+		System::reset_events();
+		Voting::on_finalize(2);
+		assert!(System::events().is_empty());
+	});
+}
+
+#[test]
+fn can_vote_reports_why_a_vote_would_fail() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		let alice = add_candidate(election_id, b"Alice");
+		assert!(matches!(
+			Voting::can_vote(&1, election_id, alice),
+			Err(Error::<Test>::ElectionNotActive)
+		));
+		start_election(election_id);
+
+		assert!(Voting::can_vote(&1, election_id, alice).is_ok());
+		assert!(matches!(
+			Voting::can_vote(&1, election_id, alice + 1),
+			Err(Error::<Test>::InvalidCandidate)
+		));
+		assert!(matches!(
+			Voting::can_vote(&1, election_id + 1, alice),
+			Err(Error::<Test>::NoElectionExists)
+		));
+		RequireRegistration::set(true);
+		assert!(matches!(
+			Voting::can_vote(&1, election_id, alice),
+			Err(Error::<Test>::NotRegistered)
+		));
+		RequireRegistration::set(false);
+
+		// Checking does not change any state.
+		assert!(!Voting::has_account_voted(election_id, &1));
+		assert_eq!(TotalVotes::<Test>::get(election_id), 0);
+
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(1), election_id, alice));
+		assert!(matches!(
+			Voting::can_vote(&1, election_id, alice),
+			Err(Error::<Test>::AlreadyVoted)
+		));
+	});
+}
+
+#[test]
+fn votes_are_rejected_once_max_voters_is_reached() {
+	new_test_ext().execute_with(|| {
+		MaxVoters::set(2);
+		let election_id = create_election(1, 10);
+		let alice = add_candidate(election_id, b"Alice");
+		start_election(election_id);
+		let ranked = create_election_of_kind(1, 10, ElectionKind::RankedChoice);
+		add_candidate(ranked, b"Alice");
+		start_election(ranked);
+
+		for voter in [1, 2] {
+			assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(voter), election_id, alice));
+			assert_ok!(Voting::cast_ranked_vote(RuntimeOrigin::signed(voter), ranked, vec![1]));
+		}
+		assert_noop!(
+			Voting::cast_vote(RuntimeOrigin::signed(3), election_id, alice),
+			Error::<Test>::MaxVotersReached
+		);
+		assert_noop!(
+			Voting::cast_ranked_vote(RuntimeOrigin::signed(3), ranked, vec![1]),
+			Error::<Test>::MaxVotersReached
+		);
+		assert_eq!(TotalVotes::<Test>::get(election_id), 2);
+
+		// A retracted vote makes room for another one.
+		assert_ok!(Voting::retract_vote(RuntimeOrigin::signed(1), election_id));
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(3), election_id, alice));
+	});
+}
+
+#[test]
+fn unlisted_votes_record_nullifiers_instead_of_voters() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Voting::create_election(
+			RuntimeOrigin::root(),
+			b"Council".to_vec(),
+			blocks(1, 10),
+			ElectionKind::Plurality,
+			false,
+			None,
+			1,
+			true,
+			false,
+			None,
+			None
+		));
+		let alice = add_candidate(0, b"Alice");
+		start_election(0);
+		let open = create_election(1, 10);
+		add_candidate(open, b"Alice");
+		start_election(open);
+
+		assert_ok!(Voting::cast_unlisted_vote(RuntimeOrigin::signed(1), 0, alice));
+		System::assert_last_event(
+			Event::UnlistedVoteCast { election_id: 0, candidate_id: Some(alice) }.into(),
+		);
+		assert!(Nullifiers::<Test>::contains_key(0, Voting::nullifier(0, &1)));
+		assert!(!Voting::has_account_voted(0, &1));
+		assert_eq!(VoteCount::<Test>::get(0, alice), 1);
+		assert_eq!(TotalVotes::<Test>::get(0), 1);
+
+		assert_noop!(
+			Voting::cast_unlisted_vote(RuntimeOrigin::signed(1), 0, alice),
+			Error::<Test>::AlreadyVoted
+		);
+		assert_noop!(
+			Voting::cast_vote(RuntimeOrigin::signed(2), 0, alice),
+			Error::<Test>::WrongElectionKind
+		);
+		assert_noop!(
+			Voting::cast_unlisted_vote(RuntimeOrigin::signed(2), open, alice),
+			Error::<Test>::WrongElectionKind
+		);
+		assert_ok!(Voting::cast_unlisted_vote(RuntimeOrigin::signed(2), 0, alice));
+		assert_eq!(VoteCount::<Test>::get(0, alice), 2);
+	});
+}
+
+#[test]
+fn nullifiers_are_kept_per_election_and_cleared_with_the_votes() {
+	new_test_ext().execute_with(|| {
+		for _ in 0..2 {
+			assert_ok!(Voting::create_election(
+				RuntimeOrigin::root(),
+				b"Council".to_vec(),
+				blocks(1, 10),
+				ElectionKind::Plurality,
+				false,
+				None,
+				1,
+				true,
+				false,
+				None,
+				None
+			));
+		}
+		let alice = add_candidate(0, b"Alice");
+		start_election(0);
+		add_candidate(1, b"Alice");
+		start_election(1);
+
+		assert_ok!(Voting::cast_unlisted_vote(RuntimeOrigin::signed(1), 0, alice));
+		assert_ok!(Voting::cast_unlisted_vote(RuntimeOrigin::signed(1), 1, alice));
+		assert_ne!(Voting::nullifier(0, &1), Voting::nullifier(1, &1));
+
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), 0));
+		assert_ok!(Voting::reset_votes(RuntimeOrigin::root(), 0));
+		assert_eq!(Nullifiers::<Test>::iter_prefix(0).count(), 0);
+		assert!(Nullifiers::<Test>::contains_key(1, Voting::nullifier(1, &1)));
+	});
+}
+
+#[test]
+fn only_open_plurality_elections_can_be_unlisted() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Voting::create_election(
+				RuntimeOrigin::root(),
+				b"Council".to_vec(),
+				blocks(1, 10),
+				ElectionKind::RankedChoice,
+				false,
+				None,
+				1,
+				true,
+				false,
+				None,
+				None
+			),
+			Error::<Test>::WrongElectionKind
+		);
+		assert_noop!(
+			Voting::create_election(
+				RuntimeOrigin::root(),
+				b"Council".to_vec(),
+				blocks(1, 10),
+				ElectionKind::Plurality,
+				false,
+				Some(20),
+				1,
+				true,
+				false,
+				None,
+				None
+			),
+			Error::<Test>::WrongElectionKind
+		);
+	});
+}
+
+#[test]
+fn write_in_votes_add_missing_candidates() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Voting::create_election(
+			RuntimeOrigin::root(),
+			b"Poll".to_vec(),
+			blocks(1, 10),
+			ElectionKind::Plurality,
+			false,
+			None,
+			1,
+			false,
+			true,
+			None,
+			None
+		));
+		let alice = add_candidate(0, b"Alice");
+		start_election(0);
+		let closed = create_election(1, 10);
+		add_candidate(closed, b"Alice");
+		start_election(closed);
+
+		// A name that stands is voted for without adding a candidate.
+		assert_ok!(Voting::cast_write_in_vote(RuntimeOrigin::signed(1), 0, b"Alice".to_vec()));
+		System::assert_last_event(
+			Event::WriteInVoteCast {
+				election_id: 0,
+				voter: 1,
+				candidate_id: Some(alice),
+				name: Some(b"Alice".to_vec()),
+			}
+			.into(),
+		);
+		assert_eq!(Voting::candidate_count(0), 1);
+
+		assert_ok!(Voting::cast_write_in_vote(RuntimeOrigin::signed(2), 0, b"Bob".to_vec()));
+		System::assert_last_event(
+			Event::WriteInVoteCast {
+				election_id: 0,
+				voter: 2,
+				candidate_id: Some(2),
+				name: Some(b"Bob".to_vec()),
+			}
+			.into(),
+		);
+		System::assert_has_event(
+			Event::VoteCast {
+				election_id: 0,
+				voter: 2,
+				candidate_id: Some(2),
+				new_count: Some(1),
+				total_votes: Some(2),
+				receipt: receipt(0, 2, 2),
+			}
+			.into(),
+		);
+		assert_eq!(Voting::verify_receipt(0, &2), Some(receipt(0, 2, 2)));
+		assert_ok!(Voting::cast_write_in_vote(RuntimeOrigin::signed(3), 0, b"Bob".to_vec()));
+		assert_eq!(
+			Voting::get_results(0),
+			vec![
+				(alice, b"Alice".to_vec(), 1, None),
+				(2, b"Bob".to_vec(), 2, None),
+				(ABSTAIN, b"Abstain".to_vec(), 0, None),
+			]
+		);
+
+		assert_noop!(
+			Voting::cast_write_in_vote(RuntimeOrigin::signed(3), 0, b"Carol".to_vec()),
+			Error::<Test>::AlreadyVoted
+		);
+		assert_noop!(
+			Voting::cast_write_in_vote(RuntimeOrigin::signed(4), 0, vec![b'x'; 17]),
+			Error::<Test>::NameTooLong
+		);
+		assert_ok!(Voting::cast_write_in_vote(RuntimeOrigin::signed(4), 0, b"Carol".to_vec()));
+		assert_noop!(
+			Voting::cast_write_in_vote(RuntimeOrigin::signed(5), 0, b"Dave".to_vec()),
+			Error::<Test>::TooManyCandidates
+		);
+		assert_noop!(
+			Voting::cast_write_in_vote(RuntimeOrigin::signed(5), closed, b"Alice".to_vec()),
+			Error::<Test>::WriteInsNotAllowed
+		);
+	});
+}
+
+#[test]
+fn write_in_votes_are_summarized_per_block_without_per_vote_events() {
+	new_test_ext().execute_with(|| {
+		EmitPerVoteEvents::set(false);
+		assert_ok!(Voting::create_election(
+			RuntimeOrigin::root(),
+			b"Poll".to_vec(),
+			blocks(1, 10),
+			ElectionKind::Plurality,
+			false,
+			None,
+			1,
+			false,
+			true,
+			None,
+			None
+		));
+		add_candidate(0, b"Alice");
+		start_election(0);
+
+		assert_ok!(Voting::cast_write_in_vote(RuntimeOrigin::signed(1), 0, b"Bob".to_vec()));
+		assert_eq!(VotesInBlock::<Test>::get(), (1, 1));
+		Voting::on_finalize(1);
+		System::assert_last_event(Event::VotesBatchRecorded { count: 1, total_votes: 1 }.into());
+	});
+}
+
+#[test]
+fn only_open_plurality_elections_can_allow_write_ins() {
+	new_test_ext().execute_with(|| {
+		for (kind, reveal_end_block, unlisted) in [
+			(ElectionKind::RankedChoice, None, false),
+			(ElectionKind::Plurality, Some(20), false),
+			(ElectionKind::Plurality, None, true),
+		] {
+			assert_noop!(
+				Voting::create_election(
+					RuntimeOrigin::root(),
+					b"Poll".to_vec(),
+					blocks(1, 10),
+					kind,
+					false,
+					reveal_end_block,
+					1,
+					unlisted,
+					true,
+					None,
+					None
+				),
+				Error::<Test>::WrongElectionKind
+			);
+		}
+	});
+}
+
+#[test]
+fn check_not_already_voted_rejects_repeat_votes_in_the_pool() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		let alice = add_candidate(election_id, b"Alice");
+		start_election(election_id);
+
+		let ranked = create_election_of_kind(1, 10, ElectionKind::RankedChoice);
+		let bob = add_candidate(ranked, b"Bob");
+		start_election(ranked);
+
+		let call: RuntimeCall = crate::Call::cast_vote { election_id, candidate_id: alice }.into();
+		let ranked_call: RuntimeCall =
+			crate::Call::cast_ranked_vote { election_id: ranked, rankings: vec![bob] }.into();
+		let validity = |who: u64, call: &RuntimeCall| -> TransactionValidity {
+			CheckNotAlreadyVoted::<Test>::new()
+				.validate_only(
+					Some(who).into(),
+					call,
+					&DispatchInfo::default(),
+					0,
+					TransactionSource::External,
+					0,
+				)
+				.map(|(validity, _, _)| validity)
+		};
+
+		assert_ok!(validity(1, &call));
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(1), election_id, alice));
+		assert_eq!(
+			validity(1, &call),
+			Err(InvalidTransaction::Custom(crate::extensions::ALREADY_VOTED).into())
+		);
+		assert_ok!(validity(1, &ranked_call));
+		assert_ok!(Voting::cast_ranked_vote(RuntimeOrigin::signed(1), ranked, vec![bob]));
+		assert_eq!(
+			validity(1, &ranked_call),
+			Err(InvalidTransaction::Custom(crate::extensions::ALREADY_VOTED).into())
+		);
+		// Other voters and calls are not affected.
+		assert_ok!(validity(2, &call));
+		let other_call: RuntimeCall = crate::Call::retract_vote { election_id }.into();
+		assert_ok!(CheckNotAlreadyVoted::<Test>::new().validate_only(
+			Some(1).into(),
+			&other_call,
+			&DispatchInfo::default(),
+			0,
+			TransactionSource::External,
+			0,
+		));
+	});
+}
+
+#[test]
+fn names_and_titles_must_be_valid_utf8() {
+	new_test_ext().execute_with(|| {
+		let invalid = vec![b'A', 0xff, 0xfe];
+		assert_noop!(
+			Voting::create_election(
+				RuntimeOrigin::root(),
+				invalid.clone(),
+				blocks(1, 10),
+				ElectionKind::Plurality,
+				false,
+				None,
+				1,
+				false,
+				false,
+				None,
+				None
+			),
+			Error::<Test>::InvalidUtf8
+		);
+
+		let election_id = create_election(1, 10);
+		assert_noop!(
+			Voting::add_candidate(
+				RuntimeOrigin::root(),
+				election_id,
+				invalid.clone(),
+				b"Manifesto".to_vec()
+			),
+			Error::<Test>::InvalidUtf8
+		);
+		let alice = add_candidate(election_id, "Zoë".as_bytes());
+		assert_noop!(
+			Voting::update_candidate(
+				RuntimeOrigin::root(),
+				election_id,
+				alice,
+				invalid,
+				b"Manifesto".to_vec()
+			),
+			Error::<Test>::InvalidUtf8
+		);
+	});
+}
+
+#[test]
+fn add_candidate_fails_when_election_is_full() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		for name in [&b"Alice"[..], b"Bob", b"Carol"] {
+			add_candidate(election_id, name);
+		}
+		assert_noop!(
+			Voting::add_candidate(
+				RuntimeOrigin::root(),
+				election_id,
+				b"Dave".to_vec(),
+				b"Manifesto".to_vec()
+			),
+			Error::<Test>::TooManyCandidates
+		);
+	});
+}
+
+#[test]
+fn elections_can_take_fewer_candidates_than_max_candidates() {
+	new_test_ext().execute_with(|| {
+		let create = |max_candidates| {
+			Voting::create_election(
+				RuntimeOrigin::root(),
+				b"Club".to_vec(),
+				blocks(1, 10),
+				ElectionKind::Plurality,
+				false,
+				None,
+				1,
+				false,
+				false,
+				None,
+				max_candidates,
+			)
+		};
+		assert_noop!(create(Some(0)), Error::<Test>::InvalidMaxCandidates);
+		assert_noop!(create(Some(4)), Error::<Test>::InvalidMaxCandidates);
+		let election_id = NextElectionId::<Test>::get();
+		assert_ok!(create(Some(2)));
+		assert_eq!(Election::<Test>::get(election_id).unwrap().max_candidates, 2);
+
+		add_candidate(election_id, b"Alice");
+		assert_noop!(
+			Voting::batch_add_candidates(
+				RuntimeOrigin::root(),
+				election_id,
+				vec![(b"Bob".to_vec(), vec![]), (b"Carol".to_vec(), vec![])]
+			),
+			Error::<Test>::TooManyCandidates
+		);
+		add_candidate(election_id, b"Bob");
+		assert_noop!(
+			Voting::add_candidate(RuntimeOrigin::root(), election_id, b"Carol".to_vec(), vec![]),
+			Error::<Test>::TooManyCandidates
+		);
+	});
+}
+
+#[test]
+fn batch_add_candidates_adds_all_or_nothing() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		add_candidate(election_id, b"Alice");
+		assert_noop!(
+			Voting::batch_add_candidates(
+				RuntimeOrigin::root(),
+				election_id,
+				vec![(b"Bob".to_vec(), vec![]), (b"A name that is far too long".to_vec(), vec![])]
+			),
+			Error::<Test>::NameTooLong
+		);
+		assert_noop!(
+			Voting::batch_add_candidates(
+				RuntimeOrigin::root(),
+				election_id,
+				vec![(b"Bob".to_vec(), vec![]); 3]
+			),
+			Error::<Test>::TooManyCandidates
+		);
+
+		assert_ok!(Voting::batch_add_candidates(
+			RuntimeOrigin::root(),
+			election_id,
+			vec![(b"Bob".to_vec(), b"Second".to_vec()), (b"Carol".to_vec(), b"Third".to_vec())]
+		));
+		System::assert_last_event(Event::CandidatesBatchAdded { election_id, count: 2 }.into());
+		let candidates = Candidates::<Test>::get(election_id);
+		assert_eq!(candidates.iter().map(|c| c.id).collect::<Vec<_>>(), vec![1, 2, 3]);
+		assert_eq!(candidates[2].description.to_vec(), b"Third".to_vec());
+	});
+}
+
+#[test]
+fn reaching_the_expected_candidates_completes_the_set() {
+	new_test_ext().execute_with(|| {
+		ExpectedCandidates::set(2);
+		let election_id = create_election(1, 10);
+		add_candidate(election_id, b"Alice");
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::Voting(Event::CandidateSetComplete { .. })
+		)));
+		add_candidate(election_id, b"Bob");
+		System::assert_last_event(
+			Event::CandidateSetComplete { election_id, total_candidates: 2 }.into(),
+		);
+
+		// Going past the expected number only completes the set once.
+		System::reset_events();
+		add_candidate(election_id, b"Carol");
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::Voting(Event::CandidateSetComplete { .. })
+		)));
+
+		// A batch that crosses the expected number completes the set too.
+		let other = create_election(1, 10);
+		assert_ok!(Voting::batch_add_candidates(
+			RuntimeOrigin::root(),
+			other,
+			vec![
+				(b"Alice".to_vec(), vec![]),
+				(b"Bob".to_vec(), vec![]),
+				(b"Carol".to_vec(), vec![])
+			]
+		));
+		System::assert_last_event(
+			Event::CandidateSetComplete { election_id: other, total_candidates: 3 }.into(),
+		);
+	});
+}
+
+#[test]
+fn titles_names_and_descriptions_longer_than_their_limits_are_rejected() {
+	new_test_ext().execute_with(|| {
+		let too_long = vec![b'x'; 17];
+		assert_noop!(
+			Voting::create_election(
+				RuntimeOrigin::root(),
+				vec![b'x'; 33],
+				blocks(1, 10),
+				ElectionKind::Plurality,
+				false,
+				None,
+				1,
+				false,
+				false,
+				None,
+				None
+			),
+			Error::<Test>::TitleTooLong
+		);
+
+		// Titles are bounded by `MaxTitleLength` rather than `MaxNameLength`.
+		let election_id = NextElectionId::<Test>::get();
+		assert_ok!(Voting::create_election(
+			RuntimeOrigin::root(),
+			vec![b'x'; 32],
+			blocks(1, 10),
+			ElectionKind::Plurality,
+			false,
+			None,
+			1,
+			false,
+			false,
+			None,
+			None
+		));
+		assert_noop!(
+			Voting::add_candidate(
+				RuntimeOrigin::root(),
+				election_id,
+				too_long.clone(),
+				b"Manifesto".to_vec()
+			),
+			Error::<Test>::NameTooLong
+		);
+		// Descriptions are bounded by `MaxDescriptionLength` rather than `MaxNameLength`.
+		assert_noop!(
+			Voting::add_candidate(
+				RuntimeOrigin::root(),
+				election_id,
+				b"Alice".to_vec(),
+				vec![b'x'; 33]
+			),
+			Error::<Test>::DescriptionTooLong
+		);
+		assert_ok!(Voting::add_candidate(
+			RuntimeOrigin::root(),
+			election_id,
+			b"Alice".to_vec(),
+			too_long.clone()
+		));
+		assert_noop!(
+			Voting::update_candidate(
+				RuntimeOrigin::root(),
+				election_id,
+				1,
+				too_long.clone(),
+				b"Manifesto".to_vec()
+			),
+			Error::<Test>::NameTooLong
+		);
+		assert_noop!(
+			Voting::update_candidate(
+				RuntimeOrigin::root(),
+				election_id,
+				1,
+				b"Alice".to_vec(),
+				vec![b'x'; 33]
+			),
+			Error::<Test>::DescriptionTooLong
+		);
+		// Names of exactly `MaxNameLength` bytes are accepted.
+		add_candidate(election_id, &[b'x'; 16]);
+	});
+}
+
+#[test]
+fn empty_names_and_too_short_descriptions_are_rejected() {
+	new_test_ext().execute_with(|| {
+		MinDescriptionLength::set(4);
+		let election_id = create_election(1, 10);
+		assert_noop!(
+			Voting::add_candidate(
+				RuntimeOrigin::root(),
+				election_id,
+				Vec::new(),
+				b"Manifesto".to_vec()
+			),
+			Error::<Test>::NameEmpty
+		);
+		assert_noop!(
+			Voting::add_candidate(
+				RuntimeOrigin::root(),
+				election_id,
+				b"Alice".to_vec(),
+				Vec::new()
+			),
+			Error::<Test>::DescriptionTooShort
+		);
+		assert_noop!(
+			Voting::register_as_candidate(
+				RuntimeOrigin::signed(2),
+				election_id,
+				b"Bob".to_vec(),
+				b"Bob".to_vec()
+			),
+			Error::<Test>::DescriptionTooShort
+		);
+		assert_noop!(
+			Voting::batch_add_candidates(
+				RuntimeOrigin::root(),
+				election_id,
+				vec![(b"Bob".to_vec(), b"Manifesto".to_vec()), (b"Carol".to_vec(), vec![])]
+			),
+			Error::<Test>::DescriptionTooShort
+		);
+
+		// Descriptions of exactly `MinDescriptionLength` bytes are accepted.
+		assert_ok!(Voting::add_candidate(
+			RuntimeOrigin::root(),
+			election_id,
+			b"Alice".to_vec(),
+			b"Mine".to_vec()
+		));
+		assert_noop!(
+			Voting::update_candidate(
+				RuntimeOrigin::root(),
+				election_id,
+				1,
+				Vec::new(),
+				b"Manifesto".to_vec()
+			),
+			Error::<Test>::NameEmpty
+		);
+		assert_noop!(
+			Voting::update_candidate(
+				RuntimeOrigin::root(),
+				election_id,
+				1,
+				b"Alice".to_vec(),
+				b"Me".to_vec()
+			),
+			Error::<Test>::DescriptionTooShort
+		);
+	});
+}
+
+#[test]
+fn candidates_get_sequential_ids() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+
+		assert_ok!(Voting::add_candidate(
+			RuntimeOrigin::root(),
+			election_id,
+			b"Alice".to_vec(),
+			b"First".to_vec()
+		));
+		System::assert_last_event(
+			Event::CandidateAdded {
+				election_id,
+				candidate_id: 1,
+				name: b"Alice".to_vec(),
+				description: b"First".to_vec(),
+				total_candidates: 1,
+			}
+			.into(),
+		);
+		assert_eq!(add_candidate(election_id, b"Bob"), 2);
+		assert_eq!(NextCandidateId::<Test>::get(election_id), 3);
+
+		// Identifiers of removed candidates are not handed out again.
+		assert_ok!(Voting::remove_candidate(RuntimeOrigin::root(), election_id, 2));
+		assert_eq!(add_candidate(election_id, b"Carol"), 3);
+
+		// Every election has its own sequence.
+		let other = create_election(1, 10);
+		assert_eq!(add_candidate(other, b"Dave"), 1);
+	});
+}
+
+#[test]
+fn candidates_paged_slices_defensively() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		for name in [&b"Alice"[..], b"Bob", b"Carol"] {
+			add_candidate(election_id, name);
+		}
+		assert_eq!(Voting::candidate_count(election_id), 3);
+		assert_eq!(Voting::candidate_count(election_id + 1), 0);
+
+		let ids = |offset, limit| -> Vec<u32> {
+			Voting::candidates_paged(election_id, offset, limit).iter().map(|c| c.id).collect()
+		};
+		assert_eq!(ids(0, 2), vec![1, 2]);
+		assert_eq!(ids(2, 2), vec![3]);
+		assert_eq!(ids(1, u32::MAX), vec![2, 3]);
+		assert_eq!(ids(0, 0), Vec::<u32>::new());
+		// Out-of-range offsets yield an empty page rather than panicking.
+		assert!(Voting::candidates_paged(election_id, 3, 2).is_empty());
+		assert!(Voting::candidates_paged(election_id, u32::MAX, u32::MAX).is_empty());
+		assert!(Voting::candidates_paged(election_id + 1, 0, 10).is_empty());
+	});
+}
+
+#[test]
+fn get_candidate_finds_a_single_candidate() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		add_candidate(election_id, b"Alice");
+		let bob = add_candidate(election_id, b"Bob");
+
+		let candidate = Voting::get_candidate(election_id, bob).unwrap();
+		assert_eq!((candidate.id, candidate.name.to_vec()), (bob, b"Bob".to_vec()));
+		assert!(Voting::get_candidate(election_id, 3).is_none());
+		assert!(Voting::get_candidate(election_id + 1, bob).is_none());
+	});
+}
+
+#[test]
+fn votes_are_tracked_per_election() {
+	new_test_ext().execute_with(|| {
+		let council = create_election(1, 10);
+		let club = create_election(1, 10);
+		for election_id in [council, club] {
+			add_candidate(election_id, b"Alice");
+			start_election(election_id);
+		}
+
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(1), council, 1));
+		assert_noop!(
+			Voting::cast_vote(RuntimeOrigin::signed(1), council, 1),
+			Error::<Test>::AlreadyVoted
+		);
+		// Having voted in one election does not prevent voting in another.
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(1), club, 1));
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(2), club, 1));
+
+		assert_eq!(
+			Voting::get_results(council),
+			vec![(1, b"Alice".to_vec(), 1, None), (ABSTAIN, b"Abstain".to_vec(), 0, None)]
+		);
+		assert_eq!(
+			Voting::get_results(club),
+			vec![(1, b"Alice".to_vec(), 2, None), (ABSTAIN, b"Abstain".to_vec(), 0, None)]
+		);
+	});
+}
+
+#[test]
+fn reset_election_only_clears_that_election() {
+	new_test_ext().execute_with(|| {
+		let council = create_election(1, 10);
+		let club = create_election(1, 10);
+		for election_id in [council, club] {
+			let candidate_id = add_candidate(election_id, b"Alice");
+			start_election(election_id);
+			assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(1), election_id, candidate_id));
+		}
+
+		assert_ok!(Voting::request_reset(RuntimeOrigin::root(), council));
+		assert_ok!(Voting::confirm_reset(RuntimeOrigin::root(), council, 0));
+		System::assert_has_event(Event::ElectionReset { election_id: council }.into());
+		System::assert_last_event(Event::ResetConfirmed { election_id: council }.into());
+
+		assert!(!Election::<Test>::contains_key(council));
+		assert!(Candidates::<Test>::get(council).is_empty());
+		assert_eq!(NextCandidateId::<Test>::get(council), 1);
+		assert!(!HasVoted::<Test>::contains_key(council, 1));
+		assert_eq!(TotalVotes::<Test>::get(council), 0);
+
+		assert!(Election::<Test>::contains_key(club));
+		assert_eq!(Voting::get_vote_for_account(club, &1), Some(1));
+		assert_eq!(TotalVotes::<Test>::get(club), 1);
+	});
+}
+
+#[test]
+fn reset_election_requires_finalization_unless_allowed_during_voting() {
+	new_test_ext().execute_with(|| {
+		AllowResetDuringVoting::set(false);
+		let election_id = run_election(&[b"Alice"], &[1]);
+		assert_noop!(
+			Voting::reset_election(RuntimeOrigin::root(), election_id),
+			Error::<Test>::ElectionIsActive
+		);
+		assert_noop!(
+			Voting::request_reset(RuntimeOrigin::root(), election_id),
+			Error::<Test>::ElectionIsActive
+		);
+
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), election_id));
+		assert_noop!(
+			Voting::reset_election(RuntimeOrigin::root(), election_id),
+			Error::<Test>::ElectionIsActive
+		);
+		assert_ok!(Voting::finalize_election(RuntimeOrigin::root(), election_id, 0));
+		assert_ok!(Voting::reset_election(RuntimeOrigin::root(), election_id));
+		assert!(Election::<Test>::get(election_id).is_none());
+	});
+}
+
+#[test]
+fn resets_of_unfinalized_elections_have_to_be_confirmed() {
+	new_test_ext().execute_with(|| {
+		let election_id = run_election(&[b"Alice"], &[1]);
+		assert_noop!(
+			Voting::reset_election(RuntimeOrigin::root(), election_id),
+			Error::<Test>::ResetNotConfirmed
+		);
+		assert_noop!(
+			Voting::confirm_reset(RuntimeOrigin::root(), election_id, 0),
+			Error::<Test>::NoPendingReset
+		);
+
+		System::set_block_number(2);
+		assert_ok!(Voting::request_reset(RuntimeOrigin::root(), election_id));
+		System::assert_last_event(
+			Event::ResetRequested { election_id, nonce: 0, expires_at: 12 }.into(),
+		);
+		assert_noop!(
+			Voting::confirm_reset(RuntimeOrigin::root(), election_id, 1),
+			Error::<Test>::WrongResetNonce
+		);
+		System::set_block_number(13);
+		assert_noop!(
+			Voting::confirm_reset(RuntimeOrigin::root(), election_id, 0),
+			Error::<Test>::ResetRequestExpired
+		);
+
+		// A new request replaces the expired one.
+		assert_ok!(Voting::request_reset(RuntimeOrigin::root(), election_id));
+		assert_noop!(
+			Voting::confirm_reset(RuntimeOrigin::root(), election_id, 0),
+			Error::<Test>::WrongResetNonce
+		);
+		assert_ok!(Voting::confirm_reset(RuntimeOrigin::root(), election_id, 1));
+		assert!(Election::<Test>::get(election_id).is_none());
+		assert!(PendingResets::<Test>::get(election_id).is_none());
+	});
+}
+
+#[test]
+fn large_elections_are_reset_over_several_calls() {
+	let mut ext = new_test_ext();
+	let election_id = ext.execute_with(|| {
+		ResetBatchSize::set(2);
+		let election_id = run_election(&[b"Alice", b"Bob"], &[1, 2, 1]);
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), election_id));
+		assert_ok!(Voting::finalize_election(RuntimeOrigin::root(), election_id, 0));
+		election_id
+	});
+	// Only committed entries count against the batch size, so every call is made on top of the
+	// storage committed by the previous one, as in separate blocks.
+	ext.commit_all().unwrap();
+
+	// Three votes, two vote counts, two vote logs and three receipts are removed two at a time.
+	for remaining_votes in [1, 0, 0, 0, 0] {
+		ext.execute_with(|| {
+			assert_ok!(Voting::reset_election(RuntimeOrigin::root(), election_id));
+			System::assert_last_event(Event::ElectionPartiallyReset { election_id }.into());
+			assert!(Election::<Test>::get(election_id).is_none());
+			assert!(ResetCursor::<Test>::contains_key(election_id));
+			assert_eq!(HasVoted::<Test>::iter_prefix(election_id).count(), remaining_votes);
+		});
+		ext.commit_all().unwrap();
+	}
+
+	ext.execute_with(|| {
+		assert_ok!(Voting::reset_election(RuntimeOrigin::root(), election_id));
+		System::assert_last_event(Event::ElectionReset { election_id }.into());
+		assert_eq!(VoteCount::<Test>::iter_prefix(election_id).count(), 0);
+		assert_eq!(VoteLog::<Test>::iter_prefix(election_id).count(), 0);
+		assert!(!ResetCursor::<Test>::contains_key(election_id));
+		assert_noop!(
+			Voting::reset_election(RuntimeOrigin::root(), election_id),
+			Error::<Test>::NoElectionExists
+		);
+	});
+}
+
+#[test]
+fn reset_votes_keeps_the_candidates_for_another_round() {
+	new_test_ext().execute_with(|| {
+		AllowResetDuringVoting::set(false);
+		let election_id = run_election(&[b"Alice", b"Bob"], &[1, 2, 1]);
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(4), election_id, ABSTAIN));
+		assert_noop!(
+			Voting::reset_votes(RuntimeOrigin::signed(1), election_id),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Voting::reset_votes(RuntimeOrigin::root(), election_id),
+			Error::<Test>::ElectionIsActive
+		);
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), election_id));
+		assert_ok!(Voting::finalize_election(RuntimeOrigin::root(), election_id, 0));
+
+		assert_ok!(Voting::reset_votes(RuntimeOrigin::root(), election_id));
+		System::assert_last_event(Event::VotesReset { election_id }.into());
+		let election = Election::<Test>::get(election_id).unwrap();
+		assert_eq!(election.status, ElectionStatus::Created);
+		assert_eq!(Candidates::<Test>::get(election_id).len(), 2);
+		assert_eq!(HasVoted::<Test>::iter_prefix(election_id).count(), 0);
+		assert_eq!(Receipts::<Test>::iter_prefix(election_id).count(), 0);
+		assert_eq!(VoteCount::<Test>::get(election_id, 1), 0);
+		assert_eq!(VoteCount::<Test>::get(election_id, 2), 0);
+		assert_eq!(TotalVotes::<Test>::get(election_id), 0);
+		assert_eq!(AbstainCount::<Test>::get(election_id), 0);
+		assert!(FinalResults::<Test>::get(election_id).is_none());
+
+		// The same candidates stand again and everybody gets a new vote.
+		assert_ok!(Voting::start_election(RuntimeOrigin::root(), election_id));
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(1), election_id, 2));
+		assert_eq!(VoteCount::<Test>::get(election_id, 2), 1);
+		assert_eq!(TotalVotes::<Test>::get(election_id), 1);
+	});
+}
+
+#[test]
+fn reset_votes_is_batched_and_blocks_the_restart() {
+	let mut ext = new_test_ext();
+	let election_id = ext.execute_with(|| {
+		ResetBatchSize::set(2);
+		let election_id = run_election(&[b"Alice", b"Bob"], &[1, 2, 1]);
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), election_id));
+		election_id
+	});
+	ext.commit_all().unwrap();
+
+	ext.execute_with(|| {
+		assert_ok!(Voting::reset_votes(RuntimeOrigin::root(), election_id));
+		System::assert_last_event(Event::VotesPartiallyReset { election_id }.into());
+		assert_noop!(
+			Voting::start_election(RuntimeOrigin::root(), election_id),
+			Error::<Test>::VotesResetInProgress
+		);
+	});
+	ext.commit_all().unwrap();
+
+	// Every call is made on top of the storage committed by the previous one, as in separate
+	// blocks.
+	while !ext.execute_with(|| {
+		assert_ok!(Voting::reset_votes(RuntimeOrigin::root(), election_id));
+		System::events()
+			.iter()
+			.any(|record| record.event == Event::VotesReset { election_id }.into())
+	}) {
+		ext.commit_all().unwrap();
+	}
+
+	ext.execute_with(|| {
+		assert_eq!(HasVoted::<Test>::iter_prefix(election_id).count(), 0);
+		assert_eq!(VoteLog::<Test>::iter_prefix(election_id).count(), 0);
+		assert_eq!(VoteCount::<Test>::iter_prefix(election_id).count(), 2);
+		assert_ok!(Voting::start_election(RuntimeOrigin::root(), election_id));
+	});
+}
+
+#[test]
+fn candidates_need_endorsements_before_the_election_starts() {
+	new_test_ext().execute_with(|| {
+		MinEndorsements::set(2);
+		let election_id = create_election(1, 10);
+		let alice = add_candidate(election_id, b"Alice");
+		let bob = NextCandidateId::<Test>::get(election_id);
+		assert_ok!(Voting::register_as_candidate(
+			RuntimeOrigin::signed(2),
+			election_id,
+			b"Bob".to_vec(),
+			b"Manifesto".to_vec()
+		));
+		for voter in [1, 2, 3] {
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+		}
+
+		assert_noop!(
+			Voting::endorse_candidate(RuntimeOrigin::signed(4), election_id, alice),
+			Error::<Test>::NotRegistered
+		);
+		assert_noop!(
+			Voting::endorse_candidate(RuntimeOrigin::signed(2), election_id, bob),
+			Error::<Test>::SelfEndorsement
+		);
+		assert_noop!(
+			Voting::endorse_candidate(RuntimeOrigin::signed(1), election_id, 7),
+			Error::<Test>::InvalidCandidate
+		);
+		assert_ok!(Voting::endorse_candidate(RuntimeOrigin::signed(1), election_id, alice));
+		System::assert_last_event(
+			Event::CandidateEndorsed {
+				election_id,
+				candidate_id: alice,
+				endorser: 1,
+				endorsements: 1,
+			}
+			.into(),
+		);
+		assert_noop!(
+			Voting::endorse_candidate(RuntimeOrigin::signed(1), election_id, alice),
+			Error::<Test>::AlreadyEndorsed
+		);
+		assert_ok!(Voting::endorse_candidate(RuntimeOrigin::signed(2), election_id, alice));
+		assert_ok!(Voting::endorse_candidate(RuntimeOrigin::signed(1), election_id, bob));
+
+		// Bob has a single endorsement.
+		assert_ok!(Voting::lock_candidates(RuntimeOrigin::root(), election_id));
+		assert_noop!(
+			Voting::start_election(RuntimeOrigin::root(), election_id),
+			Error::<Test>::InsufficientEndorsements
+		);
+		Voting::on_initialize(1);
+		assert_eq!(Election::<Test>::get(election_id).unwrap().status, ElectionStatus::Created);
+
+		assert_ok!(Voting::endorse_candidate(RuntimeOrigin::signed(3), election_id, bob));
+		assert_eq!(EndorsementCount::<Test>::get(election_id, bob), 2);
+		assert_ok!(Voting::start_election(RuntimeOrigin::root(), election_id));
+		assert_noop!(
+			Voting::endorse_candidate(RuntimeOrigin::signed(3), election_id, alice),
+			Error::<Test>::ElectionIsActive
+		);
+
+		// Resetting the election removes its endorsements.
+		confirmed_reset(election_id);
+		assert_eq!(Endorsements::<Test>::iter_prefix(election_id).count(), 0);
+		assert_eq!(EndorsementCount::<Test>::get(election_id, bob), 0);
+	});
+}
+
+#[test]
+fn remove_candidate_works() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		let alice = add_candidate(election_id, b"Alice");
+		let bob = add_candidate(election_id, b"Bob");
+
+		assert_ok!(Voting::remove_candidate(RuntimeOrigin::root(), election_id, alice));
+		System::assert_last_event(
+			Event::CandidateRemoved { election_id, candidate_id: alice }.into(),
+		);
+
+		assert_eq!(
+			Voting::get_results(election_id),
+			vec![(bob, b"Bob".to_vec(), 0, None), (ABSTAIN, b"Abstain".to_vec(), 0, None)]
+		);
+		assert!(!VoteCount::<Test>::contains_key(election_id, alice));
+		assert_noop!(
+			Voting::remove_candidate(RuntimeOrigin::root(), election_id, alice),
+			Error::<Test>::InvalidCandidate
+		);
+	});
+}
+
+#[test]
+fn remove_candidate_fails_while_election_is_active() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		let alice = add_candidate(election_id, b"Alice");
+		start_election(election_id);
+
+		assert_noop!(
+			Voting::remove_candidate(RuntimeOrigin::root(), election_id, alice),
+			Error::<Test>::ElectionIsActive
+		);
+	});
+}
+
+#[test]
+fn candidate_exists_indexes_the_standing_candidates() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		let alice = add_candidate(election_id, b"Alice");
+		let bob = add_candidate(election_id, b"Bob");
+		assert_ok!(Voting::register_as_candidate(
+			RuntimeOrigin::signed(2),
+			election_id,
+			b"Carol".to_vec(),
+			b"Manifesto".to_vec()
+		));
+		assert!(CandidateExists::<Test>::contains_key(election_id, alice));
+		assert!(CandidateExists::<Test>::contains_key(election_id, 3));
+
+		assert_ok!(Voting::remove_candidate(RuntimeOrigin::root(), election_id, alice));
+		assert_ok!(Voting::withdraw_candidacy(RuntimeOrigin::signed(2), election_id));
+		assert_eq!(
+			CandidateExists::<Test>::iter_key_prefix(election_id).collect::<Vec<_>>(),
+			[bob]
+		);
+
+		start_election(election_id);
+		assert_noop!(
+			Voting::cast_vote(RuntimeOrigin::signed(1), election_id, alice),
+			Error::<Test>::InvalidCandidate
+		);
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(1), election_id, bob));
+
+		confirmed_reset(election_id);
+		assert_eq!(CandidateExists::<Test>::iter_key_prefix(election_id).count(), 0);
+	});
+}
+
+#[test]
+fn update_candidate_works() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		let candidate_id = add_candidate(election_id, b"Alcie");
+
+		assert_ok!(Voting::update_candidate(
+			RuntimeOrigin::root(),
+			election_id,
+			candidate_id,
+			b"Alice".to_vec(),
+			b"Fixed".to_vec()
+		));
+		System::assert_last_event(
+			Event::CandidateUpdated { election_id, candidate_id, name: b"Alice".to_vec() }.into(),
+		);
+
+		let candidate = &Candidates::<Test>::get(election_id)[0];
+		assert_eq!(candidate.name.to_vec(), b"Alice".to_vec());
+		assert_eq!(candidate.description.to_vec(), b"Fixed".to_vec());
+	});
+}
+
+#[test]
+fn update_candidate_fails_for_unknown_candidate() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		assert_noop!(
+			Voting::update_candidate(
+				RuntimeOrigin::root(),
+				election_id,
+				0,
+				b"Alice".to_vec(),
+				b"First".to_vec()
+			),
+			Error::<Test>::InvalidCandidate
+		);
+	});
+}
+
+#[test]
+fn change_vote_moves_the_vote() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		let alice = add_candidate(election_id, b"Alice");
+		let bob = add_candidate(election_id, b"Bob");
+		start_election(election_id);
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(1), election_id, alice));
+		assert_eq!(Voting::vote_record(election_id, &1), Some((alice, 1)));
+
+		System::set_block_number(3);
+		assert_ok!(Voting::change_vote(RuntimeOrigin::signed(1), election_id, bob));
+		System::assert_last_event(
+			Event::VoteChanged {
+				election_id,
+				voter: 1,
+				old_candidate_id: Some(alice),
+				new_candidate_id: Some(bob),
+			}
+			.into(),
+		);
+
+		assert_eq!(VoteCount::<Test>::get(election_id, alice), 0);
+		assert_eq!(VoteCount::<Test>::get(election_id, bob), 1);
+		assert_eq!(TotalVotes::<Test>::get(election_id), 1);
+		assert_eq!(Voting::get_vote_for_account(election_id, &1), Some(bob));
+		assert_eq!(Voting::vote_record(election_id, &1), Some((bob, 3)));
+		assert_eq!(Voting::vote_record(election_id, &2), None);
+	});
+}
+
+#[test]
+fn change_vote_fails_for_invalid_input() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		let alice = add_candidate(election_id, b"Alice");
+		start_election(election_id);
+
+		assert_noop!(
+			Voting::change_vote(RuntimeOrigin::signed(1), election_id, alice),
+			Error::<Test>::HasNotVoted
+		);
+
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(1), election_id, alice));
+		assert_noop!(
+			Voting::change_vote(RuntimeOrigin::signed(1), election_id, 42),
+			Error::<Test>::InvalidCandidate
+		);
+
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), election_id));
+		assert_noop!(
+			Voting::change_vote(RuntimeOrigin::signed(1), election_id, alice),
+			Error::<Test>::ElectionNotActive
+		);
+	});
+}
+
+#[test]
+fn retract_vote_works() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		let alice = add_candidate(election_id, b"Alice");
+		start_election(election_id);
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(1), election_id, alice));
+
+		assert_ok!(Voting::retract_vote(RuntimeOrigin::signed(1), election_id));
+		System::assert_last_event(
+			Event::VoteRetracted { election_id, voter: 1, candidate_id: Some(alice) }.into(),
+		);
+
+		assert!(!Voting::has_account_voted(election_id, &1));
+		assert_eq!(VoteCount::<Test>::get(election_id, alice), 0);
+		assert_eq!(TotalVotes::<Test>::get(election_id), 0);
+		assert_noop!(
+			Voting::retract_vote(RuntimeOrigin::signed(1), election_id),
+			Error::<Test>::HasNotVoted
+		);
+
+		// The voter may vote again after retracting.
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(1), election_id, alice));
+	});
+}
+
+#[test]
+fn retract_vote_fails_after_voting_closed() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		let alice = add_candidate(election_id, b"Alice");
+		start_election(election_id);
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(1), election_id, alice));
+
+		System::set_block_number(10);
+		assert_noop!(
+			Voting::retract_vote(RuntimeOrigin::signed(1), election_id),
+			Error::<Test>::VotingEnded
+		);
+	});
+}
+
+#[test]
+fn end_election_fails_unless_the_election_is_active() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		assert_noop!(
+			Voting::end_election(RuntimeOrigin::root(), election_id),
+			Error::<Test>::ElectionNotActive
+		);
+
+		add_candidate(election_id, b"Alice");
+		start_election(election_id);
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), election_id));
+		assert_noop!(
+			Voting::end_election(RuntimeOrigin::root(), election_id),
+			Error::<Test>::ElectionNotActive
+		);
+	});
+}
+
+#[test]
+fn election_starts_automatically_at_start_block() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(3, 10);
+		add_candidate(election_id, b"Alice");
+		assert_ok!(Voting::lock_candidates(RuntimeOrigin::root(), election_id));
+
+		Voting::on_initialize(2);
+		assert_eq!(Election::<Test>::get(election_id).unwrap().status, ElectionStatus::Created);
+
+		System::set_block_number(3);
+		Voting::on_initialize(3);
+		assert_eq!(Election::<Test>::get(election_id).unwrap().status, ElectionStatus::Active);
+		System::assert_last_event(Event::ElectionStarted { election_id, block: 3 }.into());
+	});
+}
+
+#[test]
+fn start_election_requires_candidates() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		assert_noop!(
+			Voting::start_election(RuntimeOrigin::root(), election_id),
+			Error::<Test>::NoCandidates
+		);
+		// An election without candidates is not started automatically either.
+		Voting::on_initialize(1);
+		assert_eq!(Election::<Test>::get(election_id).unwrap().status, ElectionStatus::Created);
+
+		MinCandidates::set(2);
+		add_candidate(election_id, b"Alice");
+		assert_noop!(
+			Voting::start_election(RuntimeOrigin::root(), election_id),
+			Error::<Test>::TooFewCandidates
+		);
+		add_candidate(election_id, b"Bob");
+		start_election(election_id);
+	});
+}
+
+#[test]
+fn candidates_have_to_be_locked_before_the_election_starts() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		let alice = add_candidate(election_id, b"Alice");
+		assert_noop!(
+			Voting::start_election(RuntimeOrigin::root(), election_id),
+			Error::<Test>::CandidatesNotLocked
+		);
+		// Elections with unlocked candidates are not started automatically either.
+		Voting::on_initialize(1);
+		assert_eq!(Election::<Test>::get(election_id).unwrap().status, ElectionStatus::Created);
+
+		assert_ok!(Voting::lock_candidates(RuntimeOrigin::root(), election_id));
+		System::assert_last_event(Event::CandidatesLocked { election_id }.into());
+		assert_noop!(
+			Voting::lock_candidates(RuntimeOrigin::root(), election_id),
+			Error::<Test>::CandidatesLocked
+		);
+		assert_noop!(
+			Voting::add_candidate(
+				RuntimeOrigin::root(),
+				election_id,
+				b"Bob".to_vec(),
+				b"Manifesto".to_vec()
+			),
+			Error::<Test>::CandidatesLocked
+		);
+		assert_noop!(
+			Voting::remove_candidate(RuntimeOrigin::root(), election_id, alice),
+			Error::<Test>::CandidatesLocked
+		);
+		assert_noop!(
+			Voting::register_as_candidate(
+				RuntimeOrigin::signed(2),
+				election_id,
+				b"Bob".to_vec(),
+				b"Manifesto".to_vec()
+			),
+			Error::<Test>::CandidatesLocked
+		);
+
+		assert_ok!(Voting::start_election(RuntimeOrigin::root(), election_id));
+		assert_noop!(
+			Voting::add_candidate(
+				RuntimeOrigin::root(),
+				election_id,
+				b"Bob".to_vec(),
+				b"Manifesto".to_vec()
+			),
+			Error::<Test>::ElectionIsActive
+		);
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), election_id));
+		assert_noop!(
+			Voting::batch_add_candidates(
+				RuntimeOrigin::root(),
+				election_id,
+				vec![(b"Bob".to_vec(), vec![])]
+			),
+			Error::<Test>::ElectionIsActive
+		);
+	});
+}
+
+#[test]
+fn turnout_snapshots_are_emitted_for_active_elections() {
+	new_test_ext().execute_with(|| {
+		TurnoutReportInterval::set(5);
+		let election_id = create_election(8, 20);
+		let alice = add_candidate(election_id, b"Alice");
+		assert_ok!(Voting::lock_candidates(RuntimeOrigin::root(), election_id));
+		let idle = create_election(30, 40);
+		add_candidate(idle, b"Bob");
+		assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+		assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 2));
+
+		let snapshots = || {
+			System::events()
+				.iter()
+				.filter(|record| {
+					matches!(record.event, RuntimeEvent::Voting(Event::TurnoutSnapshot { .. }))
+				})
+				.count()
+		};
+
+		// No snapshot is taken while no election is active, nor between two intervals.
+		Voting::on_initialize(5);
+		assert_eq!(snapshots(), 0);
+		System::set_block_number(8);
+		Voting::on_initialize(8);
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(1), election_id, alice));
+		System::set_block_number(9);
+		Voting::on_initialize(9);
+		assert_eq!(snapshots(), 0);
+
+		System::set_block_number(10);
+		Voting::on_initialize(10);
+		System::assert_last_event(
+			Event::TurnoutSnapshot {
+				election_id,
+				block: 10,
+				total_votes: 1,
+				voters: 1,
+				registered: 2,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn manually_ended_election_is_not_restarted() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		add_candidate(election_id, b"Alice");
+		start_election(election_id);
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), election_id));
+
+		System::set_block_number(2);
+		Voting::on_initialize(2);
+		assert_eq!(Election::<Test>::get(election_id).unwrap().status, ElectionStatus::Ended);
+	});
+}
+
+#[test]
+fn election_ends_automatically_at_end_block() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		let alice = add_candidate(election_id, b"Alice");
+		start_election(election_id);
+
+		System::set_block_number(9);
+		Voting::on_initialize(9);
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(1), election_id, alice));
+
+		System::set_block_number(10);
+		Voting::on_initialize(10);
+		assert_eq!(Election::<Test>::get(election_id).unwrap().status, ElectionStatus::Ended);
+		System::assert_last_event(Event::ElectionEnded { election_id, block: 10 }.into());
+
+		// A vote submitted exactly at `end_block` is rejected.
+		assert_noop!(
+			Voting::cast_vote(RuntimeOrigin::signed(2), election_id, alice),
+			Error::<Test>::ElectionNotActive
+		);
+	});
+}
+
+#[test]
+fn on_initialize_only_drives_scheduled_elections() {
+	new_test_ext().execute_with(|| {
+		let ending = create_election(1, 10);
+		add_candidate(ending, b"Alice");
+		start_election(ending);
+		let cancelled = create_election(5, 10);
+		let pending = create_election(20, 30);
+		assert_eq!(
+			ScheduledElections::<Test>::get().into_inner(),
+			vec![ending, cancelled, pending]
+		);
+
+		assert_ok!(Voting::cancel_election(RuntimeOrigin::root(), cancelled, b"Fraud".to_vec()));
+		assert_eq!(ScheduledElections::<Test>::get().into_inner(), vec![ending, pending]);
+
+		System::set_block_number(10);
+		Voting::on_initialize(10);
+		assert_eq!(Election::<Test>::get(ending).unwrap().status, ElectionStatus::Ended);
+		assert_eq!(ScheduledElections::<Test>::get().into_inner(), vec![pending]);
+
+		assert_ok!(Voting::finalize_election(RuntimeOrigin::root(), ending, 1));
+		assert_eq!(ScheduledElections::<Test>::get().into_inner(), vec![pending]);
+	});
+}
+
+#[test]
+fn elections_that_were_never_started_expire_with_their_window() {
+	new_test_ext().execute_with(|| {
+		// The candidates are never locked, so the election cannot start.
+		let election_id = create_election(1, 10);
+		add_candidate(election_id, b"Alice");
+
+		System::set_block_number(5);
+		Voting::on_initialize(5);
+		assert_eq!(ScheduledElections::<Test>::get().into_inner(), vec![election_id]);
+
+		System::set_block_number(10);
+		Voting::on_initialize(10);
+		System::assert_last_event(Event::ElectionExpired { election_id }.into());
+		assert!(ScheduledElections::<Test>::get().is_empty());
+		assert_eq!(Election::<Test>::get(election_id).unwrap().status, ElectionStatus::Created);
+		assert_ok!(Voting::cancel_election(RuntimeOrigin::root(), election_id, b"Late".to_vec()));
+	});
+}
+
+#[test]
+fn create_election_rejects_more_scheduled_elections_than_allowed() {
+	new_test_ext().execute_with(|| {
+		MaxScheduledElections::set(1);
+		let election_id = create_election(1, 10);
+		assert_noop!(
+			Voting::create_election(
+				RuntimeOrigin::root(),
+				b"Council".to_vec(),
+				blocks(1, 10),
+				ElectionKind::Plurality,
+				false,
+				None,
+				1,
+				false,
+				false,
+				None,
+				None
+			),
+			Error::<Test>::TooManyScheduledElections
+		);
+
+		assert_ok!(Voting::cancel_election(RuntimeOrigin::root(), election_id, Vec::new()));
+		create_election(1, 10);
+	});
+}
+
+#[test]
+fn force_finalize_closes_an_active_election() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		let alice = add_candidate(election_id, b"Alice");
+		add_candidate(election_id, b"Bob");
+		start_election(election_id);
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(1), election_id, alice));
+		assert_noop!(
+			Voting::finalize_election(RuntimeOrigin::root(), election_id, 0),
+			Error::<Test>::ElectionIsActive
+		);
+		assert_noop!(
+			Voting::force_finalize(RuntimeOrigin::signed(1), election_id, 0),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_ok!(Voting::force_finalize(RuntimeOrigin::root(), election_id, 0));
+		let election = Election::<Test>::get(election_id).unwrap();
+		assert_eq!(election.status, ElectionStatus::Finalized);
+		System::assert_has_event(Event::ElectionEnded { election_id, block: 1 }.into());
+		System::assert_has_event(Event::ElectionFinalized { election_id, total_votes: 1 }.into());
+		System::assert_has_event(
+			Event::WinnerDeclared { election_id, candidate_id: alice, votes: 1 }.into(),
+		);
+		assert_eq!(PastElections::<Test>::get(0).unwrap().winners.to_vec(), vec![alice]);
+
+		assert_noop!(
+			Voting::cast_vote(RuntimeOrigin::signed(2), election_id, alice),
+			Error::<Test>::ElectionNotActive
+		);
+		assert_noop!(
+			Voting::force_finalize(RuntimeOrigin::root(), election_id, 0),
+			Error::<Test>::AlreadyFinalized
+		);
+	});
+}
+
+#[test]
+fn cancel_election_archives_the_reason_and_returns_deposits() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Voting::add_admin(RuntimeOrigin::root(), 2));
+		assert_ok!(Voting::create_election(
+			RuntimeOrigin::signed(2),
+			b"Council".to_vec(),
+			blocks(1, 10),
+			ElectionKind::Plurality,
+			false,
+			None,
+			1,
+			false,
+			false,
+			None,
+			None
+		));
+		let alice = add_candidate(0, b"Alice");
+		assert_ok!(Voting::register_as_candidate(
+			RuntimeOrigin::signed(3),
+			0,
+			b"Bob".to_vec(),
+			b"Manifesto".to_vec()
+		));
+		start_election(0);
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(1), 0, alice));
+		assert_eq!(Balances::reserved_balance(2), 5);
+		assert_eq!(Balances::reserved_balance(3), 3);
+
+		assert_noop!(
+			Voting::cancel_election(RuntimeOrigin::signed(2), 0, b"Fraud".to_vec()),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Voting::cancel_election(RuntimeOrigin::root(), 0, vec![b'x'; 33]),
+			Error::<Test>::ReasonTooLong
+		);
+		assert_ok!(Voting::cancel_election(RuntimeOrigin::root(), 0, b"Fraud".to_vec()));
+		System::assert_has_event(
+			Event::ElectionCancelled { election_id: 0, reason: b"Fraud".to_vec() }.into(),
+		);
+		System::assert_last_event(Event::ElectionArchived { election_id: 0, archive_id: 0 }.into());
+
+		let election = Election::<Test>::get(0).unwrap();
+		assert_eq!(election.status, ElectionStatus::Cancelled);
+		assert_eq!(election.deposit, None);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(CandidateOwners::<Test>::iter_prefix(0).count(), 0);
+		let summary = PastElections::<Test>::get(0).unwrap();
+		assert!(summary.winners.is_empty());
+		assert_eq!(summary.total_votes, 1);
+		assert_eq!(summary.cancellation_reason.unwrap().to_vec(), b"Fraud".to_vec());
+
+		assert_noop!(
+			Voting::cast_vote(RuntimeOrigin::signed(4), 0, alice),
+			Error::<Test>::ElectionCancelled
+		);
+		assert_noop!(
+			Voting::start_election(RuntimeOrigin::root(), 0),
+			Error::<Test>::ElectionCancelled
+		);
+		assert_noop!(
+			Voting::finalize_election(RuntimeOrigin::root(), 0, 0),
+			Error::<Test>::ElectionCancelled
+		);
+		assert_noop!(
+			Voting::cancel_election(RuntimeOrigin::root(), 0, b"Fraud".to_vec()),
+			Error::<Test>::ElectionCancelled
+		);
+		assert_ok!(Voting::reset_election(RuntimeOrigin::root(), 0));
+		assert_eq!(Balances::free_balance(2), 20);
+	});
+}
+
+#[test]
+fn finalized_elections_cannot_be_cancelled() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		add_candidate(election_id, b"Alice");
+		start_election(election_id);
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), election_id));
+		assert_ok!(Voting::finalize_election(RuntimeOrigin::root(), election_id, 0));
+
+		assert_noop!(
+			Voting::cancel_election(RuntimeOrigin::root(), election_id, b"Fraud".to_vec()),
+			Error::<Test>::AlreadyFinalized
+		);
+		assert_eq!(PastElections::<Test>::get(0).unwrap().cancellation_reason, None);
+	});
+}
+
+#[test]
+fn time_based_election_follows_the_clock() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Voting::create_election(
+			RuntimeOrigin::root(),
+			b"Council".to_vec(),
+			VotingWindow::Time { start: 1_000, end: 2_000 },
+			ElectionKind::Plurality,
+			false,
+			None,
+			1,
+			false,
+			false,
+			None,
+			None
+		));
+		let alice = add_candidate(0, b"Alice");
+		assert_ok!(Voting::lock_candidates(RuntimeOrigin::root(), 0));
+
+		// Block numbers are ignored, only the clock counts.
+		System::set_block_number(100);
+		Voting::on_initialize(100);
+		assert_eq!(Election::<Test>::get(0).unwrap().status, ElectionStatus::Created);
+
+		Now::set(1_000);
+		Voting::on_initialize(101);
+		assert_eq!(Election::<Test>::get(0).unwrap().status, ElectionStatus::Active);
+		System::assert_last_event(Event::ElectionStarted { election_id: 0, block: 101 }.into());
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(1), 0, alice));
+
+		Now::set(2_000);
+		assert_noop!(
+			Voting::cast_vote(RuntimeOrigin::signed(2), 0, alice),
+			Error::<Test>::VotingEnded
+		);
+		Voting::on_initialize(102);
+		assert_eq!(Election::<Test>::get(0).unwrap().status, ElectionStatus::Ended);
+		System::assert_last_event(Event::ElectionEnded { election_id: 0, block: 102 }.into());
+	});
+}
+
+#[test]
+fn time_based_windows_are_validated() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Voting::create_election(
+				RuntimeOrigin::root(),
+				b"Council".to_vec(),
+				VotingWindow::Time { start: 2_000, end: 2_000 },
+				ElectionKind::Plurality,
+				false,
+				None,
+				1,
+				false,
+				false,
+				None,
+				None
+			),
+			Error::<Test>::InvalidTimeRange
+		);
+		assert_noop!(
+			Voting::create_election(
+				RuntimeOrigin::root(),
+				b"Council".to_vec(),
+				VotingWindow::Time { start: 1_000, end: 2_000 },
+				ElectionKind::Plurality,
+				false,
+				Some(20),
+				1,
+				false,
+				false,
+				None,
+				None
+			),
+			Error::<Test>::BlockWindowRequired
+		);
+
+		assert_ok!(Voting::create_election(
+			RuntimeOrigin::root(),
+			b"Council".to_vec(),
+			VotingWindow::Time { start: 1_000, end: 2_000 },
+			ElectionKind::Plurality,
+			false,
+			None,
+			1,
+			false,
+			false,
+			None,
+			None
+		));
+		add_candidate(0, b"Alice");
+		start_election(0);
+		assert_noop!(
+			Voting::extend_election(RuntimeOrigin::root(), 0, 20),
+			Error::<Test>::BlockWindowRequired
+		);
+	});
+}
+
+#[test]
+fn extend_election_moves_the_end_of_voting() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		let alice = add_candidate(election_id, b"Alice");
+		assert_noop!(
+			Voting::extend_election(RuntimeOrigin::root(), election_id, 20),
+			Error::<Test>::ElectionNotActive
+		);
+		start_election(election_id);
+
+		for new_end_block in [5, 10] {
+			assert_noop!(
+				Voting::extend_election(RuntimeOrigin::root(), election_id, new_end_block),
+				Error::<Test>::InvalidTimeRange
+			);
+		}
+		assert_noop!(
+			Voting::extend_election(RuntimeOrigin::signed(1), election_id, 20),
+			Error::<Test>::NotAdmin
+		);
+		assert_ok!(Voting::extend_election(RuntimeOrigin::root(), election_id, 20));
+		System::assert_last_event(
+			Event::ElectionExtended { election_id, new_end_block: 20 }.into(),
+		);
+
+		System::set_block_number(10);
+		Voting::on_initialize(10);
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(1), election_id, alice));
+
+		System::set_block_number(20);
+		Voting::on_initialize(20);
+		assert_eq!(Election::<Test>::get(election_id).unwrap().status, ElectionStatus::Ended);
+	});
+}
+
+#[test]
+fn extend_election_fails_for_finalized_and_commit_reveal_elections() {
+	new_test_ext().execute_with(|| {
+		let election_id = run_election(&[b"Alice"], &[1]);
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), election_id));
+		assert_ok!(Voting::finalize_election(RuntimeOrigin::root(), election_id, 0));
+		assert_noop!(
+			Voting::extend_election(RuntimeOrigin::root(), election_id, 20),
+			Error::<Test>::AlreadyFinalized
+		);
+
+		let election_id = create_commit_reveal_election(1, 10, 20);
+		add_candidate(election_id, b"Alice");
+		start_election(election_id);
+		assert_noop!(
+			Voting::extend_election(RuntimeOrigin::root(), election_id, 20),
+			Error::<Test>::InvalidTimeRange
+		);
+		assert_ok!(Voting::extend_election(RuntimeOrigin::root(), election_id, 19));
+	});
+}
+
+#[cfg(feature = "scheduler")]
+#[test]
+fn finalization_is_scheduled_after_the_voting_window() {
+	use sp_runtime::traits::Dispatchable;
+
+	new_test_ext().execute_with(|| {
+		FinalizationDelay::set(Some(5));
+		let commit_reveal = create_commit_reveal_election(1, 10, 20);
+		System::assert_last_event(
+			Event::FinalizationScheduled { election_id: commit_reveal, at: 25 }.into(),
+		);
+		assert_ok!(Voting::cancel_election(RuntimeOrigin::root(), commit_reveal, b"Typo".to_vec()));
+		assert!(ScheduledTasks::get().is_empty());
+
+		let election_id = run_election(&[b"Alice"], &[1]);
+		System::assert_has_event(Event::FinalizationScheduled { election_id, at: 15 }.into());
+		assert_ok!(Voting::extend_election(RuntimeOrigin::root(), election_id, 20));
+		System::assert_last_event(Event::FinalizationScheduled { election_id, at: 25 }.into());
+		let [(_, at, call)] = &ScheduledTasks::get()[..] else { panic!("one task is scheduled") };
+		assert_eq!(*at, 25);
+
+		System::set_block_number(25);
+		Voting::on_initialize(25);
+		assert_ok!(call.clone().dispatch(RuntimeOrigin::root()));
+		assert_eq!(Election::<Test>::get(election_id).unwrap().status, ElectionStatus::Finalized);
+	});
+}
+
+#[cfg(feature = "scheduler")]
+#[test]
+fn scheduled_finalizations_are_bounded_by_the_ranked_ballots_once_voting_ends() {
+	let scheduled_voters = |election_id| {
+		ScheduledTasks::get()
+			.into_iter()
+			.find_map(|(_, _, call)| match call {
+				RuntimeCall::Voting(crate::Call::finalize_election { election_id: id, voters })
+					if id == election_id =>
+					Some(voters),
+				_ => None,
+			})
+			.unwrap()
+	};
+
+	new_test_ext().execute_with(|| {
+		FinalizationDelay::set(Some(5));
+		let ranked = create_election_of_kind(1, 10, ElectionKind::RankedChoice);
+		let alice = add_candidate(ranked, b"Alice");
+		let bob = add_candidate(ranked, b"Bob");
+		start_election(ranked);
+		let plurality = create_election(1, 20);
+		add_candidate(plurality, b"Carol");
+		start_election(plurality);
+		assert_eq!(scheduled_voters(ranked), MaxVoters::get());
+		assert_eq!(scheduled_voters(plurality), MaxVoters::get());
+
+		assert_ok!(Voting::cast_ranked_vote(RuntimeOrigin::signed(1), ranked, vec![alice, bob]));
+		assert_ok!(Voting::cast_ranked_vote(RuntimeOrigin::signed(2), ranked, vec![bob]));
+		assert_ok!(Voting::cast_ranked_vote(RuntimeOrigin::signed(3), ranked, vec![bob]));
+		assert_ok!(Voting::retract_vote(RuntimeOrigin::signed(3), ranked));
+		assert_eq!(RankedBallotCount::<Test>::get(ranked), 2);
+
+		System::set_block_number(10);
+		Voting::on_initialize(10);
+		assert_eq!(scheduled_voters(ranked), 2);
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), plurality));
+		assert_eq!(scheduled_voters(plurality), 0);
+		// The finalizations stay due at the end of the voting windows.
+		assert_eq!(
+			ScheduledTasks::get().into_iter().map(|(_, at, _)| at).collect::<Vec<_>>(),
+			vec![15, 25]
+		);
+	});
+}
+
+#[test]
+fn paused_elections_reject_votes_until_resumed() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		let alice = add_candidate(election_id, b"Alice");
+		assert_noop!(
+			Voting::pause_election(RuntimeOrigin::root(), election_id),
+			Error::<Test>::ElectionNotActive
+		);
+		start_election(election_id);
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(1), election_id, alice));
+
+		assert_ok!(Voting::pause_election(RuntimeOrigin::root(), election_id));
+		System::assert_last_event(Event::ElectionPaused { election_id }.into());
+		assert_noop!(
+			Voting::pause_election(RuntimeOrigin::root(), election_id),
+			Error::<Test>::ElectionPaused
+		);
+		assert_eq!(Election::<Test>::get(election_id).unwrap().status, ElectionStatus::Paused);
+		assert_noop!(
+			Voting::cast_vote(RuntimeOrigin::signed(2), election_id, alice),
+			Error::<Test>::ElectionPaused
+		);
+		assert_noop!(
+			Voting::retract_vote(RuntimeOrigin::signed(1), election_id),
+			Error::<Test>::ElectionPaused
+		);
+
+		assert_ok!(Voting::resume_election(RuntimeOrigin::root(), election_id));
+		System::assert_last_event(Event::ElectionResumed { election_id }.into());
+		assert_noop!(
+			Voting::resume_election(RuntimeOrigin::root(), election_id),
+			Error::<Test>::ElectionNotPaused
+		);
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(2), election_id, alice));
+	});
+}
+
+#[test]
+fn ending_an_election_lifts_the_pause() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		add_candidate(election_id, b"Alice");
+		start_election(election_id);
+		assert_ok!(Voting::pause_election(RuntimeOrigin::root(), election_id));
+
+		System::set_block_number(10);
+		Voting::on_initialize(10);
+		assert_eq!(Election::<Test>::get(election_id).unwrap().status, ElectionStatus::Ended);
+	});
+}
+
+#[test]
+fn elections_only_move_through_valid_status_transitions() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		add_candidate(election_id, b"Alice");
+		let root = || RuntimeOrigin::root();
+		assert_noop!(Voting::end_election(root(), election_id), Error::<Test>::ElectionNotActive);
+		assert_noop!(Voting::pause_election(root(), election_id), Error::<Test>::ElectionNotActive);
+		assert_noop!(
+			Voting::resume_election(root(), election_id),
+			Error::<Test>::ElectionNotPaused
+		);
+		assert_noop!(
+			Voting::reopen_election(root(), election_id),
+			Error::<Test>::ElectionNotFinalized
+		);
+		assert_noop!(
+			Voting::finalize_election(root(), election_id, 0),
+			Error::<Test>::ElectionNotStarted
+		);
+
+		start_election(election_id);
+		assert_noop!(Voting::start_election(root(), election_id), Error::<Test>::ElectionIsActive);
+		assert_ok!(Voting::end_election(root(), election_id));
+		assert_eq!(Election::<Test>::get(election_id).unwrap().status, ElectionStatus::Ended);
+		assert_noop!(Voting::pause_election(root(), election_id), Error::<Test>::ElectionNotActive);
+		assert_noop!(Voting::start_election(root(), election_id), Error::<Test>::VotingEnded);
+
+		assert_ok!(Voting::finalize_election(root(), election_id, 0));
+		assert_noop!(Voting::start_election(root(), election_id), Error::<Test>::AlreadyFinalized);
+		assert_noop!(Voting::end_election(root(), election_id), Error::<Test>::ElectionNotActive);
+		assert_ok!(Voting::reopen_election(root(), election_id));
+		assert_eq!(Election::<Test>::get(election_id).unwrap().status, ElectionStatus::Ended);
+
+		assert_ok!(Voting::cancel_election(root(), election_id, b"Fraud".to_vec()));
+		assert_noop!(
+			Voting::cancel_election(root(), election_id, b"Fraud".to_vec()),
+			Error::<Test>::ElectionCancelled
+		);
+		assert_noop!(Voting::start_election(root(), election_id), Error::<Test>::ElectionCancelled);
+	});
+}
+
+/// Create and start an election with the given candidates, and have account `i + 1` vote for
+/// `votes[i]`.
+fn run_election(candidates: &[&[u8]], votes: &[u32]) -> u32 {
+	let election_id = create_election(1, 10);
+	for name in candidates {
+		add_candidate(election_id, name);
+	}
+	start_election(election_id);
+	for (voter, candidate_id) in votes.iter().enumerate() {
+		assert_ok!(Voting::cast_vote(
+			RuntimeOrigin::signed(voter as u64 + 1),
+			election_id,
+			*candidate_id
+		));
+	}
+	election_id
+}
+
+#[test]
+fn winners_have_to_reach_the_win_threshold() {
+	new_test_ext().execute_with(|| {
+		let majority = Some(Permill::from_parts(500_001));
+		let election_id = create_election(1, 10);
+		for name in [&b"Alice"[..], b"Bob", b"Carol"] {
+			add_candidate(election_id, name);
+		}
+		assert_noop!(
+			Voting::set_win_threshold(RuntimeOrigin::signed(1), election_id, majority),
+			Error::<Test>::NotAdmin
+		);
+		assert_ok!(Voting::set_win_threshold(RuntimeOrigin::root(), election_id, majority));
+		System::assert_last_event(
+			Event::WinThresholdChanged { election_id, threshold: majority }.into(),
+		);
+		start_election(election_id);
+		assert_noop!(
+			Voting::set_win_threshold(RuntimeOrigin::root(), election_id, None),
+			Error::<Test>::ElectionIsActive
+		);
+		for (voter, candidate_id) in [(1, 1), (2, 1), (3, 2), (4, 3)] {
+			assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(voter), election_id, candidate_id));
+		}
+
+		// Alice leads with exactly half of the votes, which is not a majority.
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), election_id));
+		assert_ok!(Voting::finalize_election(RuntimeOrigin::root(), election_id, 0));
+		System::assert_has_event(
+			Event::ThresholdNotMet {
+				election_id,
+				top_candidate: 1,
+				share: Permill::from_percent(50),
+			}
+			.into(),
+		);
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::Voting(Event::WinnerDeclared { .. })
+		)));
+		assert!(PastElections::<Test>::get(0).unwrap().winners.is_empty());
+
+		// Thresholds only apply to the single winner of an election.
+		let board = NextElectionId::<Test>::get();
+		assert_ok!(Voting::create_election(
+			RuntimeOrigin::root(),
+			b"Board".to_vec(),
+			blocks(1, 10),
+			ElectionKind::Plurality,
+			false,
+			None,
+			2,
+			false,
+			false,
+			None,
+			None
+		));
+		assert_noop!(
+			Voting::set_win_threshold(RuntimeOrigin::root(), board, majority),
+			Error::<Test>::ThresholdRequiresSingleSeat
+		);
+	});
+}
+
+#[test]
+fn disputes_hold_back_finalization() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		add_candidate(election_id, b"Alice");
+		assert_noop!(
+			Voting::set_dispute_window(RuntimeOrigin::signed(1), election_id, 5),
+			Error::<Test>::NotAdmin
+		);
+		assert_ok!(Voting::set_dispute_window(RuntimeOrigin::root(), election_id, 5));
+		System::assert_last_event(
+			Event::DisputeWindowChanged { election_id, dispute_window: 5 }.into(),
+		);
+		start_election(election_id);
+		assert_noop!(
+			Voting::set_dispute_window(RuntimeOrigin::root(), election_id, 0),
+			Error::<Test>::ElectionIsActive
+		);
+		assert_noop!(
+			Voting::flag_dispute(RuntimeOrigin::root(), election_id, b"Recount".to_vec()),
+			Error::<Test>::ElectionIsActive
+		);
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(1), election_id, 1));
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), election_id));
+
+		// The window runs until block 15, five blocks after the end of the voting window.
+		assert_noop!(
+			Voting::finalize_election(RuntimeOrigin::root(), election_id, 0),
+			Error::<Test>::DisputeWindowOpen
+		);
+		assert_noop!(
+			Voting::flag_dispute(RuntimeOrigin::signed(1), election_id, b"Recount".to_vec()),
+			Error::<Test>::NotAdmin
+		);
+		assert_noop!(
+			Voting::flag_dispute(RuntimeOrigin::root(), election_id, vec![b'x'; 33]),
+			Error::<Test>::ReasonTooLong
+		);
+		assert_noop!(
+			Voting::resolve_dispute(RuntimeOrigin::root(), election_id),
+			Error::<Test>::NotDisputed
+		);
+		assert_ok!(Voting::flag_dispute(RuntimeOrigin::root(), election_id, b"Recount".to_vec()));
+		System::assert_last_event(
+			Event::DisputeFlagged { election_id, reason: b"Recount".to_vec() }.into(),
+		);
+		assert_noop!(
+			Voting::flag_dispute(RuntimeOrigin::root(), election_id, b"Again".to_vec()),
+			Error::<Test>::ElectionDisputed
+		);
+
+		// A dispute blocks finalization after the window, even when forced.
+		System::set_block_number(15);
+		assert_noop!(
+			Voting::flag_dispute(RuntimeOrigin::root(), election_id, b"Again".to_vec()),
+			Error::<Test>::DisputeWindowClosed
+		);
+		assert_noop!(
+			Voting::finalize_election(RuntimeOrigin::root(), election_id, 0),
+			Error::<Test>::ElectionDisputed
+		);
+		assert_noop!(
+			Voting::force_finalize(RuntimeOrigin::root(), election_id, 0),
+			Error::<Test>::ElectionDisputed
+		);
+		assert_noop!(
+			Voting::resolve_dispute(RuntimeOrigin::signed(1), election_id),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(Voting::resolve_dispute(RuntimeOrigin::root(), election_id));
+		System::assert_last_event(Event::DisputeResolved { election_id }.into());
+		assert!(!Disputes::<Test>::contains_key(election_id));
+		assert_ok!(Voting::finalize_election(RuntimeOrigin::root(), election_id, 0));
+
+		// Elections without a dispute window cannot be disputed.
+		let election_id = create_election(20, 30);
+		add_candidate(election_id, b"Bob");
+		System::set_block_number(20);
+		start_election(election_id);
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), election_id));
+		assert_noop!(
+			Voting::flag_dispute(RuntimeOrigin::root(), election_id, b"Recount".to_vec()),
+			Error::<Test>::DisputeWindowClosed
+		);
+		assert_ok!(Voting::finalize_election(RuntimeOrigin::root(), election_id, 0));
+	});
+}
+
+#[test]
+fn get_winner_reports_winner_tie_and_no_votes() {
+	new_test_ext().execute_with(|| {
+		let election_id = run_election(&[b"Alice", b"Bob"], &[]);
+		assert_eq!(Voting::get_winner(election_id), WinnerResult::NoVotes);
+
+		let election_id = run_election(&[b"Alice", b"Bob"], &[2, 1, 2]);
+		assert_eq!(Voting::get_winner(election_id), WinnerResult::Winner(2, 2));
+
+		let election_id = run_election(&[b"Alice", b"Bob", b"Carol"], &[3, 1, 2, 3, 1]);
+		assert_eq!(Voting::get_winner(election_id), WinnerResult::Tie(vec![1, 3]));
+	});
+}
+
+#[test]
+fn results_with_percentage_shares_the_total_votes() {
+	new_test_ext().execute_with(|| {
+		let election_id = run_election(&[b"Alice", b"Bob"], &[]);
+		assert!(Voting::results_with_percentage(election_id)
+			.iter()
+			.all(|(_, _, _, share)| share.is_zero()));
+
+		let election_id = run_election(&[b"Alice", b"Bob"], &[1, 2, ABSTAIN]);
+		let results = Voting::results_with_percentage(election_id);
+		assert_eq!(
+			results,
+			vec![
+				(1, b"Alice".to_vec(), 1, Permill::from_parts(333_333)),
+				(2, b"Bob".to_vec(), 1, Permill::from_parts(333_333)),
+				(ABSTAIN, b"Abstain".to_vec(), 1, Permill::from_parts(333_333)),
+			]
+		);
+		let sum: u32 = results.iter().map(|(_, _, _, share)| share.deconstruct()).sum();
+		assert!(sum <= Permill::one().deconstruct());
+	});
+}
+
+#[test]
+fn get_results_ordered_sorts_the_results() {
+	new_test_ext().execute_with(|| {
+		let election_id = run_election(&[b"Alice", b"Bob", b"Carol"], &[3, 2, 3, ABSTAIN, 1, 3]);
+		let alice = (1, b"Alice".to_vec(), 1);
+		let bob = (2, b"Bob".to_vec(), 1);
+		let carol = (3, b"Carol".to_vec(), 3);
+		let abstain = (ABSTAIN, b"Abstain".to_vec(), 1);
+
+		assert_eq!(
+			Voting::get_results_ordered(election_id, ResultOrder::Insertion),
+			vec![alice.clone(), bob.clone(), carol.clone(), abstain.clone()]
+		);
+		assert_eq!(
+			Voting::get_results_ordered(election_id, ResultOrder::ById),
+			vec![abstain.clone(), alice.clone(), bob.clone(), carol.clone()]
+		);
+		// Ties keep their insertion order.
+		assert_eq!(
+			Voting::get_results_ordered(election_id, ResultOrder::ByVotesDesc),
+			vec![carol, alice, bob, abstain]
+		);
+	});
+}
+
+#[test]
+fn vote_log_records_the_block_of_every_vote() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		let alice = add_candidate(election_id, b"Alice");
+		let bob = add_candidate(election_id, b"Bob");
+		start_election(election_id);
+
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(1), election_id, alice));
+		System::set_block_number(3);
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(2), election_id, alice));
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(3), election_id, ABSTAIN));
+		System::set_block_number(4);
+		assert_ok!(Voting::change_vote(RuntimeOrigin::signed(3), election_id, bob));
+		assert_eq!(VoteLog::<Test>::get(election_id, alice).to_vec(), vec![1, 3]);
+		assert_eq!(VoteLog::<Test>::get(election_id, bob).to_vec(), vec![4]);
+
+		EnableVoteLog::set(false);
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(4), election_id, bob));
+		assert_eq!(VoteLog::<Test>::get(election_id, bob).to_vec(), vec![4]);
+
+		confirmed_reset(election_id);
+		assert!(VoteLog::<Test>::get(election_id, alice).is_empty());
+	});
+}
+
+#[test]
+fn turnout_divides_the_voters_by_the_registered_voters() {
+	new_test_ext().execute_with(|| {
+		let election_id = run_election(&[b"Alice"], &[1, ABSTAIN]);
+		assert_eq!(Voting::turnout(election_id), Permill::zero());
+
+		for who in 1..=4 {
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), who));
+		}
+		assert_eq!(Voting::turnout(election_id), Permill::from_percent(50));
+		assert_ok!(Voting::deregister_voter(RuntimeOrigin::root(), 4));
+		assert_eq!(Voting::turnout(election_id), Permill::from_parts(666_666));
+	});
+}
+
+#[test]
+fn turnout_counts_every_ballot_once_whatever_its_weight() {
+	new_test_ext().execute_with(|| {
+		for who in 1..=4 {
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), who));
+		}
+		let election_id = create_election_with(1, 10, ElectionKind::Plurality, true);
+		let alice = add_candidate(election_id, b"Alice");
+		start_election(election_id);
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(2), election_id, alice));
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(3), election_id, alice));
+		assert_eq!(TotalVotes::<Test>::get(election_id), 50);
+		assert_eq!(BallotCount::<Test>::get(election_id), 2);
+		assert_eq!(Voting::turnout(election_id), Permill::from_percent(50));
+
+		assert_ok!(Voting::retract_vote(RuntimeOrigin::signed(3), election_id));
+		assert_eq!(Voting::turnout(election_id), Permill::from_percent(25));
+	});
+}
+
+#[test]
+fn candidate_shares_leave_out_the_abstentions() {
+	new_test_ext().execute_with(|| {
+		let election_id = run_election(&[b"Alice", b"Bob"], &[ABSTAIN, ABSTAIN]);
+		assert_eq!(Voting::candidate_share_of_decided(election_id, 1), Permill::zero());
+
+		for voter in 3..=5 {
+			let candidate_id = if voter == 5 { 2 } else { 1 };
+			assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(voter), election_id, candidate_id));
+		}
+		// Two of the three decided votes went to Alice, although she has only two of five votes.
+		assert_eq!(
+			Voting::candidate_share_of_decided(election_id, 1),
+			Permill::from_parts(666_666)
+		);
+		assert_eq!(
+			Voting::candidate_share_of_decided(election_id, 2),
+			Permill::from_parts(333_333)
+		);
+		assert_eq!(Voting::candidate_share_of_decided(election_id, 7), Permill::zero());
+	});
+}
+
+#[test]
+fn is_voting_open_follows_the_status_and_the_window() {
+	new_test_ext().execute_with(|| {
+		assert!(!Voting::is_voting_open(0));
+		let election_id = create_election(2, 10);
+		add_candidate(election_id, b"Alice");
+		assert!(!Voting::is_voting_open(election_id));
+		start_election(election_id);
+		assert!(!Voting::is_voting_open(election_id));
+
+		System::set_block_number(2);
+		assert!(Voting::is_voting_open(election_id));
+		assert_ok!(Voting::pause_election(RuntimeOrigin::root(), election_id));
+		assert!(!Voting::is_voting_open(election_id));
+		assert_ok!(Voting::resume_election(RuntimeOrigin::root(), election_id));
+		assert!(Voting::is_voting_open(election_id));
+
+		System::set_block_number(10);
+		assert!(!Voting::is_voting_open(election_id));
+	});
+}
+
+#[test]
+fn export_results_bundles_the_results_and_status() {
+	new_test_ext().execute_with(|| {
+		assert!(Voting::export_results(0).is_none());
+		let pending = create_election(1, 10);
+		add_candidate(pending, b"Alice");
+		assert_eq!(Voting::export_results(pending).unwrap().status, ElectionStatus::Created);
+
+		let election_id = run_election(&[b"Alice", b"Bob"], &[2, 2, ABSTAIN]);
+		let export = Voting::export_results(election_id).unwrap();
+		assert_eq!(export.title.to_vec(), b"Council".to_vec());
+		assert_eq!(
+			export
+				.candidates
+				.iter()
+				.map(|(id, name, votes)| (*id, name.to_vec(), *votes))
+				.collect::<Vec<_>>(),
+			vec![(1, b"Alice".to_vec(), 0), (2, b"Bob".to_vec(), 2)]
+		);
+		assert_eq!((export.total_votes, export.abstentions), (3, 1));
+		assert_eq!(export.status, ElectionStatus::Active);
+
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), election_id));
+		assert_eq!(Voting::export_results(election_id).unwrap().status, ElectionStatus::Ended);
+		assert_ok!(Voting::finalize_election(RuntimeOrigin::root(), election_id, 0));
+		let export = Voting::export_results(election_id).unwrap();
+		assert_eq!(export.status, ElectionStatus::Finalized);
+		assert_eq!(export.total_votes, 3);
+
+		assert_ok!(Voting::cancel_election(RuntimeOrigin::root(), pending, b"Fraud".to_vec()));
+		assert_eq!(Voting::export_results(pending).unwrap().status, ElectionStatus::Cancelled);
+	});
+}
+
+#[test]
+fn election_snapshot_bundles_the_state_of_an_election() {
+	new_test_ext().execute_with(|| {
+		assert!(Voting::election_snapshot(0).is_none());
+		let election_id = create_election(2, 10);
+		let alice = add_candidate(election_id, b"Alice");
+		add_candidate(election_id, b"Bob");
+		let snapshot = Voting::election_snapshot(election_id).unwrap();
+		assert_eq!(snapshot.title.to_vec(), b"Council".to_vec());
+		assert_eq!(snapshot.status, ElectionStatus::Created);
+		assert_eq!((snapshot.start_block, snapshot.end_block), (Some(2), Some(10)));
+		assert_eq!(snapshot.candidate_count, 2);
+		assert!(!snapshot.within_window);
+
+		System::set_block_number(2);
+		start_election(election_id);
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(1), election_id, alice));
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(2), election_id, ABSTAIN));
+		let snapshot = Voting::election_snapshot(election_id).unwrap();
+		assert_eq!(snapshot.status, ElectionStatus::Active);
+		assert_eq!(snapshot.total_votes, 2);
+		assert!(snapshot.within_window);
+
+		System::set_block_number(10);
+		assert!(!Voting::election_snapshot(election_id).unwrap().within_window);
+	});
+}
+
+#[test]
+fn finalize_election_declares_winner() {
+	new_test_ext().execute_with(|| {
+		let election_id = run_election(&[b"Alice", b"Bob"], &[2, 2, 1]);
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), election_id));
+		assert_ok!(Voting::finalize_election(RuntimeOrigin::root(), election_id, 0));
+		System::assert_has_event(Event::ElectionFinalized { election_id, total_votes: 3 }.into());
+		System::assert_has_event(
+			Event::WinnerDeclared { election_id, candidate_id: 2, votes: 2 }.into(),
+		);
+	});
+}
+
+#[test]
+fn reopened_elections_can_be_finalized_again() {
+	new_test_ext().execute_with(|| {
+		let election_id = run_election(&[b"Alice", b"Bob"], &[2, 2, 1]);
+		assert_noop!(
+			Voting::reopen_election(RuntimeOrigin::root(), election_id),
+			Error::<Test>::ElectionNotFinalized
+		);
+		assert_noop!(
+			Voting::reopen_election(RuntimeOrigin::root(), 7),
+			Error::<Test>::NoElectionExists
+		);
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), election_id));
+		assert_ok!(Voting::finalize_election(RuntimeOrigin::root(), election_id, 0));
+		assert!(Voting::reopen_election(RuntimeOrigin::signed(1), election_id).is_err());
+
+		assert_ok!(Voting::reopen_election(RuntimeOrigin::root(), election_id));
+		System::assert_last_event(Event::ElectionReopened { election_id, reopen_count: 1 }.into());
+		let election = Election::<Test>::get(election_id).unwrap();
+		assert_eq!(election.status, ElectionStatus::Ended);
+		assert_eq!(election.reopen_count, 1);
+		// Reopened elections are recounted, not voted on again.
+		assert_noop!(
+			Voting::start_election(RuntimeOrigin::root(), election_id),
+			Error::<Test>::VotingEnded
+		);
+
+		// The votes are kept, so the recount declares the same winner.
+		assert_ok!(Voting::finalize_election(RuntimeOrigin::root(), election_id, 0));
+		System::assert_has_event(
+			Event::WinnerDeclared { election_id, candidate_id: 2, votes: 2 }.into(),
+		);
+		System::assert_has_event(Event::ElectionArchived { election_id, archive_id: 1 }.into());
+		assert_ok!(Voting::reopen_election(RuntimeOrigin::root(), election_id));
+		assert_eq!(Election::<Test>::get(election_id).unwrap().reopen_count, 2);
+	});
+}
+
+#[test]
+fn vote_counts_can_be_adjusted_between_the_end_and_finalization() {
+	new_test_ext().execute_with(|| {
+		let election_id = run_election(&[b"Alice", b"Bob"], &[2, 2, 1]);
+		assert_noop!(
+			Voting::adjust_vote_count(RuntimeOrigin::root(), election_id, 1, 1),
+			Error::<Test>::ElectionIsActive
+		);
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), election_id));
+		assert!(Voting::adjust_vote_count(RuntimeOrigin::signed(1), election_id, 1, 1).is_err());
+		assert_noop!(
+			Voting::adjust_vote_count(RuntimeOrigin::root(), election_id, 7, 1),
+			Error::<Test>::InvalidCandidate
+		);
+
+		assert_ok!(Voting::adjust_vote_count(RuntimeOrigin::root(), election_id, 1, 2));
+		System::assert_last_event(
+			Event::VoteCountAdjusted { election_id, candidate_id: 1, delta: 2 }.into(),
+		);
+		assert_ok!(Voting::adjust_vote_count(RuntimeOrigin::root(), election_id, 2, -1));
+		assert_eq!(VoteCount::<Test>::get(election_id, 1), 3);
+		assert_eq!(VoteCount::<Test>::get(election_id, 2), 1);
+		assert_eq!(TotalVotes::<Test>::get(election_id), 4);
+
+		assert_ok!(Voting::finalize_election(RuntimeOrigin::root(), election_id, 0));
+		assert_noop!(
+			Voting::adjust_vote_count(RuntimeOrigin::root(), election_id, 1, 1),
+			Error::<Test>::AlreadyFinalized
+		);
+	});
+}
+
+#[test]
+fn adjusting_below_zero_only_removes_the_votes_of_the_candidate() {
+	new_test_ext().execute_with(|| {
+		let election_id = run_election(&[b"Alice", b"Bob"], &[2, 2, 1]);
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), election_id));
+
+		assert_ok!(Voting::adjust_vote_count(RuntimeOrigin::root(), election_id, 2, -5));
+		assert_eq!(VoteCount::<Test>::get(election_id, 1), 1);
+		assert_eq!(VoteCount::<Test>::get(election_id, 2), 0);
+		assert_eq!(TotalVotes::<Test>::get(election_id), 1);
+		assert_ok!(Voting::do_try_state());
+	});
+}
+
+#[test]
+fn finalize_election_reports_tie() {
+	new_test_ext().execute_with(|| {
+		let election_id = run_election(&[b"Alice", b"Bob"], &[2, 1]);
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), election_id));
+		assert_ok!(Voting::finalize_election(RuntimeOrigin::root(), election_id, 0));
+		System::assert_has_event(
+			Event::ElectionTied { election_id, candidate_ids: vec![1, 2] }.into(),
+		);
+	});
+}
+
+#[test]
+fn finalized_elections_are_archived_and_survive_a_reset() {
+	new_test_ext().execute_with(|| {
+		let first = run_election(&[b"Alice", b"Bob"], &[2, ABSTAIN, 2]);
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), first));
+		assert_ok!(Voting::finalize_election(RuntimeOrigin::root(), first, 0));
+		System::assert_has_event(
+			Event::ElectionArchived { election_id: first, archive_id: 0 }.into(),
+		);
+		System::assert_last_event(
+			Event::ElectionFinalizedDetailed {
+				election_id: first,
+				title: b"Council".to_vec(),
+				results: vec![(1, 0), (2, 2)],
+				total_votes: 3,
+				winner: Some(2),
+			}
+			.into(),
+		);
+
+		let second = run_election(&[b"Alice", b"Bob"], &[2, 1]);
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), second));
+		assert_ok!(Voting::finalize_election(RuntimeOrigin::root(), second, 0));
+		assert_ok!(Voting::reset_election(RuntimeOrigin::root(), first));
+		assert!(Election::<Test>::get(first).is_none());
+
+		let summary = PastElections::<Test>::get(0).unwrap();
+		assert_eq!(summary.election_id, first);
+		assert_eq!(summary.title.to_vec(), b"Council".to_vec());
+		assert_eq!(summary.winners.to_vec(), vec![2]);
+		assert_eq!(summary.total_votes, 3);
+		assert_eq!(summary.vote_counts.to_vec(), vec![(1, 0), (2, 2)]);
+		assert_eq!(summary.abstentions, 1);
+
+		// The tie of the second election is not broken, so it has no winner.
+		let history = Voting::past_elections(1, 10);
+		assert_eq!(history.len(), 1);
+		assert_eq!(history[0].0, 1);
+		assert!(history[0].1.winners.is_empty());
+		assert_eq!(Voting::past_elections(0, 10).len(), 2);
+		assert!(Voting::past_elections(2, 10).is_empty());
+	});
+}
+
+#[test]
+fn finalize_election_breaks_ties_randomly_when_enabled() {
+	new_test_ext().execute_with(|| {
+		RandomTieBreaking::set(true);
+		let first = run_election(&[b"Alice", b"Bob"], &[2, 1]);
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), first));
+		assert_ok!(Voting::finalize_election(RuntimeOrigin::root(), first, 0));
+		System::assert_has_event(
+			Event::TieBrokenRandomly { election_id: first, winner: 1, tied: vec![1, 2] }.into(),
+		);
+
+		// A different random seed picks a different candidate.
+		RandomSeed::set(1);
+		let second = run_election(&[b"Alice", b"Bob"], &[2, 1]);
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), second));
+		assert_ok!(Voting::finalize_election(RuntimeOrigin::root(), second, 0));
+		System::assert_has_event(
+			Event::TieBrokenRandomly { election_id: second, winner: 2, tied: vec![1, 2] }.into(),
+		);
+	});
+}
+
+#[test]
+fn multi_seat_elections_declare_the_top_candidates() {
+	new_test_ext().execute_with(|| {
+		let election_id = NextElectionId::<Test>::get();
+		assert_ok!(Voting::create_election(
+			RuntimeOrigin::root(),
+			b"Committee".to_vec(),
+			blocks(1, 10),
+			ElectionKind::Plurality,
+			false,
+			None,
+			2,
+			false,
+			false,
+			None,
+			None
+		));
+		for name in [&b"Alice"[..], b"Bob", b"Carol"] {
+			add_candidate(election_id, name);
+		}
+		start_election(election_id);
+		// Bob leads, and Alice and Carol tie for the second seat.
+		for (voter, candidate_id) in [(1, 2), (2, 2), (3, 3), (4, 1)] {
+			assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(voter), election_id, candidate_id));
+		}
+		assert_eq!(Voting::get_top_winners(election_id), vec![(2, 2), (1, 1)]);
+
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), election_id));
+		assert_ok!(Voting::finalize_election(RuntimeOrigin::root(), election_id, 0));
+		System::assert_has_event(
+			Event::WinnersDeclared { election_id, candidate_ids: vec![2, 1] }.into(),
+		);
+	});
+}
+
+#[test]
+fn get_top_winners_skips_candidates_without_votes() {
+	new_test_ext().execute_with(|| {
+		let election_id = run_election(&[b"Alice", b"Bob"], &[2]);
+		Election::<Test>::mutate(election_id, |election| election.as_mut().unwrap().seats = 3);
+		assert_eq!(Voting::get_top_winners(election_id), vec![(2, 1)]);
+	});
+}
+
+#[test]
+fn create_election_rejects_invalid_seat_counts() {
+	new_test_ext().execute_with(|| {
+		for (kind, seats, error) in [
+			(ElectionKind::Plurality, 0, Error::<Test>::InvalidSeatCount),
+			(ElectionKind::RankedChoice, 2, Error::<Test>::WrongElectionKind),
+		] {
+			assert_noop!(
+				Voting::create_election(
+					RuntimeOrigin::root(),
+					b"Committee".to_vec(),
+					blocks(1, 10),
+					kind,
+					false,
+					None,
+					seats,
+					false,
+					false,
+					None,
+					None
+				),
+				error
+			);
+		}
+	});
+}
+
+/// Create and start a ranked-choice election with the given candidates, and have account `i + 1`
+/// cast `ballots[i]`.
+fn run_ranked_election(candidates: &[&[u8]], ballots: &[&[u32]]) -> u32 {
+	let election_id = create_election_of_kind(1, 10, ElectionKind::RankedChoice);
+	for name in candidates {
+		add_candidate(election_id, name);
+	}
+	start_election(election_id);
+	for (voter, ballot) in ballots.iter().enumerate() {
+		assert_ok!(Voting::cast_ranked_vote(
+			RuntimeOrigin::signed(voter as u64 + 1),
+			election_id,
+			ballot.to_vec()
+		));
+	}
+	election_id
+}
+
+#[test]
+fn votes_leave_a_receipt_that_voters_can_verify() {
+	new_test_ext().execute_with(|| {
+		let election_id = run_election(&[b"Alice", b"Bob"], &[2]);
+		assert_eq!(Voting::verify_receipt(election_id, &1), Some(receipt(election_id, 1, 2)));
+		assert_eq!(Receipts::<Test>::get(election_id, 1), Some(receipt(election_id, 1, 2)));
+		assert_eq!(Voting::verify_receipt(election_id, &2), None);
+
+		// A vote in another election keeps the receipt of the first one.
+		let other = run_election(&[b"Carol", b"Dave"], &[2]);
+		assert_eq!(Voting::verify_receipt(other, &1), Some(receipt(other, 1, 2)));
+		assert_eq!(Voting::verify_receipt(election_id, &1), Some(receipt(election_id, 1, 2)));
+		assert_ne!(receipt(election_id, 1, 2), receipt(other, 1, 2));
+	});
+}
+
+#[test]
+fn cast_ranked_vote_records_ballot() {
+	new_test_ext().execute_with(|| {
+		let election_id = run_ranked_election(&[b"Alice", b"Bob", b"Carol"], &[&[3, 1]]);
+
+		assert_eq!(Rankings::<Test>::get(election_id, 1).unwrap().to_vec(), vec![3, 1]);
+		assert_eq!(Voting::get_vote_for_account(election_id, &1), Some(3));
+		assert_eq!(VoteCount::<Test>::get(election_id, 3), 1);
+		assert_eq!(TotalVotes::<Test>::get(election_id), 1);
+		System::assert_last_event(
+			Event::VoteCast {
+				election_id,
+				voter: 1,
+				candidate_id: Some(3),
+				new_count: Some(1),
+				total_votes: Some(1),
+				receipt: receipt(election_id, 1, 3),
+			}
+			.into(),
+		);
+
+		assert_ok!(Voting::retract_vote(RuntimeOrigin::signed(1), election_id));
+		assert!(!Rankings::<Test>::contains_key(election_id, 1));
+		assert_eq!(VoteCount::<Test>::get(election_id, 3), 0);
+	});
+}
+
+#[test]
+fn cast_ranked_vote_rejects_invalid_ballots() {
+	new_test_ext().execute_with(|| {
+		let election_id = run_ranked_election(&[b"Alice", b"Bob"], &[]);
+
+		assert_noop!(
+			Voting::cast_ranked_vote(RuntimeOrigin::signed(1), election_id, vec![]),
+			Error::<Test>::EmptyRanking
+		);
+		assert_noop!(
+			Voting::cast_ranked_vote(RuntimeOrigin::signed(1), election_id, vec![1, 1]),
+			Error::<Test>::DuplicateRanking
+		);
+		assert_noop!(
+			Voting::cast_ranked_vote(RuntimeOrigin::signed(1), election_id, vec![1, 7]),
+			Error::<Test>::InvalidCandidate
+		);
+		assert_noop!(
+			Voting::cast_ranked_vote(RuntimeOrigin::signed(1), election_id, vec![ABSTAIN]),
+			Error::<Test>::InvalidCandidate
+		);
+		assert_noop!(
+			Voting::cast_vote(RuntimeOrigin::signed(1), election_id, 1),
+			Error::<Test>::WrongElectionKind
+		);
+
+		let plurality = run_election(&[b"Alice"], &[]);
+		assert_noop!(
+			Voting::cast_ranked_vote(RuntimeOrigin::signed(1), plurality, vec![1]),
+			Error::<Test>::WrongElectionKind
+		);
+	});
+}
+
+fn run_approval_election(candidates: &[&[u8]]) -> u32 {
+	let election_id = create_election_of_kind(1, 10, ElectionKind::Approval);
+	for name in candidates {
+		add_candidate(election_id, name);
+	}
+	start_election(election_id);
+	election_id
+}
+
+#[test]
+fn approval_votes_count_for_every_approved_candidate() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Voting::delegate_vote(RuntimeOrigin::signed(2), 1));
+		let election_id = run_approval_election(&[b"Alice", b"Bob", b"Carol"]);
+
+		assert_ok!(Voting::cast_approval_vote(RuntimeOrigin::signed(1), election_id, vec![3, 1]));
+		assert_eq!(Approvals::<Test>::get(election_id, 1).unwrap().to_vec(), vec![3, 1]);
+		assert_eq!(Voting::get_vote_for_account(election_id, &1), Some(3));
+		assert_eq!(VoteCount::<Test>::get(election_id, 1), 2);
+		assert_eq!(VoteCount::<Test>::get(election_id, 3), 2);
+		assert_eq!(TotalVotes::<Test>::get(election_id), 2);
+		System::assert_last_event(
+			Event::VoteCast {
+				election_id,
+				voter: 1,
+				candidate_id: Some(3),
+				new_count: Some(2),
+				total_votes: Some(2),
+				receipt: receipt(election_id, 1, 3),
+			}
+			.into(),
+		);
+
+		// Voting directly takes the delegated weight back from every approved candidate.
+		assert_ok!(Voting::cast_approval_vote(RuntimeOrigin::signed(2), election_id, vec![1, 2]));
+		assert_eq!(VoteCount::<Test>::get(election_id, 1), 2);
+		assert_eq!(VoteCount::<Test>::get(election_id, 2), 1);
+		assert_eq!(VoteCount::<Test>::get(election_id, 3), 1);
+		assert_eq!(TotalVotes::<Test>::get(election_id), 2);
+		assert_eq!(Voting::get_winner(election_id), WinnerResult::Winner(1, 2));
+
+		assert_ok!(Voting::retract_vote(RuntimeOrigin::signed(1), election_id));
+		assert!(!Approvals::<Test>::contains_key(election_id, 1));
+		assert_eq!(VoteCount::<Test>::get(election_id, 1), 1);
+		assert_eq!(VoteCount::<Test>::get(election_id, 3), 0);
+		assert_eq!(TotalVotes::<Test>::get(election_id), 1);
+	});
+}
+
+#[test]
+fn cast_approval_vote_rejects_invalid_ballots() {
+	new_test_ext().execute_with(|| {
+		let election_id = run_approval_election(&[b"Alice", b"Bob"]);
+
+		assert_noop!(
+			Voting::cast_approval_vote(RuntimeOrigin::signed(1), election_id, vec![]),
+			Error::<Test>::EmptyApproval
+		);
+		assert_noop!(
+			Voting::cast_approval_vote(RuntimeOrigin::signed(1), election_id, vec![2, 1, 2]),
+			Error::<Test>::DuplicateApproval
+		);
+		assert_noop!(
+			Voting::cast_approval_vote(RuntimeOrigin::signed(1), election_id, vec![1, ABSTAIN]),
+			Error::<Test>::InvalidCandidate
+		);
+		assert_noop!(
+			Voting::cast_vote(RuntimeOrigin::signed(1), election_id, 1),
+			Error::<Test>::WrongElectionKind
+		);
+		assert_ok!(Voting::cast_approval_vote(RuntimeOrigin::signed(1), election_id, vec![1]));
+		assert_noop!(
+			Voting::cast_approval_vote(RuntimeOrigin::signed(1), election_id, vec![2]),
+			Error::<Test>::AlreadyVoted
+		);
+
+		let plurality = run_election(&[b"Alice"], &[]);
+		assert_noop!(
+			Voting::cast_approval_vote(RuntimeOrigin::signed(1), plurality, vec![1]),
+			Error::<Test>::WrongElectionKind
+		);
+	});
+}
+
+#[test]
+fn quadratic_votes_cost_the_square_of_their_number_in_credits() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election_of_kind(1, 10, ElectionKind::Quadratic);
+		for name in [&b"Alice"[..], b"Bob", b"Carol"] {
+			add_candidate(election_id, name);
+		}
+		start_election(election_id);
+
+		// 6 votes for Bob and 8 for Alice cost 36 + 64 credits, the whole budget.
+		assert_ok!(Voting::cast_quadratic_vote(
+			RuntimeOrigin::signed(1),
+			election_id,
+			vec![(2, 6), (1, 8)]
+		));
+		assert_eq!(SpentCredits::<Test>::get(election_id, 1), 100);
+		assert_eq!(VoteCount::<Test>::get(election_id, 1), 8);
+		assert_eq!(VoteCount::<Test>::get(election_id, 2), 6);
+		assert_eq!(TotalVotes::<Test>::get(election_id), 14);
+		assert_eq!(Voting::get_vote_for_account(election_id, &1), Some(2));
+		System::assert_last_event(
+			Event::VoteCast {
+				election_id,
+				voter: 1,
+				candidate_id: Some(2),
+				new_count: Some(6),
+				total_votes: Some(14),
+				receipt: receipt(election_id, 1, 2),
+			}
+			.into(),
+		);
+
+		assert_ok!(Voting::cast_quadratic_vote(
+			RuntimeOrigin::signed(2),
+			election_id,
+			vec![(3, 9)]
+		));
+		assert_eq!(Voting::get_winner(election_id), WinnerResult::Winner(3, 9));
+		assert_noop!(
+			Voting::retract_vote(RuntimeOrigin::signed(1), election_id),
+			Error::<Test>::WrongElectionKind
+		);
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), election_id));
+		assert_ok!(Voting::finalize_election(RuntimeOrigin::root(), election_id, 0));
+	});
+}
+
+#[test]
+fn cast_quadratic_vote_rejects_invalid_ballots() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election_of_kind(1, 10, ElectionKind::Quadratic);
+		add_candidate(election_id, b"Alice");
+		add_candidate(election_id, b"Bob");
+		start_election(election_id);
+
+		assert_noop!(
+			Voting::cast_quadratic_vote(RuntimeOrigin::signed(1), election_id, vec![]),
+			Error::<Test>::EmptyQuadraticBallot
+		);
+		assert_noop!(
+			Voting::cast_quadratic_vote(RuntimeOrigin::signed(1), election_id, vec![(1, 0)]),
+			Error::<Test>::EmptyQuadraticBallot
+		);
+		assert_noop!(
+			Voting::cast_quadratic_vote(
+				RuntimeOrigin::signed(1),
+				election_id,
+				vec![(1, 1), (1, 2)]
+			),
+			Error::<Test>::DuplicateQuadraticVote
+		);
+		assert_noop!(
+			Voting::cast_quadratic_vote(RuntimeOrigin::signed(1), election_id, vec![(ABSTAIN, 1)]),
+			Error::<Test>::InvalidCandidate
+		);
+		assert_noop!(
+			Voting::cast_quadratic_vote(
+				RuntimeOrigin::signed(1),
+				election_id,
+				vec![(1, 8), (2, 7)]
+			),
+			Error::<Test>::InsufficientCredits
+		);
+		assert_noop!(
+			Voting::cast_quadratic_vote(RuntimeOrigin::signed(1), election_id, vec![(1, u32::MAX)]),
+			Error::<Test>::InsufficientCredits
+		);
+		assert_noop!(
+			Voting::cast_vote(RuntimeOrigin::signed(1), election_id, 1),
+			Error::<Test>::WrongElectionKind
+		);
+		assert_ok!(Voting::cast_quadratic_vote(
+			RuntimeOrigin::signed(1),
+			election_id,
+			vec![(1, 1)]
+		));
+		assert_noop!(
+			Voting::cast_quadratic_vote(RuntimeOrigin::signed(1), election_id, vec![(2, 1)]),
+			Error::<Test>::AlreadyVoted
+		);
+
+		let plurality = run_election(&[b"Alice"], &[]);
+		assert_noop!(
+			Voting::cast_quadratic_vote(RuntimeOrigin::signed(1), plurality, vec![(1, 1)]),
+			Error::<Test>::WrongElectionKind
+		);
+		assert_noop!(
+			Voting::create_election(
+				RuntimeOrigin::root(),
+				b"Budget".to_vec(),
+				blocks(1, 10),
+				ElectionKind::Quadratic,
+				true,
+				None,
+				1,
+				false,
+				false,
+				None,
+				None
+			),
+			Error::<Test>::WrongElectionKind
+		);
+	});
+}
+
+#[test]
+fn try_state_checks_the_tallies_of_every_election() {
+	new_test_ext().execute_with(|| {
+		let election_id = run_election(&[b"Alice", b"Bob"], &[2, 2, ABSTAIN]);
+		let approval = run_approval_election(&[b"Alice", b"Bob"]);
+		assert_ok!(Voting::cast_approval_vote(RuntimeOrigin::signed(1), approval, vec![1, 2]));
+		assert_ok!(Voting::do_try_state());
+
+		TotalVotes::<Test>::insert(election_id, 4);
+		assert!(Voting::do_try_state().is_err());
+		TotalVotes::<Test>::insert(election_id, 1);
+		VoteCount::<Test>::insert(election_id, 2, 0);
+		AbstainCount::<Test>::insert(election_id, 0);
+		assert!(Voting::do_try_state().is_err());
+		VoteCount::<Test>::insert(election_id, 2, 1);
+		assert_ok!(Voting::do_try_state());
+
+		HasVoted::<Test>::mutate(election_id, 1, |vote| vote.as_mut().unwrap().candidate_id = 9);
+		assert!(Voting::do_try_state().is_err());
+	});
+}
+
+#[test]
+fn instant_runoff_redistributes_eliminated_preferences() {
+	new_test_ext().execute_with(|| {
+		// Alice leads on first preferences, but Carol's supporters prefer Bob.
+		let election_id = run_ranked_election(
+			&[b"Alice", b"Bob", b"Carol"],
+			&[&[1], &[1], &[2], &[2, 1, 3], &[3, 2], &[3, 2], &[1]],
+		);
+		assert_eq!(Voting::get_winner(election_id), WinnerResult::Winner(2, 4));
+		// A plurality tally would have gone to Alice with her three first preferences.
+		assert_eq!(VoteCount::<Test>::get(election_id, 1), 3);
+
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), election_id));
+		assert_noop!(
+			Voting::finalize_election(RuntimeOrigin::root(), election_id, 6),
+			Error::<Test>::WitnessTooLow
+		);
+		assert_ok!(Voting::finalize_election(RuntimeOrigin::root(), election_id, 7));
+		System::assert_has_event(Event::RankedResult { election_id, rounds: 2 }.into());
+		System::assert_has_event(
+			Event::WinnerDeclared { election_id, candidate_id: 2, votes: 4 }.into(),
+		);
+	});
+}
+
+#[test]
+fn instant_runoff_reports_ties_and_empty_elections() {
+	new_test_ext().execute_with(|| {
+		let election_id = run_ranked_election(&[b"Alice", b"Bob"], &[]);
+		assert_eq!(Voting::instant_runoff(election_id), (WinnerResult::NoVotes, 1));
+
+		let election_id = run_ranked_election(&[b"Alice", b"Bob"], &[&[1], &[2]]);
+		assert_eq!(Voting::instant_runoff(election_id), (WinnerResult::Tie(vec![1, 2]), 1));
+	});
+}
+
+#[test]
+fn weighted_votes_count_with_free_balance() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election_with(1, 10, ElectionKind::Plurality, true);
+		let alice = add_candidate(election_id, b"Alice");
+		let bob = add_candidate(election_id, b"Bob");
+		start_election(election_id);
+
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(1), election_id, alice));
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(3), election_id, bob));
+		assert_eq!(
+			HasVoted::<Test>::get(election_id, 3),
+			Some(VoteRecord { candidate_id: bob, weight: 30, block: 1 })
+		);
+		assert_eq!(VoteCount::<Test>::get(election_id, alice), 10);
+		assert_eq!(VoteCount::<Test>::get(election_id, bob), 30);
+		assert_eq!(TotalVotes::<Test>::get(election_id), 40);
+		assert_eq!(Voting::get_winner(election_id), WinnerResult::Winner(bob, 30));
+	});
+}
+
+#[test]
+fn weighted_votes_are_moved_and_retracted_with_their_recorded_weight() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election_with(1, 10, ElectionKind::Plurality, true);
+		let alice = add_candidate(election_id, b"Alice");
+		let bob = add_candidate(election_id, b"Bob");
+		start_election(election_id);
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(2), election_id, alice));
+
+		// A later change of balance does not affect a vote that has already been cast.
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 2, 1_000));
+		assert_ok!(Voting::change_vote(RuntimeOrigin::signed(2), election_id, bob));
+		assert_eq!(VoteCount::<Test>::get(election_id, alice), 0);
+		assert_eq!(VoteCount::<Test>::get(election_id, bob), 20);
+
+		assert_ok!(Voting::retract_vote(RuntimeOrigin::signed(2), election_id));
+		assert_eq!(VoteCount::<Test>::get(election_id, bob), 0);
+		assert_eq!(TotalVotes::<Test>::get(election_id), 0);
+	});
+}
+
+#[test]
+fn weighted_votes_lock_the_balance_until_the_election_is_closed() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election_with(1, 10, ElectionKind::Plurality, true);
+		let alice = add_candidate(election_id, b"Alice");
+		start_election(election_id);
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(2), election_id, alice));
+		assert_eq!(VoteLocks::<Test>::get(2).into_inner(), vec![(election_id, 20)]);
+
+		// The balance cannot be moved to another account and counted again.
+		assert!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), 4, 10).is_err());
+		assert_ok!(Voting::unlock_vote_balance(RuntimeOrigin::signed(5), 2));
+		System::assert_last_event(Event::VoteBalanceUnlocked { who: 2, locked: 20 }.into());
+		assert!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), 4, 10).is_err());
+
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), election_id));
+		assert_ok!(Voting::finalize_election(RuntimeOrigin::root(), election_id, 0));
+		assert_ok!(Voting::unlock_vote_balance(RuntimeOrigin::signed(5), 2));
+		System::assert_last_event(Event::VoteBalanceUnlocked { who: 2, locked: 0 }.into());
+		assert!(!VoteLocks::<Test>::contains_key(2));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), 4, 10));
+		assert_noop!(
+			Voting::unlock_vote_balance(RuntimeOrigin::signed(5), 2),
+			Error::<Test>::NoVoteLock
+		);
+	});
+}
+
+#[test]
+fn the_balance_is_locked_for_a_bounded_number_of_weighted_elections() {
+	new_test_ext().execute_with(|| {
+		let elections: Vec<_> = (0..4)
+			.map(|_| {
+				let election_id = create_election_with(1, 10, ElectionKind::Plurality, true);
+				let alice = add_candidate(election_id, b"Alice");
+				start_election(election_id);
+				(election_id, alice)
+			})
+			.collect();
+		for (election_id, alice) in &elections[..3] {
+			assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(2), *election_id, *alice));
+		}
+		let (last, alice) = elections[3];
+		assert_noop!(
+			Voting::cast_vote(RuntimeOrigin::signed(2), last, alice),
+			Error::<Test>::TooManyVoteLocks
+		);
+
+		assert_ok!(Voting::cancel_election(
+			RuntimeOrigin::root(),
+			elections[0].0,
+			b"Void".to_vec()
+		));
+		assert_ok!(Voting::unlock_vote_balance(RuntimeOrigin::signed(2), 2));
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(2), last, alice));
+		assert_eq!(
+			VoteLocks::<Test>::get(2).into_inner(),
+			vec![(elections[1].0, 20), (elections[2].0, 20), (last, 20)]
+		);
+	});
+}
+
+#[test]
+fn delegated_votes_count_with_the_delegate() {
+	new_test_ext().execute_with(|| {
+		for delegator in [2, 3] {
+			assert_ok!(Voting::delegate_vote(RuntimeOrigin::signed(delegator), 1));
+			System::assert_last_event(Event::VoteDelegated { delegator, delegate: 1 }.into());
+		}
+		let election_id = run_election(&[b"Alice", b"Bob"], &[1]);
+		assert_eq!(VoteCount::<Test>::get(election_id, 1), 3);
+		assert_eq!(TotalVotes::<Test>::get(election_id), 3);
+		assert_eq!(
+			HasVoted::<Test>::get(election_id, 1),
+			Some(VoteRecord { candidate_id: 1, weight: 3, block: 1 })
+		);
+		assert_eq!(DelegatedVotes::<Test>::get(election_id, 2), Some((1, 1)));
+
+		// Voting directly takes the weight back out of the delegate's vote.
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(2), election_id, 2));
+		assert_eq!(VoteCount::<Test>::get(election_id, 1), 2);
+		assert_eq!(VoteCount::<Test>::get(election_id, 2), 1);
+		assert_eq!(TotalVotes::<Test>::get(election_id), 3);
+		assert_eq!(HasVoted::<Test>::get(election_id, 1).map(|vote| vote.weight), Some(2));
+		assert!(!DelegatedVotes::<Test>::contains_key(election_id, 2));
+	});
+}
+
+#[test]
+fn delegations_are_resolved_one_level_deep() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Voting::delegate_vote(RuntimeOrigin::signed(2), 1));
+		assert_ok!(Voting::delegate_vote(RuntimeOrigin::signed(3), 2));
+		let election_id = run_election(&[b"Alice"], &[1]);
+		assert_eq!(VoteCount::<Test>::get(election_id, 1), 2);
+		assert!(!DelegatedVotes::<Test>::contains_key(election_id, 3));
+	});
+}
+
+#[test]
+fn retracting_a_vote_releases_the_delegated_weight() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Voting::delegate_vote(RuntimeOrigin::signed(2), 1));
+		let election_id = run_election(&[b"Alice"], &[1]);
+		assert_ok!(Voting::retract_vote(RuntimeOrigin::signed(1), election_id));
+		assert_eq!(TotalVotes::<Test>::get(election_id), 0);
+		assert!(!DelegatedVotes::<Test>::contains_key(election_id, 2));
+
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(2), election_id, 1));
+		assert_eq!(VoteCount::<Test>::get(election_id, 1), 1);
+		assert_eq!(TotalVotes::<Test>::get(election_id), 1);
+	});
+}
+
+#[test]
+fn delegate_vote_and_undelegate_maintain_both_directions() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Voting::delegate_vote(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::SelfDelegation
+		);
+		assert_noop!(Voting::undelegate(RuntimeOrigin::signed(1)), Error::<Test>::NotDelegating);
+
+		assert_ok!(Voting::delegate_vote(RuntimeOrigin::signed(1), 2));
+		assert_ok!(Voting::delegate_vote(RuntimeOrigin::signed(1), 3));
+		assert_eq!(Delegations::<Test>::get(1), Some(3));
+		assert!(!Delegators::<Test>::contains_key(2));
+		assert_eq!(Delegators::<Test>::get(3).into_inner(), vec![1]);
+
+		assert_ok!(Voting::undelegate(RuntimeOrigin::signed(1)));
+		System::assert_last_event(Event::VoteUndelegated { delegator: 1, delegate: 3 }.into());
+		assert!(!Delegations::<Test>::contains_key(1));
+		assert!(!Delegators::<Test>::contains_key(3));
+
+		for delegator in 10..14 {
+			assert_ok!(Voting::delegate_vote(RuntimeOrigin::signed(delegator), 1));
+		}
+		assert_noop!(
+			Voting::delegate_vote(RuntimeOrigin::signed(14), 1),
+			Error::<Test>::TooManyDelegators
+		);
+	});
+}
+
+fn create_commit_reveal_election(start_block: u64, end_block: u64, reveal_end_block: u64) -> u32 {
+	let election_id = NextElectionId::<Test>::get();
+	assert_ok!(Voting::create_election(
+		RuntimeOrigin::root(),
+		b"Council".to_vec(),
+		blocks(start_block, end_block),
+		ElectionKind::Plurality,
+		false,
+		Some(reveal_end_block),
+		1,
+		false,
+		false,
+		None,
+		None
+	));
+	election_id
+}
+
+fn commitment(candidate_id: u32, salt: [u8; 32]) -> H256 {
+	H256(sp_io::hashing::blake2_256(&(candidate_id, salt).encode()))
+}
+
+/// The receipt of a vote of `voter` for `candidate_id` in an election in the current block.
+fn receipt(election_id: u32, voter: u64, candidate_id: u32) -> H256 {
+	H256(sp_io::hashing::blake2_256(
+		&(election_id, voter, candidate_id, System::block_number()).encode(),
+	))
+}
+
+#[test]
+fn committed_votes_are_counted_when_revealed() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_commit_reveal_election(1, 5, 10);
+		let alice = add_candidate(election_id, b"Alice");
+		start_election(election_id);
+
+		assert_noop!(
+			Voting::cast_vote(RuntimeOrigin::signed(1), election_id, alice),
+			Error::<Test>::WrongElectionKind
+		);
+		assert_ok!(Voting::commit_vote(
+			RuntimeOrigin::signed(1),
+			election_id,
+			commitment(alice, [7; 32])
+		));
+		System::assert_last_event(Event::VoteCommitted { election_id, voter: 1 }.into());
+		assert_noop!(
+			Voting::commit_vote(RuntimeOrigin::signed(1), election_id, commitment(alice, [8; 32])),
+			Error::<Test>::AlreadyVoted
+		);
+		assert_eq!(VoteCount::<Test>::get(election_id, alice), 0);
+		assert_noop!(
+			Voting::reveal_vote(RuntimeOrigin::signed(1), election_id, alice, [7; 32]),
+			Error::<Test>::ElectionIsActive
+		);
+
+		System::set_block_number(5);
+		Voting::on_initialize(5);
+		assert_ok!(Voting::reveal_vote(RuntimeOrigin::signed(1), election_id, alice, [7; 32]));
+		System::assert_last_event(
+			Event::VoteRevealed { election_id, voter: 1, candidate_id: Some(alice) }.into(),
+		);
+		assert!(!Commitments::<Test>::contains_key(election_id, 1));
+		assert_eq!(VoteCount::<Test>::get(election_id, alice), 1);
+		assert_eq!(TotalVotes::<Test>::get(election_id), 1);
+	});
+}
+
+#[test]
+fn reveal_vote_fails_for_invalid_reveals() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_commit_reveal_election(1, 5, 10);
+		let alice = add_candidate(election_id, b"Alice");
+		let bob = add_candidate(election_id, b"Bob");
+		start_election(election_id);
+		assert_ok!(Voting::commit_vote(
+			RuntimeOrigin::signed(1),
+			election_id,
+			commitment(alice, [7; 32])
+		));
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), election_id));
+
+		assert_noop!(
+			Voting::reveal_vote(RuntimeOrigin::signed(2), election_id, alice, [7; 32]),
+			Error::<Test>::CommitmentNotFound
+		);
+		assert_noop!(
+			Voting::reveal_vote(RuntimeOrigin::signed(1), election_id, bob, [7; 32]),
+			Error::<Test>::RevealMismatch
+		);
+		assert_noop!(
+			Voting::finalize_election(RuntimeOrigin::root(), election_id, 0),
+			Error::<Test>::RevealPeriodOpen
+		);
+
+		System::set_block_number(10);
+		assert_noop!(
+			Voting::reveal_vote(RuntimeOrigin::signed(1), election_id, alice, [7; 32]),
+			Error::<Test>::RevealPeriodEnded
+		);
+		assert_ok!(Voting::finalize_election(RuntimeOrigin::root(), election_id, 0));
+		assert_eq!(TotalVotes::<Test>::get(election_id), 0);
+	});
+}
+
+#[test]
+fn registration_is_only_required_when_configured() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		let alice = add_candidate(election_id, b"Alice");
+		start_election(election_id);
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(1), election_id, alice));
+
+		RequireRegistration::set(true);
+		assert_noop!(
+			Voting::cast_vote(RuntimeOrigin::signed(2), election_id, alice),
+			Error::<Test>::NotRegistered
+		);
+		assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 2));
+		System::assert_last_event(Event::VoterRegistered { who: 2 }.into());
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(2), election_id, alice));
+		assert_eq!(VoteCount::<Test>::get(election_id, alice), 2);
+	});
+}
+
+#[test]
+fn verified_identity_is_only_required_when_configured() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		let alice = add_candidate(election_id, b"Alice");
+		start_election(election_id);
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(1), election_id, alice));
+
+		RequireVerifiedIdentity::set(true);
+		VerifiedIdentities::set(vec![3]);
+		assert_noop!(
+			Voting::cast_vote(RuntimeOrigin::signed(2), election_id, alice),
+			Error::<Test>::IdentityNotVerified
+		);
+		// Delegators without a verified identity are not counted either.
+		assert_ok!(Voting::delegate_vote(RuntimeOrigin::signed(2), 3));
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(3), election_id, alice));
+		assert_eq!(VoteCount::<Test>::get(election_id, alice), 2);
+		assert!(!DelegatedVotes::<Test>::contains_key(election_id, 2));
+	});
+}
+
+#[test]
+fn registered_voters_wait_for_the_eligibility_delay() {
+	new_test_ext().execute_with(|| {
+		VotingEligibilityDelay::set(3);
+		let election_id = create_election(1, 10);
+		let alice = add_candidate(election_id, b"Alice");
+		start_election(election_id);
+		// Accounts that were never registered are not held back.
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(1), election_id, alice));
+
+		System::set_block_number(2);
+		assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 2));
+		assert_eq!(VoterRegisteredAt::<Test>::get(2), Some(2));
+		System::set_block_number(4);
+		assert_noop!(
+			Voting::cast_vote(RuntimeOrigin::signed(2), election_id, alice),
+			Error::<Test>::EligibilityPending
+		);
+		System::set_block_number(5);
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(2), election_id, alice));
+
+		assert_ok!(Voting::deregister_voter(RuntimeOrigin::root(), 2));
+		assert!(VoterRegisteredAt::<Test>::get(2).is_none());
+	});
+}
+
+#[test]
+fn register_and_deregister_voter_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Voting::register_voter(RuntimeOrigin::signed(1), 1),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+		assert!(RegisteredVoters::<Test>::contains_key(1));
+		assert_eq!(RegisteredVoterCount::<Test>::get(), 1);
+		assert_noop!(
+			Voting::register_voter(RuntimeOrigin::root(), 1),
+			Error::<Test>::AlreadyRegistered
+		);
+
+		assert_ok!(Voting::deregister_voter(RuntimeOrigin::root(), 1));
+		System::assert_last_event(Event::VoterDeregistered { who: 1 }.into());
+		assert!(!RegisteredVoters::<Test>::contains_key(1));
+		assert_eq!(RegisteredVoterCount::<Test>::get(), 0);
+		assert_noop!(
+			Voting::deregister_voter(RuntimeOrigin::root(), 1),
+			Error::<Test>::NotRegistered
+		);
+	});
+}
+
+#[test]
+fn admins_can_manage_candidates_and_run_elections() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		assert_noop!(
+			Voting::add_candidate(
+				RuntimeOrigin::signed(1),
+				election_id,
+				b"Alice".to_vec(),
+				b"Manifesto".to_vec()
+			),
+			Error::<Test>::NotAdmin
+		);
+
+		assert_ok!(Voting::add_admin(RuntimeOrigin::root(), 1));
+		System::assert_last_event(Event::AdminAdded { who: 1 }.into());
+		assert_noop!(Voting::add_admin(RuntimeOrigin::root(), 1), Error::<Test>::AlreadyAdmin);
+		assert_ok!(Voting::add_candidate(
+			RuntimeOrigin::signed(1),
+			election_id,
+			b"Alice".to_vec(),
+			b"Manifesto".to_vec()
+		));
+		assert_ok!(Voting::lock_candidates(RuntimeOrigin::signed(1), election_id));
+		assert_ok!(Voting::start_election(RuntimeOrigin::signed(1), election_id));
+		assert_ok!(Voting::end_election(RuntimeOrigin::signed(1), election_id));
+		// Admins cannot act on behalf of the admin origin.
+		assert_noop!(
+			Voting::finalize_election(RuntimeOrigin::signed(1), election_id, 0),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Voting::add_admin(RuntimeOrigin::signed(1), 2),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_ok!(Voting::remove_admin(RuntimeOrigin::root(), 1));
+		System::assert_last_event(Event::AdminRemoved { who: 1 }.into());
+		assert!(Admins::<Test>::get().is_empty());
+		assert_noop!(Voting::remove_admin(RuntimeOrigin::root(), 1), Error::<Test>::NotAdmin);
+		assert_noop!(
+			Voting::start_election(RuntimeOrigin::signed(1), election_id),
+			Error::<Test>::NotAdmin
+		);
+	});
+}
+
+#[test]
+fn admins_can_hand_their_rights_over_in_one_step() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Voting::transfer_admin(RuntimeOrigin::signed(1), 2), Error::<Test>::NotAdmin);
+		for who in [1, 3] {
+			assert_ok!(Voting::add_admin(RuntimeOrigin::root(), who));
+		}
+		assert_noop!(
+			Voting::transfer_admin(RuntimeOrigin::signed(1), 3),
+			Error::<Test>::AlreadyAdmin
+		);
+
+		assert_ok!(Voting::transfer_admin(RuntimeOrigin::signed(1), 2));
+		System::assert_last_event(Event::AdminTransferred { from: 1, to: 2 }.into());
+		assert_eq!(Admins::<Test>::get().to_vec(), vec![2, 3]);
+		assert_noop!(Voting::transfer_admin(RuntimeOrigin::signed(1), 4), Error::<Test>::NotAdmin);
+	});
+}
+
+#[test]
+fn election_deposit_is_reserved_and_returned_on_finalize() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Voting::add_admin(RuntimeOrigin::root(), 2));
+		assert_ok!(Voting::create_election(
+			RuntimeOrigin::signed(2),
+			b"Council".to_vec(),
+			blocks(1, 10),
+			ElectionKind::Plurality,
+			false,
+			None,
+			1,
+			false,
+			false,
+			None,
+			None
+		));
+		assert_eq!(Balances::reserved_balance(2), 5);
+		assert_eq!(Balances::free_balance(2), 15);
+		assert_eq!(Election::<Test>::get(0).unwrap().deposit, Some((2, 5)));
+
+		add_candidate(0, b"Alice");
+		start_election(0);
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), 0));
+		assert_ok!(Voting::finalize_election(RuntimeOrigin::root(), 0, 0));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(2), 20);
+
+		// Elections created by the admin origin do not require a deposit.
+		assert_eq!(create_election(1, 10), 1);
+		assert_eq!(Election::<Test>::get(1).unwrap().deposit, None);
+	});
+}
+
+#[test]
+fn election_deposit_is_slashed_on_reset_before_finalize() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Voting::add_admin(RuntimeOrigin::root(), 2));
+		assert_ok!(Voting::add_admin(RuntimeOrigin::root(), 11));
+		assert_noop!(
+			Voting::create_election(
+				RuntimeOrigin::signed(11),
+				b"Council".to_vec(),
+				blocks(1, 10),
+				ElectionKind::Plurality,
+				false,
+				None,
+				1,
+				false,
+				false,
+				None,
+				None
+			),
+			Error::<Test>::InsufficientBalance
+		);
+
+		assert_ok!(Voting::create_election(
+			RuntimeOrigin::signed(2),
+			b"Council".to_vec(),
+			blocks(1, 10),
+			ElectionKind::Plurality,
+			false,
+			None,
+			1,
+			false,
+			false,
+			None,
+			None
+		));
+		confirmed_reset(0);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(2), 15);
+	});
+}
+
+#[test]
+fn register_as_candidate_reserves_bond() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		add_candidate(election_id, b"Alice");
+
+		assert_ok!(Voting::register_as_candidate(
+			RuntimeOrigin::signed(2),
+			election_id,
+			b"Bob".to_vec(),
+			b"Manifesto".to_vec()
+		));
+		System::assert_last_event(
+			Event::CandidateSelfRegistered { election_id, candidate_id: 2, who: 2 }.into(),
+		);
+		assert_eq!(CandidateOwners::<Test>::get(election_id, 2), Some((2, 3)));
+		assert_eq!(Candidates::<Test>::get(election_id)[1].name.to_vec(), b"Bob".to_vec());
+		assert_eq!(Balances::reserved_balance(2), 3);
+
+		assert_noop!(
+			Voting::register_as_candidate(
+				RuntimeOrigin::signed(2),
+				election_id,
+				b"Bobby".to_vec(),
+				b"Manifesto".to_vec()
+			),
+			Error::<Test>::AlreadyCandidate
+		);
+		assert_noop!(
+			Voting::register_as_candidate(
+				RuntimeOrigin::signed(11),
+				election_id,
+				b"Carol".to_vec(),
+				b"Manifesto".to_vec()
+			),
+			Error::<Test>::InsufficientBalance
+		);
+
+		// Removing a self-registered candidate returns the bond.
+		assert_ok!(Voting::remove_candidate(RuntimeOrigin::root(), election_id, 2));
+		assert!(!CandidateOwners::<Test>::contains_key(election_id, 2));
+		assert_eq!(Balances::reserved_balance(2), 0);
+	});
+}
+
+#[test]
+fn reset_election_settles_candidate_bonds() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		for who in [2, 3] {
+			assert_ok!(Voting::register_as_candidate(
+				RuntimeOrigin::signed(who),
+				election_id,
+				b"Candidate".to_vec(),
+				b"Manifesto".to_vec()
+			));
+		}
+
+		confirmed_reset(election_id);
+		System::assert_has_event(Event::BondsSettled { election_id, count: 2 }.into());
+		assert_eq!(CandidateOwners::<Test>::iter_prefix(election_id).count(), 0);
+		assert_eq!((Balances::reserved_balance(2), Balances::free_balance(2)), (0, 20));
+		assert_eq!((Balances::reserved_balance(3), Balances::free_balance(3)), (0, 30));
+
+		// With a destination, the bonds are slashed to it instead.
+		SlashedFundsDestination::set(Some(9));
+		let election_id = create_election(1, 10);
+		assert_ok!(Voting::register_as_candidate(
+			RuntimeOrigin::signed(2),
+			election_id,
+			b"Candidate".to_vec(),
+			b"Manifesto".to_vec()
+		));
+		confirmed_reset(election_id);
+		System::assert_has_event(Event::BondsSettled { election_id, count: 1 }.into());
+		assert_eq!((Balances::reserved_balance(2), Balances::free_balance(2)), (0, 17));
+		assert_eq!(Balances::free_balance(9), 93);
+	});
+}
+
+#[test]
+fn accounts_can_stand_in_several_elections_if_allowed() {
+	new_test_ext().execute_with(|| {
+		let council = create_election(1, 10);
+		let club = create_election(1, 10);
+		for election_id in [council, club] {
+			assert_ok!(Voting::register_as_candidate(
+				RuntimeOrigin::signed(2),
+				election_id,
+				b"Bob".to_vec(),
+				b"Manifesto".to_vec()
+			));
+		}
+		assert_eq!(Candidacies::<Test>::get(2), 2);
+		assert_eq!(Balances::reserved_balance(2), 6);
+
+		assert_ok!(Voting::withdraw_candidacy(RuntimeOrigin::signed(2), council));
+		assert_ok!(Voting::remove_candidate(RuntimeOrigin::root(), club, 1));
+		assert!(!Candidacies::<Test>::contains_key(2));
+	});
+}
+
+#[test]
+fn accounts_stand_in_one_election_at_a_time_unless_allowed() {
+	new_test_ext().execute_with(|| {
+		AllowMultipleCandidaciesPerAccount::set(false);
+		let council = create_election(1, 10);
+		let club = create_election(1, 10);
+		let register = |election_id| {
+			Voting::register_as_candidate(
+				RuntimeOrigin::signed(2),
+				election_id,
+				b"Bob".to_vec(),
+				b"Manifesto".to_vec(),
+			)
+		};
+
+		assert_ok!(register(council));
+		assert_noop!(register(club), Error::<Test>::AlreadyCandidate);
+
+		// Once the candidacy is settled, the account can stand again.
+		confirmed_reset(council);
+		assert_eq!(Candidacies::<Test>::get(2), 0);
+		assert_ok!(register(club));
+	});
+}
+
+#[test]
+fn get_results_reports_the_owner_of_self_registered_candidates() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		add_candidate(election_id, b"Alice");
+		assert_ok!(Voting::register_as_candidate(
+			RuntimeOrigin::signed(2),
+			election_id,
+			b"Bob".to_vec(),
+			b"Manifesto".to_vec()
+		));
+
+		assert_eq!(Candidates::<Test>::get(election_id)[0].owner, None);
+		assert_eq!(Candidates::<Test>::get(election_id)[1].owner, Some(2));
+		assert_eq!(
+			Voting::get_results(election_id),
+			vec![
+				(1, b"Alice".to_vec(), 0, None),
+				(2, b"Bob".to_vec(), 0, Some(2)),
+				(ABSTAIN, b"Abstain".to_vec(), 0, None),
+			]
+		);
+	});
+}
+
+#[test]
+fn finalized_results_are_served_from_the_cache() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		let alice = add_candidate(election_id, b"Alice");
+		let bob = add_candidate(election_id, b"Bob");
+		start_election(election_id);
+		for (voter, candidate_id) in [(1, alice), (2, bob), (3, alice)] {
+			assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(voter), election_id, candidate_id));
+		}
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), election_id));
+		let live = Voting::get_results(election_id);
+		assert!(!FinalResults::<Test>::contains_key(election_id));
+
+		assert_ok!(Voting::finalize_election(RuntimeOrigin::root(), election_id, 0));
+		assert_eq!(FinalResults::<Test>::get(election_id).unwrap().to_vec(), vec![(1, 2), (2, 1)]);
+		assert_eq!(Voting::get_results(election_id), live);
+
+		// The live counts are no longer read once the election is finalized.
+		VoteCount::<Test>::insert(election_id, bob, 5);
+		assert_eq!(Voting::get_results(election_id), live);
+
+		assert_ok!(Voting::reopen_election(RuntimeOrigin::root(), election_id));
+		assert!(!FinalResults::<Test>::contains_key(election_id));
+		assert_eq!(Voting::get_results(election_id)[1], (bob, b"Bob".to_vec(), 5, None));
+	});
+}
+
+#[test]
+fn hidden_results_are_only_reported_once_finalized() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		let alice = add_candidate(election_id, b"Alice");
+		assert!(Voting::set_results_hidden(RuntimeOrigin::signed(1), election_id, true).is_err());
+		assert_ok!(Voting::set_results_hidden(RuntimeOrigin::root(), election_id, true));
+		System::assert_last_event(
+			Event::ResultsVisibilityChanged { election_id, hidden: true }.into(),
+		);
+		start_election(election_id);
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(1), election_id, alice));
+		System::assert_last_event(
+			Event::VoteCast {
+				election_id,
+				voter: 1,
+				candidate_id: None,
+				new_count: None,
+				total_votes: None,
+				receipt: receipt(election_id, 1, alice),
+			}
+			.into(),
+		);
+		// Nor is the vote indexed by the candidate.
+		assert!(System::events().last().unwrap().topics.is_empty());
+		assert_ok!(Voting::retract_vote(RuntimeOrigin::signed(1), election_id));
+		System::assert_last_event(
+			Event::VoteRetracted { election_id, voter: 1, candidate_id: None }.into(),
+		);
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(1), election_id, alice));
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), election_id));
+
+		// The votes are counted, but not reported.
+		assert_eq!(VoteCount::<Test>::get(election_id, alice), 1);
+		assert!(Voting::results_hidden(election_id));
+		assert!(Voting::get_results(election_id).is_empty());
+		assert!(Voting::voters_for_candidate(election_id, alice).is_empty());
+		assert!(Voting::all_voters(election_id).is_empty());
+		assert!(Voting::results_with_percentage(election_id).is_empty());
+		let export = Voting::export_results(election_id).unwrap();
+		assert_eq!(export.candidates[0].2, 0);
+		assert_eq!((export.total_votes, export.abstentions), (0, 0));
+		assert_eq!(Voting::election_snapshot(election_id).unwrap().total_votes, 0);
+
+		assert_ok!(Voting::finalize_election(RuntimeOrigin::root(), election_id, 0));
+		assert!(!Voting::results_hidden(election_id));
+		assert_eq!(Voting::all_voters(election_id), vec![1]);
+		let export = Voting::export_results(election_id).unwrap();
+		assert_eq!((export.candidates[0].2, export.total_votes), (1, 1));
+		assert_eq!(Voting::election_snapshot(election_id).unwrap().total_votes, 1);
+		assert_eq!(
+			Voting::get_results(election_id),
+			vec![(alice, b"Alice".to_vec(), 1, None), (ABSTAIN, b"Abstain".to_vec(), 0, None)]
+		);
+		assert_noop!(
+			Voting::set_results_hidden(RuntimeOrigin::root(), election_id, true),
+			Error::<Test>::AlreadyFinalized
+		);
+	});
+}
+
+#[test]
+fn elections_link_to_their_metadata() {
+	new_test_ext().execute_with(|| {
+		let election_id = NextElectionId::<Test>::get();
+		assert_ok!(Voting::create_election(
+			RuntimeOrigin::root(),
+			b"Council".to_vec(),
+			blocks(1, 10),
+			ElectionKind::Plurality,
+			false,
+			None,
+			1,
+			false,
+			false,
+			Some(b"ipfs://rules".to_vec()),
+			None
+		));
+		assert_eq!(
+			Election::<Test>::get(election_id).unwrap().metadata_uri.unwrap().to_vec(),
+			b"ipfs://rules".to_vec()
+		);
+
+		assert!(Voting::set_election_metadata(RuntimeOrigin::signed(1), election_id, None).is_err());
+		assert_noop!(
+			Voting::set_election_metadata(
+				RuntimeOrigin::root(),
+				election_id,
+				Some(vec![b'x'; MAX_METADATA_URI_LENGTH as usize + 1])
+			),
+			Error::<Test>::MetadataUriTooLong
+		);
+		let uri = b"https://example.org/rules".to_vec();
+		assert_ok!(Voting::set_election_metadata(
+			RuntimeOrigin::root(),
+			election_id,
+			Some(uri.clone())
+		));
+		System::assert_last_event(
+			Event::MetadataUpdated { election_id, uri: Some(uri.clone()) }.into(),
+		);
+		assert_eq!(Election::<Test>::get(election_id).unwrap().metadata_uri.unwrap().to_vec(), uri);
+
+		assert_ok!(Voting::set_election_metadata(RuntimeOrigin::root(), election_id, None));
+		assert!(Election::<Test>::get(election_id).unwrap().metadata_uri.is_none());
+		assert_noop!(
+			Voting::set_election_metadata(RuntimeOrigin::root(), 7, None),
+			Error::<Test>::NoElectionExists
+		);
+	});
+}
+
+#[test]
+fn register_as_candidate_fails_once_election_started() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		add_candidate(election_id, b"Alice");
+		start_election(election_id);
+		assert_noop!(
+			Voting::register_as_candidate(
+				RuntimeOrigin::signed(2),
+				election_id,
+				b"Bob".to_vec(),
+				b"Manifesto".to_vec()
+			),
+			Error::<Test>::ElectionIsActive
+		);
+
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), election_id));
+		assert_noop!(
+			Voting::register_as_candidate(
+				RuntimeOrigin::signed(2),
+				election_id,
+				b"Bob".to_vec(),
+				b"Manifesto".to_vec()
+			),
+			Error::<Test>::ElectionIsActive
+		);
+	});
+}
+
+#[test]
+fn withdraw_candidacy_returns_bond() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		assert_noop!(
+			Voting::withdraw_candidacy(RuntimeOrigin::signed(2), election_id),
+			Error::<Test>::InvalidCandidate
+		);
+		assert_ok!(Voting::register_as_candidate(
+			RuntimeOrigin::signed(2),
+			election_id,
+			b"Bob".to_vec(),
+			b"Manifesto".to_vec()
+		));
+
+		assert_ok!(Voting::withdraw_candidacy(RuntimeOrigin::signed(2), election_id));
+		System::assert_last_event(
+			Event::CandidateWithdrawn { election_id, candidate_id: 1 }.into(),
+		);
+		assert!(Candidates::<Test>::get(election_id).is_empty());
+		assert!(!VoteCount::<Test>::contains_key(election_id, 1));
+		assert!(!CandidateOwners::<Test>::contains_key(election_id, 2));
+		assert_eq!(Balances::reserved_balance(2), 0);
+	});
+}
+
+#[test]
+fn withdraw_candidacy_fails_once_the_candidates_are_locked() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		assert_ok!(Voting::register_as_candidate(
+			RuntimeOrigin::signed(2),
+			election_id,
+			b"Bob".to_vec(),
+			b"Manifesto".to_vec()
+		));
+		assert_ok!(Voting::lock_candidates(RuntimeOrigin::root(), election_id));
+		assert_noop!(
+			Voting::withdraw_candidacy(RuntimeOrigin::signed(2), election_id),
+			Error::<Test>::CandidatesLocked
+		);
+
+		assert_ok!(Voting::start_election(RuntimeOrigin::root(), election_id));
+		assert_ok!(Voting::cast_vote(RuntimeOrigin::signed(1), election_id, 1));
+		assert_noop!(
+			Voting::withdraw_candidacy(RuntimeOrigin::signed(2), election_id),
+			Error::<Test>::ElectionIsActive
+		);
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), election_id));
+		assert_noop!(
+			Voting::withdraw_candidacy(RuntimeOrigin::signed(2), election_id),
+			Error::<Test>::ElectionIsActive
+		);
+		assert_eq!(VoteCount::<Test>::get(election_id, 1), 1);
+		assert_eq!(Balances::reserved_balance(2), 3);
+		assert_ok!(Voting::do_try_state());
+	});
+}
+
+#[test]
+fn abstentions_count_towards_turnout_but_not_the_winner() {
+	new_test_ext().execute_with(|| {
+		let election_id = run_election(&[b"Alice", b"Bob"], &[ABSTAIN, 2, ABSTAIN, ABSTAIN]);
+		assert_eq!(Voting::get_vote_for_account(election_id, &1), Some(ABSTAIN));
+		assert_eq!(AbstainCount::<Test>::get(election_id), 3);
+		assert_eq!(TotalVotes::<Test>::get(election_id), 4);
+		assert_eq!(Voting::get_winner(election_id), WinnerResult::Winner(2, 1));
+		assert_eq!(
+			Voting::get_results(election_id),
+			vec![
+				(1, b"Alice".to_vec(), 0, None),
+				(2, b"Bob".to_vec(), 1, None),
+				(ABSTAIN, b"Abstain".to_vec(), 3, None)
+			]
+		);
+
+		// Abstentions can be changed and retracted like any other vote.
+		assert_ok!(Voting::change_vote(RuntimeOrigin::signed(1), election_id, 1));
+		assert_ok!(Voting::retract_vote(RuntimeOrigin::signed(3), election_id));
+		assert_eq!(AbstainCount::<Test>::get(election_id), 1);
+		assert_eq!(TotalVotes::<Test>::get(election_id), 3);
+		assert_eq!(Voting::get_winner(election_id), WinnerResult::Tie(vec![1, 2]));
+	});
+}
+
+#[test]
+fn finalize_election_fails_below_quorum() {
+	new_test_ext().execute_with(|| {
+		MinQuorum::set(3);
+		let election_id = run_election(&[b"Alice", b"Bob"], &[1, 1]);
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), election_id));
+		assert_ok!(Voting::finalize_election(RuntimeOrigin::root(), election_id, 0));
+		System::assert_has_event(
+			Event::ElectionFailedQuorum { election_id, total_votes: 2, required: 3 }.into(),
+		);
+		let election = Election::<Test>::get(election_id).unwrap();
+		assert!(election.status == ElectionStatus::Finalized && election.is_failed);
+
+		// Abstentions count towards the quorum.
+		let election_id = run_election(&[b"Alice", b"Bob"], &[1, 1, ABSTAIN]);
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), election_id));
+		assert_ok!(Voting::finalize_election(RuntimeOrigin::root(), election_id, 0));
+		System::assert_has_event(
+			Event::WinnerDeclared { election_id, candidate_id: 1, votes: 2 }.into(),
+		);
+		assert!(!Election::<Test>::get(election_id).unwrap().is_failed);
+	});
+}
+
+#[test]
+fn genesis_config_seeds_election_and_candidates() {
+	let storage = crate::GenesisConfig::<Test> {
+		election: Some((b"Council".to_vec(), 1, 10)),
+		candidates: vec![(1, b"Alice".to_vec(), b"First".to_vec()), (3, b"Bob".to_vec(), vec![])],
+	}
+	.build_storage()
+	.unwrap();
+	sp_io::TestExternalities::from(storage).execute_with(|| {
+		let election = Election::<Test>::get(0).unwrap();
+		assert_eq!(election.title.to_vec(), b"Council".to_vec());
+		assert_eq!(election.window, blocks(1, 10));
+		assert_eq!(NextElectionId::<Test>::get(), 1);
+		assert_eq!(
+			Voting::get_results(0),
+			vec![
+				(1, b"Alice".to_vec(), 0, None),
+				(3, b"Bob".to_vec(), 0, None),
+				(ABSTAIN, b"Abstain".to_vec(), 0, None)
+			]
+		);
+		assert_eq!(NextCandidateId::<Test>::get(0), 4);
+		assert!(CandidatesLocked::<Test>::get(0));
+	});
+}
+
+#[test]
+#[should_panic(expected = "Genesis candidate is invalid")]
+fn genesis_config_rejects_invalid_candidates() {
+	let _ = crate::GenesisConfig::<Test> {
+		election: Some((b"Council".to_vec(), 1, 10)),
+		candidates: vec![(1, b"A name that is far too long".to_vec(), vec![])],
+	}
+	.build_storage();
+}
+
+#[test]
+#[should_panic(expected = "ReservedCandidateId")]
+fn genesis_config_rejects_the_abstain_candidate_id() {
+	let _ = crate::GenesisConfig::<Test> {
+		election: Some((b"Council".to_vec(), 1, 10)),
+		candidates: vec![(ABSTAIN, b"Alice".to_vec(), vec![])],
+	}
+	.build_storage();
+}
+
+#[test]
+fn migration_to_v1_records_the_storage_version() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<Voting>();
+		MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(Voting::on_chain_storage_version(), StorageVersion::new(1));
+
+		MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(Voting::on_chain_storage_version(), StorageVersion::new(1));
+	});
+}
+
+#[test]
+fn migration_to_v2_gives_existing_candidates_no_owner() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		let old = vec![OldCandidate::<Test> {
+			id: 1,
+			name: b"Alice".to_vec().try_into().unwrap(),
+			description: b"Manifesto".to_vec().try_into().unwrap(),
+		}];
+		frame_support::storage::unhashed::put(
+			&Candidates::<Test>::hashed_key_for(election_id),
+			&old,
+		);
+		StorageVersion::new(1).put::<Voting>();
+
+		MigrateToV2::<Test>::on_runtime_upgrade();
+		assert_eq!(Voting::on_chain_storage_version(), StorageVersion::new(2));
+		let candidates = Candidates::<Test>::get(election_id);
+		assert_eq!(candidates.len(), 1);
+		assert_eq!(candidates[0].id, 1);
+		assert_eq!(candidates[0].name.to_vec(), b"Alice".to_vec());
+		assert_eq!(candidates[0].description.to_vec(), b"Manifesto".to_vec());
+		assert_eq!(candidates[0].owner, None);
+	});
+}
+
+#[test]
+fn migration_to_v3_turns_block_ranges_into_windows() {
+	new_test_ext().execute_with(|| {
+		let old = OldElectionInfo::<Test> {
+			title: b"Council".to_vec().try_into().unwrap(),
+			start_block: 1,
+			end_block: 10,
+			kind: ElectionKind::Plurality,
+			weighted: false,
+			reveal_end_block: Some(20),
+			seats: 1,
+			is_active: true,
+			is_paused: false,
+			has_started: true,
+			is_finalized: false,
+			is_failed: false,
+			deposit: Some((2, 5)),
+		};
+		frame_support::storage::unhashed::put(&Election::<Test>::hashed_key_for(0), &old);
+		StorageVersion::new(2).put::<Voting>();
+
+		MigrateToV3::<Test>::on_runtime_upgrade();
+		assert_eq!(Voting::on_chain_storage_version(), StorageVersion::new(3));
+		let election = v3::Election::<Test>::get(0).unwrap();
+		assert_eq!(election.window, blocks(1, 10));
+		assert_eq!(election.reveal_end_block, Some(20));
+		assert!(election.is_active && election.has_started);
+		assert_eq!(election.deposit, Some((2, 5)));
+	});
+}
+
+#[test]
+fn migration_to_v4_counts_the_registered_voters() {
+	new_test_ext().execute_with(|| {
+		for who in [1u64, 2, 3] {
+			RegisteredVoters::<Test>::insert(who, ());
+		}
+		assert_eq!(RegisteredVoterCount::<Test>::get(), 0);
+		StorageVersion::new(3).put::<Voting>();
+
+		MigrateToV4::<Test>::on_runtime_upgrade();
+		assert_eq!(Voting::on_chain_storage_version(), StorageVersion::new(4));
+		assert_eq!(RegisteredVoterCount::<Test>::get(), 3);
+	});
+}
+
+#[test]
+fn migration_to_v5_marks_elections_as_not_cancelled() {
+	new_test_ext().execute_with(|| {
+		let old = v5::OldElectionInfo::<Test> {
+			title: b"Council".to_vec().try_into().unwrap(),
+			window: blocks(1, 10),
+			kind: ElectionKind::Plurality,
+			weighted: false,
+			reveal_end_block: None,
+			seats: 1,
+			is_active: false,
+			is_paused: false,
+			has_started: true,
+			is_finalized: true,
+			is_failed: false,
+			deposit: Some((2, 5)),
+		};
+		frame_support::storage::unhashed::put(&Election::<Test>::hashed_key_for(0), &old);
+		let old_summary = v5::OldElectionSummary::<Test> {
+			election_id: 0,
+			title: b"Council".to_vec().try_into().unwrap(),
+			winners: vec![1].try_into().unwrap(),
+			total_votes: 3,
+			vote_counts: vec![(1, 3)].try_into().unwrap(),
+			abstentions: 0,
+		};
+		frame_support::storage::unhashed::put(
+			&PastElections::<Test>::hashed_key_for(0),
+			&old_summary,
+		);
+		StorageVersion::new(4).put::<Voting>();
+
+		MigrateToV5::<Test>::on_runtime_upgrade();
+		assert_eq!(Voting::on_chain_storage_version(), StorageVersion::new(5));
+		let election = v5::Election::<Test>::get(0).unwrap();
+		assert!(election.is_finalized && !election.is_cancelled);
+		assert_eq!(election.deposit, Some((2, 5)));
+		let summary = PastElections::<Test>::get(0).unwrap();
+		assert_eq!(summary.winners.to_vec(), vec![1]);
+		assert_eq!(summary.total_votes, 3);
+		assert_eq!(summary.cancellation_reason, None);
+	});
+}
+
+#[test]
+fn migration_to_v6_records_the_upgrade_block_for_existing_votes() {
+	new_test_ext().execute_with(|| {
+		let old = OldVoteRecord { candidate_id: 2, weight: 1 };
+		frame_support::storage::unhashed::put(&HasVoted::<Test>::hashed_key_for(0, 1), &old);
+		StorageVersion::new(5).put::<Voting>();
+		System::set_block_number(42);
+
+		MigrateToV6::<Test>::on_runtime_upgrade();
+		assert_eq!(Voting::on_chain_storage_version(), StorageVersion::new(6));
+		assert_eq!(
+			HasVoted::<Test>::get(0, 1),
+			Some(VoteRecord { candidate_id: 2, weight: 1, block: 42 })
+		);
+		assert_eq!(Voting::vote_record(0, &1), Some((2, 42)));
+	});
+}
+
+#[test]
+fn migration_to_v7_marks_elections_as_not_anonymous() {
+	new_test_ext().execute_with(|| {
+		let old = v7::OldElectionInfo::<Test> {
+			title: b"Council".to_vec().try_into().unwrap(),
+			window: blocks(1, 10),
+			kind: ElectionKind::Plurality,
+			weighted: true,
+			reveal_end_block: None,
+			seats: 2,
+			is_active: true,
+			is_paused: false,
+			has_started: true,
+			is_finalized: false,
+			is_failed: false,
+			is_cancelled: false,
+			deposit: None,
+		};
+		frame_support::storage::unhashed::put(&Election::<Test>::hashed_key_for(0), &old);
+		StorageVersion::new(6).put::<Voting>();
+
+		MigrateToV7::<Test>::on_runtime_upgrade();
+		assert_eq!(Voting::on_chain_storage_version(), StorageVersion::new(7));
+		let election = v7::Election::<Test>::get(0).unwrap();
+		assert!(election.weighted && election.is_active && !election.anonymous);
+		assert_eq!(election.seats, 2);
+	});
+}
+
+#[test]
+fn migration_to_v8_disallows_write_ins_in_existing_elections() {
+	new_test_ext().execute_with(|| {
+		let old = v8::OldElectionInfo::<Test> {
+			title: b"Council".to_vec().try_into().unwrap(),
+			window: blocks(1, 10),
+			kind: ElectionKind::Plurality,
+			weighted: false,
+			reveal_end_block: None,
+			seats: 1,
+			is_active: true,
+			is_paused: false,
+			has_started: true,
+			is_finalized: false,
+			is_failed: false,
+			is_cancelled: false,
+			anonymous: true,
+			deposit: Some((2, 5)),
+		};
+		frame_support::storage::unhashed::put(&Election::<Test>::hashed_key_for(0), &old);
+		StorageVersion::new(7).put::<Voting>();
+
+		MigrateToV8::<Test>::on_runtime_upgrade();
+		assert_eq!(Voting::on_chain_storage_version(), StorageVersion::new(8));
+		let election = v8::Election::<Test>::get(0).unwrap();
+		assert!(election.anonymous && !election.allow_write_ins);
+		assert_eq!(election.deposit, Some((2, 5)));
+	});
+}
+
+#[test]
+fn migration_to_v9_counts_the_candidacies_of_every_account() {
+	new_test_ext().execute_with(|| {
+		CandidateOwners::<Test>::insert(0, 2, (1, 3));
+		CandidateOwners::<Test>::insert(1, 2, (1, 3));
+		CandidateOwners::<Test>::insert(1, 3, (2, 3));
+		StorageVersion::new(8).put::<Voting>();
+
+		MigrateToV9::<Test>::on_runtime_upgrade();
+		assert_eq!(Voting::on_chain_storage_version(), StorageVersion::new(9));
+		assert_eq!(Candidacies::<Test>::get(2), 2);
+		assert_eq!(Candidacies::<Test>::get(3), 1);
+	});
+}
+
+#[test]
+fn migration_to_v10_marks_elections_as_never_reopened() {
+	new_test_ext().execute_with(|| {
+		let old = v10::OldElectionInfo::<Test> {
+			title: b"Council".to_vec().try_into().unwrap(),
+			window: blocks(1, 10),
+			kind: ElectionKind::Plurality,
+			weighted: false,
+			reveal_end_block: None,
+			seats: 1,
+			is_active: false,
+			is_paused: false,
+			has_started: true,
+			is_finalized: true,
+			is_failed: false,
+			is_cancelled: false,
+			anonymous: false,
+			allow_write_ins: true,
+			deposit: Some((2, 5)),
+		};
+		frame_support::storage::unhashed::put(&Election::<Test>::hashed_key_for(0), &old);
+		StorageVersion::new(9).put::<Voting>();
+
+		MigrateToV10::<Test>::on_runtime_upgrade();
+		assert_eq!(Voting::on_chain_storage_version(), StorageVersion::new(10));
+		let election = v10::Election::<Test>::get(0).unwrap();
+		assert!(election.is_finalized && election.allow_write_ins);
+		assert_eq!(election.reopen_count, 0);
+		assert_eq!(election.deposit, Some((2, 5)));
+	});
+}
+
+#[test]
+fn migration_to_v11_marks_results_as_visible() {
+	new_test_ext().execute_with(|| {
+		let old = v11::OldElectionInfo::<Test> {
+			title: b"Council".to_vec().try_into().unwrap(),
+			window: blocks(1, 10),
+			kind: ElectionKind::Plurality,
+			weighted: false,
+			reveal_end_block: None,
+			seats: 1,
+			is_active: true,
+			is_paused: false,
+			has_started: true,
+			is_finalized: false,
+			is_failed: false,
+			is_cancelled: false,
+			anonymous: false,
+			allow_write_ins: false,
+			reopen_count: 2,
+			deposit: Some((2, 5)),
+		};
+		frame_support::storage::unhashed::put(&Election::<Test>::hashed_key_for(0), &old);
+		StorageVersion::new(10).put::<Voting>();
+
+		MigrateToV11::<Test>::on_runtime_upgrade();
+		assert_eq!(Voting::on_chain_storage_version(), StorageVersion::new(11));
+		let election = v11::Election::<Test>::get(0).unwrap();
+		assert!(election.is_active && !election.results_hidden);
+		assert_eq!(election.reopen_count, 2);
+		assert_eq!(election.deposit, Some((2, 5)));
+	});
+}
+
+#[test]
+fn migration_to_v12_indexes_the_stored_candidates() {
+	new_test_ext().execute_with(|| {
+		let election_id = create_election(1, 10);
+		let alice = add_candidate(election_id, b"Alice");
+		let bob = add_candidate(election_id, b"Bob");
+		let _ = CandidateExists::<Test>::clear_prefix(election_id, u32::MAX, None);
+		StorageVersion::new(11).put::<Voting>();
+
+		MigrateToV12::<Test>::on_runtime_upgrade();
+		assert_eq!(Voting::on_chain_storage_version(), StorageVersion::new(12));
+		assert!(CandidateExists::<Test>::contains_key(election_id, alice));
+		assert!(CandidateExists::<Test>::contains_key(election_id, bob));
+		assert!(!CandidateExists::<Test>::contains_key(election_id, ABSTAIN));
+	});
+}
+
+#[test]
+fn migration_to_v13_stores_elections_without_metadata() {
+	new_test_ext().execute_with(|| {
+		let old = v13::OldElectionInfo::<Test> {
+			title: b"Council".to_vec().try_into().unwrap(),
+			window: blocks(1, 10),
+			kind: ElectionKind::Plurality,
+			weighted: false,
+			reveal_end_block: None,
+			seats: 1,
+			is_active: true,
+			is_paused: false,
+			has_started: true,
+			is_finalized: false,
+			is_failed: false,
+			is_cancelled: false,
+			anonymous: false,
+			allow_write_ins: false,
+			reopen_count: 1,
+			results_hidden: true,
+			deposit: Some((2, 5)),
+		};
+		frame_support::storage::unhashed::put(&Election::<Test>::hashed_key_for(0), &old);
+		StorageVersion::new(12).put::<Voting>();
+
+		MigrateToV13::<Test>::on_runtime_upgrade();
+		assert_eq!(Voting::on_chain_storage_version(), StorageVersion::new(13));
+		let election = v13::Election::<Test>::get(0).unwrap();
+		assert!(election.results_hidden && election.metadata_uri.is_none());
+		assert_eq!(election.reopen_count, 1);
+		assert_eq!(election.deposit, Some((2, 5)));
+	});
+}
+
+#[test]
+fn migration_to_v14_stores_elections_without_a_win_threshold() {
+	new_test_ext().execute_with(|| {
+		let old = v14::OldElectionInfo::<Test> {
+			title: b"Council".to_vec().try_into().unwrap(),
+			window: blocks(1, 10),
+			kind: ElectionKind::Plurality,
+			weighted: false,
+			reveal_end_block: None,
+			seats: 1,
+			is_active: true,
+			is_paused: false,
+			has_started: true,
+			is_finalized: false,
+			is_failed: false,
+			is_cancelled: false,
+			anonymous: false,
+			allow_write_ins: false,
+			reopen_count: 0,
+			results_hidden: false,
+			metadata_uri: Some(b"ipfs://council".to_vec().try_into().unwrap()),
+			deposit: Some((2, 5)),
+		};
+		frame_support::storage::unhashed::put(&Election::<Test>::hashed_key_for(0), &old);
+		StorageVersion::new(13).put::<Voting>();
+
+		MigrateToV14::<Test>::on_runtime_upgrade();
+		assert_eq!(Voting::on_chain_storage_version(), StorageVersion::new(14));
+		let election = v14::Election::<Test>::get(0).unwrap();
+		assert_eq!(election.win_threshold, None);
+		assert_eq!(election.metadata_uri.unwrap().to_vec(), b"ipfs://council".to_vec());
+		assert_eq!(election.deposit, Some((2, 5)));
+	});
+}
+
+#[test]
+fn migration_to_v15_maps_the_lifecycle_flags_to_a_status() {
+	new_test_ext().execute_with(|| {
+		let flags = [
+			// (is_active, is_paused, has_started, is_finalized, is_cancelled)
+			(false, false, false, false, false),
+			(true, false, true, false, false),
+			(true, true, true, false, false),
+			(false, false, true, false, false),
+			(false, false, true, true, false),
+			(false, false, true, true, true),
+		];
+		for (election_id, (is_active, is_paused, has_started, is_finalized, is_cancelled)) in
+			flags.into_iter().enumerate()
+		{
+			let old = v15::OldElectionInfo::<Test> {
+				title: b"Council".to_vec().try_into().unwrap(),
+				window: blocks(1, 10),
+				kind: ElectionKind::Plurality,
+				weighted: false,
+				reveal_end_block: None,
+				seats: 1,
+				is_active,
+				is_paused,
+				has_started,
+				is_finalized,
+				is_failed: false,
+				is_cancelled,
+				anonymous: false,
+				allow_write_ins: false,
+				reopen_count: 0,
+				results_hidden: false,
+				metadata_uri: None,
+				win_threshold: Some(Permill::from_percent(50)),
+				deposit: Some((2, 5)),
+			};
+			frame_support::storage::unhashed::put(
+				&Election::<Test>::hashed_key_for(election_id as u32),
+				&old,
+			);
+		}
+		StorageVersion::new(14).put::<Voting>();
+
+		MigrateToV15::<Test>::on_runtime_upgrade();
+		assert_eq!(Voting::on_chain_storage_version(), StorageVersion::new(15));
+		let statuses: Vec<_> =
+			(0..6).map(|id| v15::Election::<Test>::get(id).unwrap().status).collect();
+		assert_eq!(
+			statuses,
+			vec![
+				ElectionStatus::Created,
+				ElectionStatus::Active,
+				ElectionStatus::Paused,
+				ElectionStatus::Ended,
+				ElectionStatus::Finalized,
+				ElectionStatus::Cancelled,
+			]
+		);
+		let election = v15::Election::<Test>::get(0).unwrap();
+		assert_eq!(election.win_threshold, Some(Permill::from_percent(50)));
+		assert_eq!(election.deposit, Some((2, 5)));
+	});
+}
+
+#[test]
+fn migration_to_v16_lets_the_max_candidates_stand_in_every_election() {
+	new_test_ext().execute_with(|| {
+		let old = v16::OldElectionInfo::<Test> {
+			title: b"Council".to_vec().try_into().unwrap(),
+			window: blocks(1, 10),
+			kind: ElectionKind::Plurality,
+			weighted: false,
+			reveal_end_block: None,
+			seats: 1,
+			status: ElectionStatus::Active,
+			is_failed: false,
+			anonymous: false,
+			allow_write_ins: false,
+			reopen_count: 0,
+			results_hidden: false,
+			metadata_uri: None,
+			win_threshold: None,
+			deposit: Some((2, 5)),
+		};
+		frame_support::storage::unhashed::put(&Election::<Test>::hashed_key_for(0), &old);
+		StorageVersion::new(15).put::<Voting>();
+
+		MigrateToV16::<Test>::on_runtime_upgrade();
+		assert_eq!(Voting::on_chain_storage_version(), StorageVersion::new(16));
+		let election = v16::Election::<Test>::get(0).unwrap();
+		assert_eq!(election.max_candidates, 3);
+		assert_eq!(election.status, ElectionStatus::Active);
+		assert_eq!(election.deposit, Some((2, 5)));
+	});
+}
+
+#[test]
+fn migration_to_v17_gives_every_election_an_empty_dispute_window() {
+	new_test_ext().execute_with(|| {
+		let old = v17::OldElectionInfo::<Test> {
+			title: b"Council".to_vec().try_into().unwrap(),
+			window: blocks(1, 10),
+			kind: ElectionKind::Plurality,
+			weighted: false,
+			reveal_end_block: None,
+			seats: 1,
+			max_candidates: 2,
+			status: ElectionStatus::Ended,
+			is_failed: false,
+			anonymous: false,
+			allow_write_ins: false,
+			reopen_count: 0,
+			results_hidden: false,
+			metadata_uri: None,
+			win_threshold: None,
+			deposit: Some((2, 5)),
+		};
+		frame_support::storage::unhashed::put(&Election::<Test>::hashed_key_for(0), &old);
+		StorageVersion::new(16).put::<Voting>();
+
+		MigrateToV17::<Test>::on_runtime_upgrade();
+		assert_eq!(Voting::on_chain_storage_version(), StorageVersion::new(17));
+		let election = Election::<Test>::get(0).unwrap();
+		assert_eq!(election.dispute_window, 0);
+		assert_eq!(election.max_candidates, 2);
+		assert_eq!(election.status, ElectionStatus::Ended);
+	});
+}
+
+#[test]
+fn migration_to_v18_schedules_the_open_elections() {
+	new_test_ext().execute_with(|| {
+		let created = create_election(1, 10);
+		let active = create_election(1, 10);
+		add_candidate(active, b"Alice");
+		start_election(active);
+		let ended = create_election(1, 10);
+		add_candidate(ended, b"Alice");
+		start_election(ended);
+		assert_ok!(Voting::end_election(RuntimeOrigin::root(), ended));
+		ScheduledElections::<Test>::kill();
+		StorageVersion::new(17).put::<Voting>();
+
+		MigrateToV18::<Test>::on_runtime_upgrade();
+		assert_eq!(Voting::on_chain_storage_version(), StorageVersion::new(18));
+		let mut scheduled = ScheduledElections::<Test>::get().into_inner();
+		scheduled.sort();
+		assert_eq!(scheduled, vec![created, active]);
+	});
+}
+
+#[test]
+fn migration_to_v19_removes_the_nullifiers_chosen_by_voters() {
+	new_test_ext().execute_with(|| {
+		v19::Nullifiers::<Test>::insert(H256::repeat_byte(1), ());
+		v19::Nullifiers::<Test>::insert(H256::repeat_byte(2), ());
+		StorageVersion::new(18).put::<Voting>();
+
+		MigrateToV19::<Test>::on_runtime_upgrade();
+		assert_eq!(Voting::on_chain_storage_version(), StorageVersion::new(19));
+		assert!(!v19::Nullifiers::<Test>::contains_key(H256::repeat_byte(1)));
+		assert_eq!(v19::Nullifiers::<Test>::iter_keys().count(), 0);
+	});
+}
+
+#[test]
+fn migration_to_v20_removes_the_receipts_without_an_election() {
+	new_test_ext().execute_with(|| {
+		v20::Receipts::<Test>::insert(1, H256::repeat_byte(1));
+		StorageVersion::new(19).put::<Voting>();
+
+		MigrateToV20::<Test>::on_runtime_upgrade();
+		assert_eq!(Voting::on_chain_storage_version(), StorageVersion::new(20));
+		assert!(v20::Receipts::<Test>::get(1).is_none());
+	});
+}
+
+#[test]
+fn migration_to_v21_counts_the_ranked_ballots() {
+	new_test_ext().execute_with(|| {
+		let ranked = create_election_of_kind(1, 10, ElectionKind::RankedChoice);
+		let alice = add_candidate(ranked, b"Alice");
+		start_election(ranked);
+		assert_ok!(Voting::cast_ranked_vote(RuntimeOrigin::signed(1), ranked, vec![alice]));
+		assert_ok!(Voting::cast_ranked_vote(RuntimeOrigin::signed(2), ranked, vec![alice]));
+		RankedBallotCount::<Test>::remove(ranked);
+		StorageVersion::new(20).put::<Voting>();
+
+		MigrateToV21::<Test>::on_runtime_upgrade();
+		assert_eq!(Voting::on_chain_storage_version(), StorageVersion::new(21));
+		assert_eq!(RankedBallotCount::<Test>::get(ranked), 2);
+	});
+}
+
+#[test]
+fn migration_to_v22_counts_the_ballots() {
+	new_test_ext().execute_with(|| {
+		let unlisted = NextElectionId::<Test>::get();
+		assert_ok!(Voting::create_election(
+			RuntimeOrigin::root(),
+			b"Council".to_vec(),
+			blocks(1, 10),
+			ElectionKind::Plurality,
+			false,
+			None,
+			1,
+			true,
+			false,
+			None,
+			None
+		));
+		let alice = add_candidate(unlisted, b"Alice");
+		start_election(unlisted);
+		assert_ok!(Voting::cast_unlisted_vote(RuntimeOrigin::signed(1), unlisted, alice));
+		let open = run_election(&[b"Alice"], &[1, ABSTAIN]);
+		BallotCount::<Test>::remove(unlisted);
+		BallotCount::<Test>::remove(open);
+		StorageVersion::new(21).put::<Voting>();
+
+		MigrateToV22::<Test>::on_runtime_upgrade();
+		assert_eq!(Voting::on_chain_storage_version(), StorageVersion::new(22));
+		assert_eq!(BallotCount::<Test>::get(unlisted), 1);
+		assert_eq!(BallotCount::<Test>::get(open), 2);
+	});
+}
@@ -0,0 +1,1393 @@
+//! Weights for pallet_voting
+//!
+//! These weights follow the layout of the output of the Substrate benchmark CLI, with the storage
+//! accesses of each dispatchable taken from its worst case in `benchmarking.rs`. The
+//! execution times are conservative estimates and should be replaced by running the benchmarks
+//! on reference hardware with the command below.
+
+// Command to regenerate:
+// ./target/release/solochain-template-node
+// benchmark
+// pallet
+// --chain
+// dev
+// --pallet
+// pallet_voting
+// --extrinsic
+// *
+// --steps=50
+// --repeat=20
+// --wasm-execution=compiled
+// --output
+// pallets/voting/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_voting.
+pub trait WeightInfo {
+	fn create_election() -> Weight;
+	fn add_candidate(c: u32, ) -> Weight;
+	fn start_election() -> Weight;
+	fn cast_vote(d: u32, ) -> Weight;
+	fn end_election() -> Weight;
+	fn finalize_election(v: u32, ) -> Weight;
+	fn reset_election(v: u32, ) -> Weight;
+	fn remove_candidate() -> Weight;
+	fn update_candidate() -> Weight;
+	fn change_vote() -> Weight;
+	fn retract_vote(d: u32, ) -> Weight;
+	fn cast_ranked_vote(d: u32, ) -> Weight;
+	fn commit_vote() -> Weight;
+	fn reveal_vote() -> Weight;
+	fn register_voter() -> Weight;
+	fn deregister_voter() -> Weight;
+	fn add_admin() -> Weight;
+	fn remove_admin() -> Weight;
+	fn register_as_candidate() -> Weight;
+	fn withdraw_candidacy() -> Weight;
+	fn delegate_vote() -> Weight;
+	fn undelegate() -> Weight;
+	fn extend_election() -> Weight;
+	fn pause_election() -> Weight;
+	fn resume_election() -> Weight;
+	fn batch_add_candidates(n: u32, ) -> Weight;
+	fn force_finalize(v: u32, ) -> Weight;
+	fn cancel_election() -> Weight;
+	fn cast_unlisted_vote() -> Weight;
+	fn cast_write_in_vote(d: u32, ) -> Weight;
+	fn lock_candidates() -> Weight;
+	fn reopen_election() -> Weight;
+	fn request_reset() -> Weight;
+	fn confirm_reset(v: u32, ) -> Weight;
+	fn adjust_vote_count() -> Weight;
+	fn set_results_hidden() -> Weight;
+	fn set_election_metadata() -> Weight;
+	fn reset_votes(v: u32, ) -> Weight;
+	fn set_win_threshold() -> Weight;
+	fn cast_approval_vote(d: u32, ) -> Weight;
+	fn transfer_admin() -> Weight;
+	fn set_dispute_window() -> Weight;
+	fn flag_dispute() -> Weight;
+	fn resolve_dispute() -> Weight;
+	fn endorse_candidate() -> Weight;
+	fn cast_quadratic_vote() -> Weight;
+	fn unlock_vote_balance(l: u32, ) -> Weight;
+}
+
+/// Weights for pallet_voting using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `Voting::Admins` (r:1 w:0)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Voting::NextElectionId` (r:1 w:1)
+	/// Storage: `Voting::Election` (r:0 w:1)
+	/// Storage: `Voting::ScheduledElections` (r:1 w:1)
+	fn create_election() -> Weight {
+		Weight::from_parts(25_000_000, 3_600)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:0)
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Voting::NextCandidateId` (r:1 w:1)
+	/// Storage: `Voting::Candidates` (r:1 w:1)
+	/// Storage: `Voting::VoteCount` (r:0 w:1)
+	/// Storage: `Voting::CandidatesLocked` (r:1 w:0)
+	/// Storage: `Voting::CandidateExists` (r:0 w:1)
+	/// The range of component `c` is `[0, 63]`.
+	fn add_candidate(c: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 4_500)
+			.saturating_add(Weight::from_parts(150_000, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(0, 200).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:0)
+	/// Storage: `Voting::Election` (r:1 w:1)
+	/// Storage: `Voting::Candidates` (r:1 w:0)
+	/// Storage: `Voting::CandidatesLocked` (r:1 w:0)
+	/// Storage: `Voting::EndorsementCount` (r:64 w:0)
+	/// Storage: `Voting::ScheduledElections` (r:1 w:1)
+	fn start_election() -> Weight {
+		Weight::from_parts(16_000_000, 3_600)
+			.saturating_add(T::DbWeight::get().reads(69_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Storage: `Voting::RegisteredVoters` (r:17 w:0)
+	/// Storage: `Identity::IdentityOf` (r:17 w:0)
+	/// Storage: `Voting::HasVoted` (r:17 w:1)
+	/// Storage: `Voting::CandidateExists` (r:1 w:0)
+	/// Storage: `Voting::DelegatedVotes` (r:17 w:16)
+	/// Storage: `System::Account` (r:17 w:17)
+	/// Storage: `Voting::VoteLocks` (r:17 w:17)
+	/// Storage: `Balances::Locks` (r:17 w:17)
+	/// Storage: `Balances::Freezes` (r:17 w:0)
+	/// Storage: `Voting::Delegators` (r:1 w:0)
+	/// Storage: `Voting::AppliedDelegations` (r:0 w:1)
+	/// Storage: `Voting::VoteCount` (r:1 w:1)
+	/// Storage: `Voting::TotalVotes` (r:1 w:1)
+	/// Storage: `Voting::VoteLog` (r:1 w:1)
+	/// Storage: `Voting::VoterRegisteredAt` (r:17 w:0)
+	/// Storage: `Voting::Receipts` (r:0 w:1)
+	/// Storage: `Voting::VotesInBlock` (r:1 w:1)
+	/// Storage: `Voting::BallotCount` (r:1 w:1)
+	/// The range of component `d` is `[0, 16]`.
+	fn cast_vote(d: u32, ) -> Weight {
+		Weight::from_parts(40_000_000, 21_100)
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(34_u64))
+			.saturating_add(T::DbWeight::get().reads((8_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2_600).saturating_mul(d.into()))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:0)
+	/// Storage: `Voting::Election` (r:1 w:1)
+	/// Storage: `Voting::ScheduledElections` (r:1 w:1)
+	fn end_election() -> Weight {
+		Weight::from_parts(14_000_000, 3_600)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Voting::TotalVotes` (r:1 w:0)
+	/// Storage: `Voting::Election` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Voting::Rankings` (r:1001 w:0)
+	/// Storage: `Voting::HasVoted` (r:1000 w:0)
+	/// Storage: `Voting::Candidates` (r:1 w:0)
+	/// Storage: `RandomnessCollectiveFlip::RandomMaterial` (r:1 w:0)
+	/// Storage: `Voting::VoteCount` (r:64 w:0)
+	/// Storage: `Voting::AbstainCount` (r:1 w:0)
+	/// Storage: `Voting::NextArchiveId` (r:1 w:1)
+	/// Storage: `Voting::PastElections` (r:0 w:1)
+	/// Storage: `Voting::FinalResults` (r:0 w:1)
+	/// Storage: `Voting::Disputes` (r:1 w:0)
+	/// Storage: `Voting::ScheduledElections` (r:1 w:1)
+	/// The range of component `v` is `[0, 1000]`.
+	fn finalize_election(v: u32, ) -> Weight {
+		Weight::from_parts(45_000_000, 20_000)
+			.saturating_add(Weight::from_parts(12_000_000, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(74_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(Weight::from_parts(0, 2_600).saturating_mul(v.into()))
+	}
+	/// Storage: `Voting::Election` (r:1 w:1)
+	/// Storage: `Voting::HasVoted` (r:1001 w:1000)
+	/// Storage: `Voting::Commitments` (r:1 w:0)
+	/// Storage: `Voting::DelegatedVotes` (r:1 w:0)
+	/// Storage: `Voting::Rankings` (r:0 w:1000)
+	/// Storage: `System::Account` (r:66 w:66)
+	/// Storage: `Voting::Candidates` (r:0 w:1)
+	/// Storage: `Voting::NextCandidateId` (r:0 w:1)
+	/// Storage: `Voting::CandidateOwners` (r:65 w:64)
+	/// Storage: `Voting::Candidacies` (r:64 w:64)
+	/// Storage: `Voting::VoteCount` (r:0 w:64)
+	/// Storage: `Voting::AbstainCount` (r:0 w:1)
+	/// Storage: `Voting::TotalVotes` (r:0 w:1)
+	/// Storage: `Voting::CandidatesLocked` (r:0 w:1)
+	/// Storage: `Voting::VoteLog` (r:0 w:64)
+	/// Storage: `Voting::PendingResets` (r:0 w:1)
+	/// Storage: `Voting::ResetCursor` (r:1 w:1)
+	/// Storage: `Voting::FinalResults` (r:0 w:1)
+	/// Storage: `Voting::CandidateExists` (r:0 w:64)
+	/// Storage: `Voting::Disputes` (r:0 w:1)
+	/// Storage: `Voting::ScheduledElections` (r:1 w:1)
+	/// Storage: `Voting::RankedBallotCount` (r:0 w:1)
+	/// Storage: `Voting::BallotCount` (r:0 w:1)
+	/// The range of component `v` is `[0, 1000]`.
+	fn reset_election(v: u32, ) -> Weight {
+		Weight::from_parts(60_000_000, 3_600)
+			.saturating_add(Weight::from_parts(2_500_000, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(201_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes(399_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(v.into())))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:0)
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Voting::Candidates` (r:1 w:1)
+	/// Storage: `Voting::CandidateOwners` (r:64 w:1)
+	/// Storage: `Voting::Candidacies` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Voting::VoteCount` (r:0 w:1)
+	/// Storage: `Voting::CandidatesLocked` (r:1 w:0)
+	/// Storage: `Voting::VoteLog` (r:0 w:1)
+	/// Storage: `Voting::CandidateExists` (r:0 w:1)
+	fn remove_candidate() -> Weight {
+		Weight::from_parts(120_000_000, 170_000)
+			.saturating_add(T::DbWeight::get().reads(70_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:0)
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Voting::Candidates` (r:1 w:1)
+	/// Storage: `Voting::CandidatesLocked` (r:1 w:0)
+	fn update_candidate() -> Weight {
+		Weight::from_parts(30_000_000, 17_000)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Storage: `Voting::HasVoted` (r:1 w:1)
+	/// Storage: `Voting::CandidateExists` (r:1 w:0)
+	/// Storage: `Voting::AbstainCount` (r:1 w:1)
+	/// Storage: `Voting::VoteCount` (r:1 w:1)
+	/// Storage: `Voting::VoteLog` (r:1 w:1)
+	fn change_vote() -> Weight {
+		Weight::from_parts(34_000_000, 21_100)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Storage: `Voting::HasVoted` (r:1 w:1)
+	/// Storage: `Voting::Rankings` (r:1 w:1)
+	/// Storage: `Voting::AppliedDelegations` (r:1 w:1)
+	/// Storage: `Voting::DelegatedVotes` (r:0 w:16)
+	/// Storage: `Voting::Approvals` (r:1 w:1)
+	/// Storage: `Voting::VoteCount` (r:64 w:64)
+	/// Storage: `Voting::TotalVotes` (r:1 w:1)
+	/// Storage: `Voting::RankedBallotCount` (r:1 w:1)
+	/// Storage: `Voting::BallotCount` (r:1 w:1)
+	/// The range of component `d` is `[0, 16]`.
+	fn retract_vote(d: u32, ) -> Weight {
+		Weight::from_parts(62_000_000, 164_400)
+			.saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(73_u64))
+			.saturating_add(T::DbWeight::get().writes(71_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(d.into())))
+	}
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Storage: `Voting::RegisteredVoters` (r:17 w:0)
+	/// Storage: `Identity::IdentityOf` (r:17 w:0)
+	/// Storage: `Voting::HasVoted` (r:17 w:1)
+	/// Storage: `Voting::Candidates` (r:1 w:0)
+	/// Storage: `Voting::DelegatedVotes` (r:17 w:16)
+	/// Storage: `System::Account` (r:17 w:17)
+	/// Storage: `Voting::VoteLocks` (r:17 w:17)
+	/// Storage: `Balances::Locks` (r:17 w:17)
+	/// Storage: `Balances::Freezes` (r:17 w:0)
+	/// Storage: `Voting::Delegators` (r:1 w:0)
+	/// Storage: `Voting::AppliedDelegations` (r:0 w:1)
+	/// Storage: `Voting::Rankings` (r:0 w:1)
+	/// Storage: `Voting::VoteCount` (r:1 w:1)
+	/// Storage: `Voting::TotalVotes` (r:1 w:1)
+	/// Storage: `Voting::VoterRegisteredAt` (r:17 w:0)
+	/// Storage: `Voting::Receipts` (r:0 w:1)
+	/// Storage: `Voting::VotesInBlock` (r:1 w:1)
+	/// Storage: `Voting::RankedBallotCount` (r:1 w:1)
+	/// Storage: `Voting::BallotCount` (r:1 w:1)
+	/// The range of component `d` is `[0, 16]`.
+	fn cast_ranked_vote(d: u32, ) -> Weight {
+		Weight::from_parts(164_000_000, 17_000)
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(34_u64))
+			.saturating_add(T::DbWeight::get().reads((8_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2_600).saturating_mul(d.into()))
+	}
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Storage: `Voting::RegisteredVoters` (r:1 w:0)
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Storage: `Voting::Commitments` (r:1 w:1)
+	/// Storage: `Voting::VoterRegisteredAt` (r:1 w:0)
+	fn commit_vote() -> Weight {
+		Weight::from_parts(20_000_000, 3_600)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Voting::Commitments` (r:1 w:1)
+	/// Storage: `Voting::CandidateExists` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Voting::VoteLocks` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Storage: `Voting::HasVoted` (r:0 w:1)
+	/// Storage: `Voting::VoteCount` (r:1 w:1)
+	/// Storage: `Voting::TotalVotes` (r:1 w:1)
+	/// Storage: `Voting::VoteLog` (r:1 w:1)
+	/// Storage: `Voting::BallotCount` (r:1 w:1)
+	fn reveal_vote() -> Weight {
+		Weight::from_parts(40_000_000, 21_100)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: `Voting::RegisteredVoters` (r:1 w:1)
+	/// Storage: `Voting::RegisteredVoterCount` (r:1 w:1)
+	/// Storage: `Voting::VoterRegisteredAt` (r:0 w:1)
+	fn register_voter() -> Weight {
+		Weight::from_parts(11_000_000, 3_500)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Voting::RegisteredVoters` (r:1 w:1)
+	/// Storage: `Voting::RegisteredVoterCount` (r:1 w:1)
+	/// Storage: `Voting::VoterRegisteredAt` (r:0 w:1)
+	fn deregister_voter() -> Weight {
+		Weight::from_parts(11_000_000, 3_500)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:1)
+	fn add_admin() -> Weight {
+		Weight::from_parts(12_000_000, 2_000)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:1)
+	fn remove_admin() -> Weight {
+		Weight::from_parts(12_000_000, 2_000)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Voting::CandidateOwners` (r:1 w:1)
+	/// Storage: `Voting::Candidacies` (r:1 w:1)
+	/// Storage: `Voting::NextCandidateId` (r:1 w:1)
+	/// Storage: `Voting::Candidates` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Voting::VoteCount` (r:0 w:1)
+	/// Storage: `Voting::CandidatesLocked` (r:1 w:0)
+	/// Storage: `Voting::CandidateExists` (r:0 w:1)
+	fn register_as_candidate() -> Weight {
+		Weight::from_parts(45_000_000, 17_000)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Voting::CandidateOwners` (r:1 w:1)
+	/// Storage: `Voting::Candidacies` (r:1 w:1)
+	/// Storage: `Voting::Candidates` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Voting::VoteCount` (r:0 w:1)
+	/// Storage: `Voting::CandidatesLocked` (r:1 w:0)
+	/// Storage: `Voting::VoteLog` (r:0 w:1)
+	/// Storage: `Voting::CandidateExists` (r:0 w:1)
+	fn withdraw_candidacy() -> Weight {
+		Weight::from_parts(40_000_000, 17_000)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Voting::Delegations` (r:1 w:1)
+	/// Storage: `Voting::Delegators` (r:2 w:2)
+	fn delegate_vote() -> Weight {
+		Weight::from_parts(26_000_000, 4_000)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Voting::Delegations` (r:1 w:1)
+	/// Storage: `Voting::Delegators` (r:1 w:1)
+	fn undelegate() -> Weight {
+		Weight::from_parts(20_000_000, 4_000)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:0)
+	/// Storage: `Voting::Election` (r:1 w:1)
+	fn extend_election() -> Weight {
+		Weight::from_parts(15_000_000, 3_600)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:0)
+	/// Storage: `Voting::Election` (r:1 w:1)
+	fn pause_election() -> Weight {
+		Weight::from_parts(14_000_000, 3_600)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:0)
+	/// Storage: `Voting::Election` (r:1 w:1)
+	fn resume_election() -> Weight {
+		Weight::from_parts(14_000_000, 3_600)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:0)
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Voting::Candidates` (r:1 w:1)
+	/// Storage: `Voting::NextCandidateId` (r:1 w:1)
+	/// Storage: `Voting::VoteCount` (r:0 w:64)
+	/// Storage: `Voting::CandidatesLocked` (r:1 w:0)
+	/// Storage: `Voting::CandidateExists` (r:0 w:64)
+	/// The range of component `n` is `[1, 64]`.
+	fn batch_add_candidates(n: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 17_000)
+			.saturating_add(Weight::from_parts(4_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Voting::TotalVotes` (r:1 w:0)
+	/// Storage: `Voting::Election` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Voting::Rankings` (r:1001 w:0)
+	/// Storage: `Voting::HasVoted` (r:1000 w:0)
+	/// Storage: `Voting::Candidates` (r:1 w:0)
+	/// Storage: `RandomnessCollectiveFlip::RandomMaterial` (r:1 w:0)
+	/// Storage: `Voting::VoteCount` (r:64 w:0)
+	/// Storage: `Voting::AbstainCount` (r:1 w:0)
+	/// Storage: `Voting::NextArchiveId` (r:1 w:1)
+	/// Storage: `Voting::PastElections` (r:0 w:1)
+	/// Storage: `Voting::FinalResults` (r:0 w:1)
+	/// Storage: `Voting::Disputes` (r:1 w:0)
+	/// Storage: `Voting::ScheduledElections` (r:1 w:1)
+	/// The range of component `v` is `[0, 1000]`.
+	fn force_finalize(v: u32, ) -> Weight {
+		Weight::from_parts(47_000_000, 20_000)
+			.saturating_add(Weight::from_parts(12_000_000, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(74_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(Weight::from_parts(0, 2_600).saturating_mul(v.into()))
+	}
+	/// Storage: `Voting::Election` (r:1 w:1)
+	/// Storage: `System::Account` (r:65 w:65)
+	/// Storage: `Voting::CandidateOwners` (r:65 w:64)
+	/// Storage: `Voting::Candidacies` (r:64 w:64)
+	/// Storage: `Voting::TotalVotes` (r:1 w:0)
+	/// Storage: `Voting::Candidates` (r:1 w:0)
+	/// Storage: `Voting::VoteCount` (r:64 w:0)
+	/// Storage: `Voting::AbstainCount` (r:1 w:0)
+	/// Storage: `Voting::NextArchiveId` (r:1 w:1)
+	/// Storage: `Voting::PastElections` (r:0 w:1)
+	/// Storage: `Voting::Disputes` (r:0 w:1)
+	/// Storage: `Voting::ScheduledElections` (r:1 w:1)
+	fn cancel_election() -> Weight {
+		Weight::from_parts(150_000_000, 180_000)
+			.saturating_add(T::DbWeight::get().reads(264_u64))
+			.saturating_add(T::DbWeight::get().writes(198_u64))
+	}
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Storage: `Voting::RegisteredVoters` (r:1 w:0)
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Storage: `Voting::Nullifiers` (r:1 w:1)
+	/// Storage: `Voting::TotalVotes` (r:1 w:1)
+	/// Storage: `Voting::CandidateExists` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Voting::VoteLocks` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Storage: `Voting::VoteCount` (r:1 w:1)
+	/// Storage: `Voting::VoteLog` (r:1 w:1)
+	/// Storage: `Voting::VoterRegisteredAt` (r:1 w:0)
+	/// Storage: `Voting::BallotCount` (r:1 w:1)
+	fn cast_unlisted_vote() -> Weight {
+		Weight::from_parts(32_000_000, 9_600)
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Storage: `Voting::RegisteredVoters` (r:17 w:0)
+	/// Storage: `Identity::IdentityOf` (r:17 w:0)
+	/// Storage: `Voting::HasVoted` (r:17 w:1)
+	/// Storage: `Voting::TotalVotes` (r:1 w:1)
+	/// Storage: `Voting::Candidates` (r:1 w:1)
+	/// Storage: `Voting::NextCandidateId` (r:1 w:1)
+	/// Storage: `Voting::DelegatedVotes` (r:17 w:16)
+	/// Storage: `System::Account` (r:17 w:17)
+	/// Storage: `Voting::VoteLocks` (r:17 w:17)
+	/// Storage: `Balances::Locks` (r:17 w:17)
+	/// Storage: `Balances::Freezes` (r:17 w:0)
+	/// Storage: `Voting::Delegators` (r:1 w:0)
+	/// Storage: `Voting::AppliedDelegations` (r:0 w:1)
+	/// Storage: `Voting::VoteCount` (r:1 w:1)
+	/// Storage: `Voting::VoteLog` (r:1 w:1)
+	/// Storage: `Voting::VoterRegisteredAt` (r:17 w:0)
+	/// Storage: `Voting::CandidateExists` (r:0 w:1)
+	/// Storage: `Voting::Receipts` (r:0 w:1)
+	/// Storage: `Voting::BallotCount` (r:1 w:1)
+	/// The range of component `d` is `[0, 16]`.
+	fn cast_write_in_vote(d: u32, ) -> Weight {
+		Weight::from_parts(50_000_000, 25_100)
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(34_u64))
+			.saturating_add(T::DbWeight::get().reads((8_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2_600).saturating_mul(d.into()))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:0)
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Voting::CandidatesLocked` (r:1 w:1)
+	fn lock_candidates() -> Weight {
+		Weight::from_parts(15_000_000, 3_600)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Voting::Election` (r:1 w:1)
+	/// Storage: `Voting::FinalResults` (r:0 w:1)
+	fn reopen_election() -> Weight {
+		Weight::from_parts(12_000_000, 3_600)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Voting::NextResetNonce` (r:1 w:1)
+	/// Storage: `Voting::PendingResets` (r:0 w:1)
+	fn request_reset() -> Weight {
+		Weight::from_parts(14_000_000, 3_600)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Voting::Election` (r:1 w:1)
+	/// Storage: `Voting::HasVoted` (r:1001 w:1000)
+	/// Storage: `Voting::Commitments` (r:1 w:0)
+	/// Storage: `Voting::DelegatedVotes` (r:1 w:0)
+	/// Storage: `Voting::Rankings` (r:0 w:1000)
+	/// Storage: `System::Account` (r:66 w:66)
+	/// Storage: `Voting::Candidates` (r:0 w:1)
+	/// Storage: `Voting::NextCandidateId` (r:0 w:1)
+	/// Storage: `Voting::CandidateOwners` (r:65 w:64)
+	/// Storage: `Voting::Candidacies` (r:64 w:64)
+	/// Storage: `Voting::VoteCount` (r:0 w:64)
+	/// Storage: `Voting::AbstainCount` (r:0 w:1)
+	/// Storage: `Voting::TotalVotes` (r:0 w:1)
+	/// Storage: `Voting::CandidatesLocked` (r:0 w:1)
+	/// Storage: `Voting::VoteLog` (r:0 w:64)
+	/// Storage: `Voting::PendingResets` (r:1 w:1)
+	/// Storage: `Voting::ResetCursor` (r:1 w:1)
+	/// Storage: `Voting::FinalResults` (r:0 w:1)
+	/// Storage: `Voting::CandidateExists` (r:0 w:64)
+	/// Storage: `Voting::Disputes` (r:0 w:1)
+	/// Storage: `Voting::ScheduledElections` (r:1 w:1)
+	/// Storage: `Voting::RankedBallotCount` (r:0 w:1)
+	/// Storage: `Voting::BallotCount` (r:0 w:1)
+	/// The range of component `v` is `[0, 1000]`.
+	fn confirm_reset(v: u32, ) -> Weight {
+		Weight::from_parts(62_000_000, 3_600)
+			.saturating_add(Weight::from_parts(2_500_000, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(202_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes(399_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(v.into())))
+	}
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Voting::CandidateExists` (r:1 w:0)
+	/// Storage: `Voting::VoteCount` (r:1 w:1)
+	/// Storage: `Voting::TotalVotes` (r:1 w:1)
+	fn adjust_vote_count() -> Weight {
+		Weight::from_parts(16_000_000, 3_600)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:0)
+	/// Storage: `Voting::Election` (r:1 w:1)
+	fn set_results_hidden() -> Weight {
+		Weight::from_parts(13_000_000, 3_600)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:0)
+	/// Storage: `Voting::Election` (r:1 w:1)
+	fn set_election_metadata() -> Weight {
+		Weight::from_parts(14_000_000, 3_600)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Voting::VotesResetCursor` (r:1 w:1)
+	/// Storage: `Voting::Election` (r:1 w:1)
+	/// Storage: `Voting::AbstainCount` (r:0 w:1)
+	/// Storage: `Voting::TotalVotes` (r:0 w:1)
+	/// Storage: `Voting::FinalResults` (r:0 w:1)
+	/// Storage: `Voting::HasVoted` (r:1001 w:1000)
+	/// Storage: `Voting::Commitments` (r:1 w:0)
+	/// Storage: `Voting::Rankings` (r:0 w:1000)
+	/// Storage: `Voting::DelegatedVotes` (r:1 w:0)
+	/// Storage: `Voting::AppliedDelegations` (r:1 w:0)
+	/// Storage: `Voting::VoteCount` (r:0 w:128)
+	/// Storage: `Voting::VoteLog` (r:0 w:64)
+	/// Storage: `Voting::Candidates` (r:1 w:0)
+	/// Storage: `Voting::ScheduledElections` (r:1 w:1)
+	/// Storage: `Voting::RankedBallotCount` (r:0 w:1)
+	/// Storage: `Voting::BallotCount` (r:0 w:1)
+	/// The range of component `v` is `[0, 1000]`.
+	fn reset_votes(v: u32, ) -> Weight {
+		Weight::from_parts(45_000_000, 3_600)
+			.saturating_add(Weight::from_parts(2_500_000, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes(200_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(v.into())))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:0)
+	/// Storage: `Voting::Election` (r:1 w:1)
+	fn set_win_threshold() -> Weight {
+		Weight::from_parts(13_000_000, 3_600)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Storage: `Voting::RegisteredVoters` (r:17 w:0)
+	/// Storage: `Identity::IdentityOf` (r:17 w:0)
+	/// Storage: `Voting::HasVoted` (r:17 w:1)
+	/// Storage: `Voting::Candidates` (r:1 w:0)
+	/// Storage: `Voting::DelegatedVotes` (r:17 w:16)
+	/// Storage: `System::Account` (r:17 w:17)
+	/// Storage: `Voting::VoteLocks` (r:17 w:17)
+	/// Storage: `Balances::Locks` (r:17 w:17)
+	/// Storage: `Balances::Freezes` (r:17 w:0)
+	/// Storage: `Voting::Delegators` (r:1 w:0)
+	/// Storage: `Voting::AppliedDelegations` (r:0 w:1)
+	/// Storage: `Voting::VoteCount` (r:64 w:64)
+	/// Storage: `Voting::Approvals` (r:0 w:1)
+	/// Storage: `Voting::TotalVotes` (r:1 w:1)
+	/// Storage: `Voting::VoterRegisteredAt` (r:17 w:0)
+	/// Storage: `Voting::Receipts` (r:0 w:1)
+	/// Storage: `Voting::VotesInBlock` (r:1 w:1)
+	/// Storage: `Voting::BallotCount` (r:1 w:1)
+	/// The range of component `d` is `[0, 16]`.
+	fn cast_approval_vote(d: u32, ) -> Weight {
+		Weight::from_parts(260_000_000, 177_000)
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(96_u64))
+			.saturating_add(T::DbWeight::get().reads((8_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(74_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2_600).saturating_mul(d.into()))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:1)
+	fn transfer_admin() -> Weight {
+		Weight::from_parts(13_000_000, 2_000)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:0)
+	/// Storage: `Voting::Election` (r:1 w:1)
+	fn set_dispute_window() -> Weight {
+		Weight::from_parts(13_000_000, 3_600)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:0)
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Voting::Disputes` (r:1 w:1)
+	fn flag_dispute() -> Weight {
+		Weight::from_parts(16_000_000, 3_600)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Voting::Disputes` (r:1 w:1)
+	fn resolve_dispute() -> Weight {
+		Weight::from_parts(11_000_000, 3_500)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Voting::RegisteredVoters` (r:1 w:0)
+	/// Storage: `Voting::CandidateExists` (r:1 w:0)
+	/// Storage: `Voting::CandidateOwners` (r:1 w:0)
+	/// Storage: `Voting::Endorsements` (r:1 w:1)
+	/// Storage: `Voting::EndorsementCount` (r:1 w:1)
+	fn endorse_candidate() -> Weight {
+		Weight::from_parts(22_000_000, 3_600)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Storage: `Voting::RegisteredVoters` (r:1 w:0)
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Storage: `Voting::HasVoted` (r:1 w:1)
+	/// Storage: `Voting::TotalVotes` (r:1 w:1)
+	/// Storage: `Voting::Candidates` (r:1 w:0)
+	/// Storage: `Voting::SpentCredits` (r:0 w:1)
+	/// Storage: `Voting::VoteCount` (r:64 w:64)
+	/// Storage: `Voting::VoteLog` (r:64 w:64)
+	/// Storage: `Voting::VoterRegisteredAt` (r:1 w:0)
+	/// Storage: `Voting::Receipts` (r:0 w:1)
+	/// Storage: `Voting::VotesInBlock` (r:1 w:1)
+	/// Storage: `Voting::BallotCount` (r:1 w:1)
+	fn cast_quadratic_vote() -> Weight {
+		Weight::from_parts(240_000_000, 170_000)
+			.saturating_add(T::DbWeight::get().reads(138_u64))
+			.saturating_add(T::DbWeight::get().writes(134_u64))
+	}
+	/// Storage: `Voting::VoteLocks` (r:1 w:1)
+	/// Storage: `Voting::Election` (r:16 w:0)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// The range of component `l` is `[1, 16]`.
+	fn unlock_vote_balance(l: u32, ) -> Weight {
+		Weight::from_parts(24_000_000, 4_800)
+			.saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(l.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 2_600).saturating_mul(l.into()))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `Voting::Admins` (r:1 w:0)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Voting::NextElectionId` (r:1 w:1)
+	/// Storage: `Voting::Election` (r:0 w:1)
+	/// Storage: `Voting::ScheduledElections` (r:1 w:1)
+	fn create_election() -> Weight {
+		Weight::from_parts(25_000_000, 3_600)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:0)
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Voting::NextCandidateId` (r:1 w:1)
+	/// Storage: `Voting::Candidates` (r:1 w:1)
+	/// Storage: `Voting::VoteCount` (r:0 w:1)
+	/// Storage: `Voting::CandidatesLocked` (r:1 w:0)
+	/// Storage: `Voting::CandidateExists` (r:0 w:1)
+	/// The range of component `c` is `[0, 63]`.
+	fn add_candidate(c: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 4_500)
+			.saturating_add(Weight::from_parts(150_000, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(0, 200).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:0)
+	/// Storage: `Voting::Election` (r:1 w:1)
+	/// Storage: `Voting::Candidates` (r:1 w:0)
+	/// Storage: `Voting::CandidatesLocked` (r:1 w:0)
+	/// Storage: `Voting::EndorsementCount` (r:64 w:0)
+	/// Storage: `Voting::ScheduledElections` (r:1 w:1)
+	fn start_election() -> Weight {
+		Weight::from_parts(16_000_000, 3_600)
+			.saturating_add(RocksDbWeight::get().reads(69_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Storage: `Voting::RegisteredVoters` (r:17 w:0)
+	/// Storage: `Identity::IdentityOf` (r:17 w:0)
+	/// Storage: `Voting::HasVoted` (r:17 w:1)
+	/// Storage: `Voting::CandidateExists` (r:1 w:0)
+	/// Storage: `Voting::DelegatedVotes` (r:17 w:16)
+	/// Storage: `System::Account` (r:17 w:17)
+	/// Storage: `Voting::VoteLocks` (r:17 w:17)
+	/// Storage: `Balances::Locks` (r:17 w:17)
+	/// Storage: `Balances::Freezes` (r:17 w:0)
+	/// Storage: `Voting::Delegators` (r:1 w:0)
+	/// Storage: `Voting::AppliedDelegations` (r:0 w:1)
+	/// Storage: `Voting::VoteCount` (r:1 w:1)
+	/// Storage: `Voting::TotalVotes` (r:1 w:1)
+	/// Storage: `Voting::VoteLog` (r:1 w:1)
+	/// Storage: `Voting::VoterRegisteredAt` (r:17 w:0)
+	/// Storage: `Voting::Receipts` (r:0 w:1)
+	/// Storage: `Voting::VotesInBlock` (r:1 w:1)
+	/// Storage: `Voting::BallotCount` (r:1 w:1)
+	/// The range of component `d` is `[0, 16]`.
+	fn cast_vote(d: u32, ) -> Weight {
+		Weight::from_parts(40_000_000, 21_100)
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(34_u64))
+			.saturating_add(RocksDbWeight::get().reads((8_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2_600).saturating_mul(d.into()))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:0)
+	/// Storage: `Voting::Election` (r:1 w:1)
+	/// Storage: `Voting::ScheduledElections` (r:1 w:1)
+	fn end_election() -> Weight {
+		Weight::from_parts(14_000_000, 3_600)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Voting::TotalVotes` (r:1 w:0)
+	/// Storage: `Voting::Election` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Voting::Rankings` (r:1001 w:0)
+	/// Storage: `Voting::HasVoted` (r:1000 w:0)
+	/// Storage: `Voting::Candidates` (r:1 w:0)
+	/// Storage: `RandomnessCollectiveFlip::RandomMaterial` (r:1 w:0)
+	/// Storage: `Voting::VoteCount` (r:64 w:0)
+	/// Storage: `Voting::AbstainCount` (r:1 w:0)
+	/// Storage: `Voting::NextArchiveId` (r:1 w:1)
+	/// Storage: `Voting::PastElections` (r:0 w:1)
+	/// Storage: `Voting::FinalResults` (r:0 w:1)
+	/// Storage: `Voting::Disputes` (r:1 w:0)
+	/// Storage: `Voting::ScheduledElections` (r:1 w:1)
+	/// The range of component `v` is `[0, 1000]`.
+	fn finalize_election(v: u32, ) -> Weight {
+		Weight::from_parts(45_000_000, 20_000)
+			.saturating_add(Weight::from_parts(12_000_000, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(74_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(Weight::from_parts(0, 2_600).saturating_mul(v.into()))
+	}
+	/// Storage: `Voting::Election` (r:1 w:1)
+	/// Storage: `Voting::HasVoted` (r:1001 w:1000)
+	/// Storage: `Voting::Commitments` (r:1 w:0)
+	/// Storage: `Voting::DelegatedVotes` (r:1 w:0)
+	/// Storage: `Voting::Rankings` (r:0 w:1000)
+	/// Storage: `System::Account` (r:66 w:66)
+	/// Storage: `Voting::Candidates` (r:0 w:1)
+	/// Storage: `Voting::NextCandidateId` (r:0 w:1)
+	/// Storage: `Voting::CandidateOwners` (r:65 w:64)
+	/// Storage: `Voting::Candidacies` (r:64 w:64)
+	/// Storage: `Voting::VoteCount` (r:0 w:64)
+	/// Storage: `Voting::AbstainCount` (r:0 w:1)
+	/// Storage: `Voting::TotalVotes` (r:0 w:1)
+	/// Storage: `Voting::CandidatesLocked` (r:0 w:1)
+	/// Storage: `Voting::VoteLog` (r:0 w:64)
+	/// Storage: `Voting::PendingResets` (r:0 w:1)
+	/// Storage: `Voting::ResetCursor` (r:1 w:1)
+	/// Storage: `Voting::FinalResults` (r:0 w:1)
+	/// Storage: `Voting::CandidateExists` (r:0 w:64)
+	/// Storage: `Voting::Disputes` (r:0 w:1)
+	/// Storage: `Voting::ScheduledElections` (r:1 w:1)
+	/// Storage: `Voting::RankedBallotCount` (r:0 w:1)
+	/// Storage: `Voting::BallotCount` (r:0 w:1)
+	/// The range of component `v` is `[0, 1000]`.
+	fn reset_election(v: u32, ) -> Weight {
+		Weight::from_parts(60_000_000, 3_600)
+			.saturating_add(Weight::from_parts(2_500_000, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(201_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().writes(399_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(v.into())))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:0)
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Voting::Candidates` (r:1 w:1)
+	/// Storage: `Voting::CandidateOwners` (r:64 w:1)
+	/// Storage: `Voting::Candidacies` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Voting::VoteCount` (r:0 w:1)
+	/// Storage: `Voting::CandidatesLocked` (r:1 w:0)
+	/// Storage: `Voting::VoteLog` (r:0 w:1)
+	/// Storage: `Voting::CandidateExists` (r:0 w:1)
+	fn remove_candidate() -> Weight {
+		Weight::from_parts(120_000_000, 170_000)
+			.saturating_add(RocksDbWeight::get().reads(70_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:0)
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Voting::Candidates` (r:1 w:1)
+	/// Storage: `Voting::CandidatesLocked` (r:1 w:0)
+	fn update_candidate() -> Weight {
+		Weight::from_parts(30_000_000, 17_000)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Storage: `Voting::HasVoted` (r:1 w:1)
+	/// Storage: `Voting::CandidateExists` (r:1 w:0)
+	/// Storage: `Voting::AbstainCount` (r:1 w:1)
+	/// Storage: `Voting::VoteCount` (r:1 w:1)
+	/// Storage: `Voting::VoteLog` (r:1 w:1)
+	fn change_vote() -> Weight {
+		Weight::from_parts(34_000_000, 21_100)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Storage: `Voting::HasVoted` (r:1 w:1)
+	/// Storage: `Voting::Rankings` (r:1 w:1)
+	/// Storage: `Voting::AppliedDelegations` (r:1 w:1)
+	/// Storage: `Voting::DelegatedVotes` (r:0 w:16)
+	/// Storage: `Voting::Approvals` (r:1 w:1)
+	/// Storage: `Voting::VoteCount` (r:64 w:64)
+	/// Storage: `Voting::TotalVotes` (r:1 w:1)
+	/// Storage: `Voting::RankedBallotCount` (r:1 w:1)
+	/// Storage: `Voting::BallotCount` (r:1 w:1)
+	/// The range of component `d` is `[0, 16]`.
+	fn retract_vote(d: u32, ) -> Weight {
+		Weight::from_parts(62_000_000, 164_400)
+			.saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(73_u64))
+			.saturating_add(RocksDbWeight::get().writes(71_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(d.into())))
+	}
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Storage: `Voting::RegisteredVoters` (r:17 w:0)
+	/// Storage: `Identity::IdentityOf` (r:17 w:0)
+	/// Storage: `Voting::HasVoted` (r:17 w:1)
+	/// Storage: `Voting::Candidates` (r:1 w:0)
+	/// Storage: `Voting::DelegatedVotes` (r:17 w:16)
+	/// Storage: `System::Account` (r:17 w:17)
+	/// Storage: `Voting::VoteLocks` (r:17 w:17)
+	/// Storage: `Balances::Locks` (r:17 w:17)
+	/// Storage: `Balances::Freezes` (r:17 w:0)
+	/// Storage: `Voting::Delegators` (r:1 w:0)
+	/// Storage: `Voting::AppliedDelegations` (r:0 w:1)
+	/// Storage: `Voting::Rankings` (r:0 w:1)
+	/// Storage: `Voting::VoteCount` (r:1 w:1)
+	/// Storage: `Voting::TotalVotes` (r:1 w:1)
+	/// Storage: `Voting::VoterRegisteredAt` (r:17 w:0)
+	/// Storage: `Voting::Receipts` (r:0 w:1)
+	/// Storage: `Voting::VotesInBlock` (r:1 w:1)
+	/// Storage: `Voting::RankedBallotCount` (r:1 w:1)
+	/// Storage: `Voting::BallotCount` (r:1 w:1)
+	/// The range of component `d` is `[0, 16]`.
+	fn cast_ranked_vote(d: u32, ) -> Weight {
+		Weight::from_parts(164_000_000, 17_000)
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(34_u64))
+			.saturating_add(RocksDbWeight::get().reads((8_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2_600).saturating_mul(d.into()))
+	}
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Storage: `Voting::RegisteredVoters` (r:1 w:0)
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Storage: `Voting::Commitments` (r:1 w:1)
+	/// Storage: `Voting::VoterRegisteredAt` (r:1 w:0)
+	fn commit_vote() -> Weight {
+		Weight::from_parts(20_000_000, 3_600)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Voting::Commitments` (r:1 w:1)
+	/// Storage: `Voting::CandidateExists` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Voting::VoteLocks` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Storage: `Voting::HasVoted` (r:0 w:1)
+	/// Storage: `Voting::VoteCount` (r:1 w:1)
+	/// Storage: `Voting::TotalVotes` (r:1 w:1)
+	/// Storage: `Voting::VoteLog` (r:1 w:1)
+	/// Storage: `Voting::BallotCount` (r:1 w:1)
+	fn reveal_vote() -> Weight {
+		Weight::from_parts(40_000_000, 21_100)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: `Voting::RegisteredVoters` (r:1 w:1)
+	/// Storage: `Voting::RegisteredVoterCount` (r:1 w:1)
+	/// Storage: `Voting::VoterRegisteredAt` (r:0 w:1)
+	fn register_voter() -> Weight {
+		Weight::from_parts(11_000_000, 3_500)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Voting::RegisteredVoters` (r:1 w:1)
+	/// Storage: `Voting::RegisteredVoterCount` (r:1 w:1)
+	/// Storage: `Voting::VoterRegisteredAt` (r:0 w:1)
+	fn deregister_voter() -> Weight {
+		Weight::from_parts(11_000_000, 3_500)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:1)
+	fn add_admin() -> Weight {
+		Weight::from_parts(12_000_000, 2_000)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:1)
+	fn remove_admin() -> Weight {
+		Weight::from_parts(12_000_000, 2_000)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Voting::CandidateOwners` (r:1 w:1)
+	/// Storage: `Voting::Candidacies` (r:1 w:1)
+	/// Storage: `Voting::NextCandidateId` (r:1 w:1)
+	/// Storage: `Voting::Candidates` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Voting::VoteCount` (r:0 w:1)
+	/// Storage: `Voting::CandidatesLocked` (r:1 w:0)
+	/// Storage: `Voting::CandidateExists` (r:0 w:1)
+	fn register_as_candidate() -> Weight {
+		Weight::from_parts(45_000_000, 17_000)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Voting::CandidateOwners` (r:1 w:1)
+	/// Storage: `Voting::Candidacies` (r:1 w:1)
+	/// Storage: `Voting::Candidates` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Voting::VoteCount` (r:0 w:1)
+	/// Storage: `Voting::CandidatesLocked` (r:1 w:0)
+	/// Storage: `Voting::VoteLog` (r:0 w:1)
+	/// Storage: `Voting::CandidateExists` (r:0 w:1)
+	fn withdraw_candidacy() -> Weight {
+		Weight::from_parts(40_000_000, 17_000)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Voting::Delegations` (r:1 w:1)
+	/// Storage: `Voting::Delegators` (r:2 w:2)
+	fn delegate_vote() -> Weight {
+		Weight::from_parts(26_000_000, 4_000)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Voting::Delegations` (r:1 w:1)
+	/// Storage: `Voting::Delegators` (r:1 w:1)
+	fn undelegate() -> Weight {
+		Weight::from_parts(20_000_000, 4_000)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:0)
+	/// Storage: `Voting::Election` (r:1 w:1)
+	fn extend_election() -> Weight {
+		Weight::from_parts(15_000_000, 3_600)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:0)
+	/// Storage: `Voting::Election` (r:1 w:1)
+	fn pause_election() -> Weight {
+		Weight::from_parts(14_000_000, 3_600)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:0)
+	/// Storage: `Voting::Election` (r:1 w:1)
+	fn resume_election() -> Weight {
+		Weight::from_parts(14_000_000, 3_600)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:0)
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Voting::Candidates` (r:1 w:1)
+	/// Storage: `Voting::NextCandidateId` (r:1 w:1)
+	/// Storage: `Voting::VoteCount` (r:0 w:64)
+	/// Storage: `Voting::CandidatesLocked` (r:1 w:0)
+	/// Storage: `Voting::CandidateExists` (r:0 w:64)
+	/// The range of component `n` is `[1, 64]`.
+	fn batch_add_candidates(n: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 17_000)
+			.saturating_add(Weight::from_parts(4_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Voting::TotalVotes` (r:1 w:0)
+	/// Storage: `Voting::Election` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Voting::Rankings` (r:1001 w:0)
+	/// Storage: `Voting::HasVoted` (r:1000 w:0)
+	/// Storage: `Voting::Candidates` (r:1 w:0)
+	/// Storage: `RandomnessCollectiveFlip::RandomMaterial` (r:1 w:0)
+	/// Storage: `Voting::VoteCount` (r:64 w:0)
+	/// Storage: `Voting::AbstainCount` (r:1 w:0)
+	/// Storage: `Voting::NextArchiveId` (r:1 w:1)
+	/// Storage: `Voting::PastElections` (r:0 w:1)
+	/// Storage: `Voting::FinalResults` (r:0 w:1)
+	/// Storage: `Voting::Disputes` (r:1 w:0)
+	/// Storage: `Voting::ScheduledElections` (r:1 w:1)
+	/// The range of component `v` is `[0, 1000]`.
+	fn force_finalize(v: u32, ) -> Weight {
+		Weight::from_parts(47_000_000, 20_000)
+			.saturating_add(Weight::from_parts(12_000_000, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(74_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(Weight::from_parts(0, 2_600).saturating_mul(v.into()))
+	}
+	/// Storage: `Voting::Election` (r:1 w:1)
+	/// Storage: `System::Account` (r:65 w:65)
+	/// Storage: `Voting::CandidateOwners` (r:65 w:64)
+	/// Storage: `Voting::Candidacies` (r:64 w:64)
+	/// Storage: `Voting::TotalVotes` (r:1 w:0)
+	/// Storage: `Voting::Candidates` (r:1 w:0)
+	/// Storage: `Voting::VoteCount` (r:64 w:0)
+	/// Storage: `Voting::AbstainCount` (r:1 w:0)
+	/// Storage: `Voting::NextArchiveId` (r:1 w:1)
+	/// Storage: `Voting::PastElections` (r:0 w:1)
+	/// Storage: `Voting::Disputes` (r:0 w:1)
+	/// Storage: `Voting::ScheduledElections` (r:1 w:1)
+	fn cancel_election() -> Weight {
+		Weight::from_parts(150_000_000, 180_000)
+			.saturating_add(RocksDbWeight::get().reads(264_u64))
+			.saturating_add(RocksDbWeight::get().writes(198_u64))
+	}
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Storage: `Voting::RegisteredVoters` (r:1 w:0)
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Storage: `Voting::Nullifiers` (r:1 w:1)
+	/// Storage: `Voting::TotalVotes` (r:1 w:1)
+	/// Storage: `Voting::CandidateExists` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Voting::VoteLocks` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Storage: `Voting::VoteCount` (r:1 w:1)
+	/// Storage: `Voting::VoteLog` (r:1 w:1)
+	/// Storage: `Voting::VoterRegisteredAt` (r:1 w:0)
+	/// Storage: `Voting::BallotCount` (r:1 w:1)
+	fn cast_unlisted_vote() -> Weight {
+		Weight::from_parts(32_000_000, 9_600)
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Storage: `Voting::RegisteredVoters` (r:17 w:0)
+	/// Storage: `Identity::IdentityOf` (r:17 w:0)
+	/// Storage: `Voting::HasVoted` (r:17 w:1)
+	/// Storage: `Voting::TotalVotes` (r:1 w:1)
+	/// Storage: `Voting::Candidates` (r:1 w:1)
+	/// Storage: `Voting::NextCandidateId` (r:1 w:1)
+	/// Storage: `Voting::DelegatedVotes` (r:17 w:16)
+	/// Storage: `System::Account` (r:17 w:17)
+	/// Storage: `Voting::VoteLocks` (r:17 w:17)
+	/// Storage: `Balances::Locks` (r:17 w:17)
+	/// Storage: `Balances::Freezes` (r:17 w:0)
+	/// Storage: `Voting::Delegators` (r:1 w:0)
+	/// Storage: `Voting::AppliedDelegations` (r:0 w:1)
+	/// Storage: `Voting::VoteCount` (r:1 w:1)
+	/// Storage: `Voting::VoteLog` (r:1 w:1)
+	/// Storage: `Voting::VoterRegisteredAt` (r:17 w:0)
+	/// Storage: `Voting::CandidateExists` (r:0 w:1)
+	/// Storage: `Voting::Receipts` (r:0 w:1)
+	/// Storage: `Voting::BallotCount` (r:1 w:1)
+	/// The range of component `d` is `[0, 16]`.
+	fn cast_write_in_vote(d: u32, ) -> Weight {
+		Weight::from_parts(50_000_000, 25_100)
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(34_u64))
+			.saturating_add(RocksDbWeight::get().reads((8_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2_600).saturating_mul(d.into()))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:0)
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Voting::CandidatesLocked` (r:1 w:1)
+	fn lock_candidates() -> Weight {
+		Weight::from_parts(15_000_000, 3_600)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Voting::Election` (r:1 w:1)
+	/// Storage: `Voting::FinalResults` (r:0 w:1)
+	fn reopen_election() -> Weight {
+		Weight::from_parts(12_000_000, 3_600)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Voting::NextResetNonce` (r:1 w:1)
+	/// Storage: `Voting::PendingResets` (r:0 w:1)
+	fn request_reset() -> Weight {
+		Weight::from_parts(14_000_000, 3_600)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Voting::Election` (r:1 w:1)
+	/// Storage: `Voting::HasVoted` (r:1001 w:1000)
+	/// Storage: `Voting::Commitments` (r:1 w:0)
+	/// Storage: `Voting::DelegatedVotes` (r:1 w:0)
+	/// Storage: `Voting::Rankings` (r:0 w:1000)
+	/// Storage: `System::Account` (r:66 w:66)
+	/// Storage: `Voting::Candidates` (r:0 w:1)
+	/// Storage: `Voting::NextCandidateId` (r:0 w:1)
+	/// Storage: `Voting::CandidateOwners` (r:65 w:64)
+	/// Storage: `Voting::Candidacies` (r:64 w:64)
+	/// Storage: `Voting::VoteCount` (r:0 w:64)
+	/// Storage: `Voting::AbstainCount` (r:0 w:1)
+	/// Storage: `Voting::TotalVotes` (r:0 w:1)
+	/// Storage: `Voting::CandidatesLocked` (r:0 w:1)
+	/// Storage: `Voting::VoteLog` (r:0 w:64)
+	/// Storage: `Voting::PendingResets` (r:1 w:1)
+	/// Storage: `Voting::ResetCursor` (r:1 w:1)
+	/// Storage: `Voting::FinalResults` (r:0 w:1)
+	/// Storage: `Voting::CandidateExists` (r:0 w:64)
+	/// Storage: `Voting::Disputes` (r:0 w:1)
+	/// Storage: `Voting::ScheduledElections` (r:1 w:1)
+	/// Storage: `Voting::RankedBallotCount` (r:0 w:1)
+	/// Storage: `Voting::BallotCount` (r:0 w:1)
+	/// The range of component `v` is `[0, 1000]`.
+	fn confirm_reset(v: u32, ) -> Weight {
+		Weight::from_parts(62_000_000, 3_600)
+			.saturating_add(Weight::from_parts(2_500_000, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(202_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().writes(399_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(v.into())))
+	}
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Voting::CandidateExists` (r:1 w:0)
+	/// Storage: `Voting::VoteCount` (r:1 w:1)
+	/// Storage: `Voting::TotalVotes` (r:1 w:1)
+	fn adjust_vote_count() -> Weight {
+		Weight::from_parts(16_000_000, 3_600)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:0)
+	/// Storage: `Voting::Election` (r:1 w:1)
+	fn set_results_hidden() -> Weight {
+		Weight::from_parts(13_000_000, 3_600)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:0)
+	/// Storage: `Voting::Election` (r:1 w:1)
+	fn set_election_metadata() -> Weight {
+		Weight::from_parts(14_000_000, 3_600)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Voting::VotesResetCursor` (r:1 w:1)
+	/// Storage: `Voting::Election` (r:1 w:1)
+	/// Storage: `Voting::AbstainCount` (r:0 w:1)
+	/// Storage: `Voting::TotalVotes` (r:0 w:1)
+	/// Storage: `Voting::FinalResults` (r:0 w:1)
+	/// Storage: `Voting::HasVoted` (r:1001 w:1000)
+	/// Storage: `Voting::Commitments` (r:1 w:0)
+	/// Storage: `Voting::Rankings` (r:0 w:1000)
+	/// Storage: `Voting::DelegatedVotes` (r:1 w:0)
+	/// Storage: `Voting::AppliedDelegations` (r:1 w:0)
+	/// Storage: `Voting::VoteCount` (r:0 w:128)
+	/// Storage: `Voting::VoteLog` (r:0 w:64)
+	/// Storage: `Voting::Candidates` (r:1 w:0)
+	/// Storage: `Voting::ScheduledElections` (r:1 w:1)
+	/// Storage: `Voting::RankedBallotCount` (r:0 w:1)
+	/// Storage: `Voting::BallotCount` (r:0 w:1)
+	/// The range of component `v` is `[0, 1000]`.
+	fn reset_votes(v: u32, ) -> Weight {
+		Weight::from_parts(45_000_000, 3_600)
+			.saturating_add(Weight::from_parts(2_500_000, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().writes(200_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(v.into())))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:0)
+	/// Storage: `Voting::Election` (r:1 w:1)
+	fn set_win_threshold() -> Weight {
+		Weight::from_parts(13_000_000, 3_600)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Storage: `Voting::RegisteredVoters` (r:17 w:0)
+	/// Storage: `Identity::IdentityOf` (r:17 w:0)
+	/// Storage: `Voting::HasVoted` (r:17 w:1)
+	/// Storage: `Voting::Candidates` (r:1 w:0)
+	/// Storage: `Voting::DelegatedVotes` (r:17 w:16)
+	/// Storage: `System::Account` (r:17 w:17)
+	/// Storage: `Voting::VoteLocks` (r:17 w:17)
+	/// Storage: `Balances::Locks` (r:17 w:17)
+	/// Storage: `Balances::Freezes` (r:17 w:0)
+	/// Storage: `Voting::Delegators` (r:1 w:0)
+	/// Storage: `Voting::AppliedDelegations` (r:0 w:1)
+	/// Storage: `Voting::VoteCount` (r:64 w:64)
+	/// Storage: `Voting::Approvals` (r:0 w:1)
+	/// Storage: `Voting::TotalVotes` (r:1 w:1)
+	/// Storage: `Voting::VoterRegisteredAt` (r:17 w:0)
+	/// Storage: `Voting::Receipts` (r:0 w:1)
+	/// Storage: `Voting::VotesInBlock` (r:1 w:1)
+	/// Storage: `Voting::BallotCount` (r:1 w:1)
+	/// The range of component `d` is `[0, 16]`.
+	fn cast_approval_vote(d: u32, ) -> Weight {
+		Weight::from_parts(260_000_000, 177_000)
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(96_u64))
+			.saturating_add(RocksDbWeight::get().reads((8_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(74_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2_600).saturating_mul(d.into()))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:1)
+	fn transfer_admin() -> Weight {
+		Weight::from_parts(13_000_000, 2_000)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:0)
+	/// Storage: `Voting::Election` (r:1 w:1)
+	fn set_dispute_window() -> Weight {
+		Weight::from_parts(13_000_000, 3_600)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Voting::Admins` (r:1 w:0)
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Voting::Disputes` (r:1 w:1)
+	fn flag_dispute() -> Weight {
+		Weight::from_parts(16_000_000, 3_600)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Voting::Disputes` (r:1 w:1)
+	fn resolve_dispute() -> Weight {
+		Weight::from_parts(11_000_000, 3_500)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Voting::RegisteredVoters` (r:1 w:0)
+	/// Storage: `Voting::CandidateExists` (r:1 w:0)
+	/// Storage: `Voting::CandidateOwners` (r:1 w:0)
+	/// Storage: `Voting::Endorsements` (r:1 w:1)
+	/// Storage: `Voting::EndorsementCount` (r:1 w:1)
+	fn endorse_candidate() -> Weight {
+		Weight::from_parts(22_000_000, 3_600)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Voting::Election` (r:1 w:0)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Storage: `Voting::RegisteredVoters` (r:1 w:0)
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Storage: `Voting::HasVoted` (r:1 w:1)
+	/// Storage: `Voting::TotalVotes` (r:1 w:1)
+	/// Storage: `Voting::Candidates` (r:1 w:0)
+	/// Storage: `Voting::SpentCredits` (r:0 w:1)
+	/// Storage: `Voting::VoteCount` (r:64 w:64)
+	/// Storage: `Voting::VoteLog` (r:64 w:64)
+	/// Storage: `Voting::VoterRegisteredAt` (r:1 w:0)
+	/// Storage: `Voting::Receipts` (r:0 w:1)
+	/// Storage: `Voting::VotesInBlock` (r:1 w:1)
+	/// Storage: `Voting::BallotCount` (r:1 w:1)
+	fn cast_quadratic_vote() -> Weight {
+		Weight::from_parts(240_000_000, 170_000)
+			.saturating_add(RocksDbWeight::get().reads(138_u64))
+			.saturating_add(RocksDbWeight::get().writes(134_u64))
+	}
+	/// Storage: `Voting::VoteLocks` (r:1 w:1)
+	/// Storage: `Voting::Election` (r:16 w:0)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// The range of component `l` is `[1, 16]`.
+	fn unlock_vote_balance(l: u32, ) -> Weight {
+		Weight::from_parts(24_000_000, 4_800)
+			.saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(l.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 2_600).saturating_mul(l.into()))
+	}
+}
@@ -43,7 +43,7 @@ use sp_version::RuntimeVersion;
 // Local module imports
 use super::{
 	AccountId, Aura, Balance, Block, Executive, Grandpa, InherentDataExt, Nonce, Runtime,
-	RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment, VERSION,
+	RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment, Voting, VERSION,
 };
 
 impl_runtime_apis! {
@@ -220,6 +220,138 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_voting_runtime_api::VotingApi<Block> for Runtime {
+		fn get_results(election_id: u32) -> Option<Vec<(u32, Vec<u8>, u32)>> {
+			pallet_voting::Election::<Runtime>::contains_key(election_id)
+				.then(|| {
+					Voting::get_results(election_id)
+						.into_iter()
+						.map(|(candidate_id, name, votes, _)| (candidate_id, name, votes))
+						.collect()
+				})
+		}
+		fn results_with_percentage(
+			election_id: u32,
+		) -> Option<Vec<pallet_voting_runtime_api::ResultWithPercentage>> {
+			pallet_voting::Election::<Runtime>::contains_key(election_id)
+				.then(|| Voting::results_with_percentage(election_id))
+		}
+		fn get_winner(election_id: u32) -> Option<(u32, u32)> {
+			if Voting::results_hidden(election_id) {
+				return None
+			}
+			match Voting::get_winner(election_id) {
+				pallet_voting::WinnerResult::Winner(candidate_id, votes) => Some((candidate_id, votes)),
+				_ => None,
+			}
+		}
+		fn total_votes(election_id: u32) -> u32 {
+			if Voting::results_hidden(election_id) {
+				return 0
+			}
+			pallet_voting::TotalVotes::<Runtime>::get(election_id)
+		}
+		fn candidates_paged(election_id: u32, offset: u32, limit: u32) -> Vec<(u32, Vec<u8>, Vec<u8>)> {
+			Voting::candidates_paged(election_id, offset, limit)
+				.into_iter()
+				.map(|c| (c.id, c.name.to_vec(), c.description.to_vec()))
+				.collect()
+		}
+		fn candidate_count(election_id: u32) -> u32 {
+			Voting::candidate_count(election_id)
+		}
+		fn get_candidate(election_id: u32, candidate_id: u32) -> Option<(u32, Vec<u8>, Vec<u8>)> {
+			Voting::get_candidate(election_id, candidate_id)
+				.map(|c| (c.id, c.name.to_vec(), c.description.to_vec()))
+		}
+		fn past_elections(offset: u32, limit: u32) -> Vec<pallet_voting_runtime_api::PastElection> {
+			Voting::past_elections(offset, limit)
+				.into_iter()
+				.map(|(archive_id, summary)| (
+					archive_id,
+					summary.election_id,
+					summary.title.to_vec(),
+					summary.winners.to_vec(),
+					summary.total_votes,
+					summary.vote_counts.to_vec(),
+				))
+				.collect()
+		}
+		fn export_results(election_id: u32) -> Option<pallet_voting_runtime_api::ElectionExport> {
+			Voting::export_results(election_id).map(|export| pallet_voting_runtime_api::ElectionExport {
+				title: export.title.to_vec(),
+				candidates: export
+					.candidates
+					.into_iter()
+					.map(|(candidate_id, name, votes)| (candidate_id, name.to_vec(), votes))
+					.collect(),
+				total_votes: export.total_votes,
+				abstentions: export.abstentions,
+				status: election_status(export.status, export.is_failed),
+			})
+		}
+		fn turnout(election_id: u32) -> sp_runtime::Permill {
+			if Voting::results_hidden(election_id) {
+				return sp_runtime::Permill::zero()
+			}
+			Voting::turnout(election_id)
+		}
+		fn get_results_ordered(
+			election_id: u32,
+			order: pallet_voting_runtime_api::ResultOrder,
+		) -> Option<Vec<(u32, Vec<u8>, u32)>> {
+			use pallet_voting_runtime_api::ResultOrder as Order;
+			let order = match order {
+				Order::ByVotesDesc => pallet_voting::ResultOrder::ByVotesDesc,
+				Order::ById => pallet_voting::ResultOrder::ById,
+				Order::Insertion => pallet_voting::ResultOrder::Insertion,
+			};
+			pallet_voting::Election::<Runtime>::contains_key(election_id)
+				.then(|| Voting::get_results_ordered(election_id, order))
+		}
+		fn candidate_share_of_decided(election_id: u32, candidate_id: u32) -> sp_runtime::Permill {
+			if Voting::results_hidden(election_id) {
+				return sp_runtime::Permill::zero()
+			}
+			Voting::candidate_share_of_decided(election_id, candidate_id)
+		}
+		fn is_voting_open(election_id: u32) -> bool {
+			Voting::is_voting_open(election_id)
+		}
+		fn election_snapshot(election_id: u32) -> Option<pallet_voting_runtime_api::ElectionSnapshot> {
+			Voting::election_snapshot(election_id).map(|snapshot| {
+				pallet_voting_runtime_api::ElectionSnapshot {
+					title: snapshot.title.to_vec(),
+					status: election_status(snapshot.status, snapshot.is_failed),
+					start_block: snapshot.start_block,
+					end_block: snapshot.end_block,
+					candidate_count: snapshot.candidate_count,
+					total_votes: snapshot.total_votes,
+					within_window: snapshot.within_window,
+				}
+			})
+		}
+	}
+
+	impl pallet_voting_runtime_api::VotingAuditApi<Block, AccountId> for Runtime {
+		fn voters_for_candidate(election_id: u32, candidate_id: u32) -> Vec<AccountId> {
+			Voting::voters_for_candidate(election_id, candidate_id)
+		}
+		fn all_voters(election_id: u32) -> Vec<AccountId> {
+			Voting::all_voters(election_id)
+		}
+	}
+
+	impl pallet_voting_runtime_api::VoterEligibilityApi<Block, AccountId> for Runtime {
+		fn can_vote(
+			who: AccountId,
+			election_id: u32,
+			candidate_id: u32,
+		) -> Result<(), sp_runtime::DispatchError> {
+			Voting::can_vote(&who, election_id, candidate_id).map_err(Into::into)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -302,3 +434,21 @@ impl_runtime_apis! {
 		}
 	}
 }
+
+/// The status of an election as reported by the runtime API, which tells failed elections apart
+/// from finalized ones.
+fn election_status(
+	status: pallet_voting::ElectionStatus,
+	is_failed: bool,
+) -> pallet_voting_runtime_api::ElectionStatus {
+	use pallet_voting::ElectionStatus as Status;
+	use pallet_voting_runtime_api::ElectionStatus as ApiStatus;
+	match status {
+		Status::Created => ApiStatus::Pending,
+		Status::Active | Status::Paused => ApiStatus::Active,
+		Status::Ended => ApiStatus::Ended,
+		Status::Finalized if is_failed => ApiStatus::Failed,
+		Status::Finalized => ApiStatus::Finalized,
+		Status::Cancelled => ApiStatus::Cancelled,
+	}
+}
@@ -32,17 +32,24 @@ use frame_support::{
 		IdentityFee, Weight,
 	},
 };
-use frame_system::limits::{BlockLength, BlockWeights};
+use frame_system::{
+	limits::{BlockLength, BlockWeights},
+	EnsureRoot,
+};
 use pallet_transaction_payment::{ConstFeeMultiplier, FungibleAdapter, Multiplier};
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_runtime::{traits::One, Perbill};
+use sp_runtime::{
+	traits::{Convert, One},
+	Perbill, SaturatedConversion,
+};
 use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-	AccountId, Aura, Balance, Balances, Block, BlockNumber, Hash, Nonce, PalletInfo, Runtime,
-	RuntimeCall, RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask,
-	System, EXISTENTIAL_DEPOSIT, SLOT_DURATION, VERSION,
+	AccountId, Aura, Balance, Balances, Block, BlockNumber, Hash, Nonce, PalletInfo,
+	RandomnessCollectiveFlip, Runtime, RuntimeCall, RuntimeEvent, RuntimeFreezeReason,
+	RuntimeHoldReason, RuntimeOrigin, RuntimeTask, System, Timestamp, DAYS, EXISTENTIAL_DEPOSIT,
+	MINUTES, SLOT_DURATION, UNIT, VERSION,
 };
 
 const NORMAL_DISPATCH_RATIO: Perbill = Perbill::from_percent(75);
@@ -162,3 +169,58 @@ impl pallet_template::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_template::weights::SubstrateWeight<Runtime>;
 }
+
+/// Configure the randomness source of pallet-voting. It is only suitable for development chains,
+/// as block producers can influence its output.
+impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
+
+/// Configure the pallet-voting in pallets/voting.
+impl pallet_voting::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type MaxCandidates = ConstU32<64>;
+	type MaxNameLength = ConstU32<64>;
+	type MaxTitleLength = ConstU32<128>;
+	type Currency = Balances;
+	type BalanceToVoteWeight = BalanceToVoteWeight;
+	type RequireRegistration = ConstBool<false>;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type ElectionDeposit = ConstU128<{ 10 * UNIT }>;
+	type CandidateBond = ConstU128<UNIT>;
+	type MinQuorum = ConstU32<1>;
+	type WeightInfo = pallet_voting::weights::SubstrateWeight<Runtime>;
+	type Randomness = RandomnessCollectiveFlip;
+	type RandomTieBreaking = ConstBool<true>;
+	type MaxDelegators = ConstU32<16>;
+	type AllowResetDuringVoting = ConstBool<false>;
+	type Time = Timestamp;
+	type MinCandidates = ConstU32<1>;
+	type IdentityProvider = ();
+	type RequireVerifiedIdentity = ConstBool<false>;
+	type TurnoutReportInterval = ConstU32<{ 10 * MINUTES }>;
+	type MaxReasonLength = ConstU32<256>;
+	type MaxVoters = ConstU32<1_000_000>;
+	type MinCreationLead = ConstU32<1>;
+	type SlashedFundsDestination = ();
+	type AllowMultipleCandidaciesPerAccount = ConstBool<true>;
+	type ExpectedCandidates = ConstU32<0>;
+	type EnableVoteLog = ConstBool<true>;
+	type MaxDescriptionLength = ConstU32<256>;
+	type ResetConfirmationWindow = ConstU32<{ 10 * MINUTES }>;
+	type ResetBatchSize = ConstU32<1_000>;
+	type VotingEligibilityDelay = ConstU32<0>;
+	type MinDescriptionLength = ConstU32<1>;
+	type MaxElectionDuration = ConstU32<{ 90 * DAYS }>;
+	type EmitPerVoteEvents = ConstBool<true>;
+	type MinEndorsements = ConstU32<0>;
+	type VoteCreditBudget = ConstU32<100>;
+	type MaxScheduledElections = ConstU32<256>;
+	type MaxVoteLocks = ConstU32<16>;
+}
+
+/// Counts one vote per whole `UNIT` of free balance in weighted elections.
+pub struct BalanceToVoteWeight;
+impl Convert<Balance, u32> for BalanceToVoteWeight {
+	fn convert(balance: Balance) -> u32 {
+		(balance / UNIT).saturated_into()
+	}
+}
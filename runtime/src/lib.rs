@@ -69,10 +69,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 101,
 	impl_version: 1,
 	apis: apis::RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	system_version: 1,
 };
 
@@ -155,6 +155,7 @@ pub type TxExtension = (
 	frame_system::CheckEra<Runtime>,
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
+	pallet_voting::CheckNotAlreadyVoted<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
 	frame_metadata_hash_extension::CheckMetadataHash<Runtime>,
 	frame_system::WeightReclaim<Runtime>,
@@ -171,7 +172,30 @@ pub type SignedPayload = generic::SignedPayload<RuntimeCall, TxExtension>;
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+	pallet_voting::migrations::v1::MigrateToV1<Runtime>,
+	pallet_voting::migrations::v2::MigrateToV2<Runtime>,
+	pallet_voting::migrations::v3::MigrateToV3<Runtime>,
+	pallet_voting::migrations::v4::MigrateToV4<Runtime>,
+	pallet_voting::migrations::v5::MigrateToV5<Runtime>,
+	pallet_voting::migrations::v6::MigrateToV6<Runtime>,
+	pallet_voting::migrations::v7::MigrateToV7<Runtime>,
+	pallet_voting::migrations::v8::MigrateToV8<Runtime>,
+	pallet_voting::migrations::v9::MigrateToV9<Runtime>,
+	pallet_voting::migrations::v10::MigrateToV10<Runtime>,
+	pallet_voting::migrations::v11::MigrateToV11<Runtime>,
+	pallet_voting::migrations::v12::MigrateToV12<Runtime>,
+	pallet_voting::migrations::v13::MigrateToV13<Runtime>,
+	pallet_voting::migrations::v14::MigrateToV14<Runtime>,
+	pallet_voting::migrations::v15::MigrateToV15<Runtime>,
+	pallet_voting::migrations::v16::MigrateToV16<Runtime>,
+	pallet_voting::migrations::v17::MigrateToV17<Runtime>,
+	pallet_voting::migrations::v18::MigrateToV18<Runtime>,
+	pallet_voting::migrations::v19::MigrateToV19<Runtime>,
+	pallet_voting::migrations::v20::MigrateToV20<Runtime>,
+	pallet_voting::migrations::v21::MigrateToV21<Runtime>,
+	pallet_voting::migrations::v22::MigrateToV22<Runtime>,
+);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -225,4 +249,12 @@ mod runtime {
 	// Include the custom logic from the pallet-template in the runtime.
 	#[runtime::pallet_index(7)]
 	pub type Template = pallet_template;
+
+	// Include the election logic from the pallet-voting in the runtime.
+	#[runtime::pallet_index(8)]
+	pub type Voting = pallet_voting;
+
+	// Provides the randomness used by pallet-voting to break ties.
+	#[runtime::pallet_index(9)]
+	pub type RandomnessCollectiveFlip = pallet_insecure_randomness_collective_flip;
 }